@@ -1,6 +1,6 @@
 import { Component, inject } from '@angular/core';
 import { MAT_DIALOG_DATA, MatDialogModule } from '@angular/material/dialog';
-import { Mission } from '../../_models/mission';
+import { MissionModel } from '../../_models/generated';
 import { DatePipe, LowerCasePipe, UpperCasePipe } from '@angular/common';
 import { MatButtonModule } from '@angular/material/button';
 
@@ -11,5 +11,5 @@ import { MatButtonModule } from '@angular/material/button';
   styleUrl: './description-mission.scss',
 })
 export class DescriptionMission {
-  mission = inject<Mission>(MAT_DIALOG_DATA);
+  mission = inject<MissionModel>(MAT_DIALOG_DATA);
 }
@@ -1,10 +1,9 @@
 import { Component, inject } from '@angular/core'
 import { CommonModule } from '@angular/common'
-import { AddMission } from '../../_models/add-mission'
+import { AddMissionModel, MissionModel } from '../../_models/generated';
 import { MAT_DIALOG_DATA, MatDialogActions, MatDialogContent, MatDialogRef, MatDialogTitle, MatDialogClose } from '@angular/material/dialog'
 import { MatButtonModule } from '@angular/material/button'
 import { FormsModule } from '@angular/forms'
-import { Mission } from '../../_models/mission';
 
 @Component({
   selector: 'app-new-mission',
@@ -14,19 +13,19 @@ import { Mission } from '../../_models/mission';
 })
 export class NewMission {
   categories = ['General', 'Gaming', 'Work', 'Fitness', 'Social', 'Learning', 'Creative', 'Finance']
-  addMission: AddMission = {
+  addMission: AddMissionModel = {
     name: '',
     description: '',
     category: 'General'
   }
   private readonly _dialogRef = inject(MatDialogRef<NewMission>)
-  private readonly _data = inject<Mission>(MAT_DIALOG_DATA, { optional: true })
+  private readonly _data = inject<MissionModel>(MAT_DIALOG_DATA, { optional: true })
 
   constructor() {
     if (this._data) {
       this.addMission = {
         name: this._data.name,
-        description: this._data.description,
+        description: this._data.description ?? undefined,
         category: this._data.category || 'General'
       }
     }
@@ -37,7 +36,7 @@ export class NewMission {
     this._dialogRef.close(mission)
   }
 
-  private clean(addMission: AddMission): AddMission {
+  private clean(addMission: AddMissionModel): AddMissionModel {
     return {
       name: addMission.name.trim() || 'untitled',
       description: addMission.description?.trim() || undefined,
@@ -1,4 +1,4 @@
-import { Passport } from "../_models/passport"
+import { Passport } from "../_models/generated"
 
 const _default_avatar = 'assets/def.jpg'
 export function getAvatar(passport?: Passport) {
@@ -1,5 +0,0 @@
-export interface AddMission {
-  name: string
-  description?: string
-  category?: string
-}
\ No newline at end of file
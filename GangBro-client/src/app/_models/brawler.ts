@@ -1,6 +0,0 @@
-export interface Brawler {
-display_name: string,
-avatar_url: string,
-mission_success_count: number,
-mission_joined_count: number
-}
\ No newline at end of file
@@ -1,4 +0,0 @@
-export interface CloudinaryImage {
-    public_id: string
-    url: string
-}
\ No newline at end of file
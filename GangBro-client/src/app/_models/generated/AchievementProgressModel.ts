@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AchievementProgressModel = { missions_led: number, missions_completed: number, missions_joined: number, categories_explored: number, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AchievementProgressModel } from "./AchievementProgressModel";
+import type { BrawlerAchievementModel } from "./BrawlerAchievementModel";
+
+export type BrawlerAchievementsModel = { progress: AchievementProgressModel, achievements: Array<BrawlerAchievementModel>, };
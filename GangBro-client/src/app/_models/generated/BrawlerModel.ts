@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BrawlerModel = { brawler_id: number, display_name: string, avatar_url: string, mission_success_count: number, mission_joined_count: number, rating_average: number, rating_count: number, };
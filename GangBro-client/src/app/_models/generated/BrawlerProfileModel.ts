@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BrawlerAchievementsModel } from "./BrawlerAchievementsModel";
+import type { Role } from "./Role";
+
+/**
+ * What anyone can see of a brawler, earned badges included.
+ */
+export type BrawlerProfileModel = { brawler_id: number, display_name: string, avatar_url: string | null, role: Role, achievements: BrawlerAchievementsModel, };
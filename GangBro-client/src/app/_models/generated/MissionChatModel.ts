@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MissionChatModel = { id: number, mission_id: number, brawler_id: number, brawler_name: string, message: string, created_at: string, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MissionSort } from "./MissionSort";
+import type { MissionStatuses } from "./MissionStatuses";
+import type { SortDirection } from "./SortDirection";
+
+export type MissionFilter = { name?: string, status?: MissionStatuses, 
+/**
+ * Comma-separated statuses, combined with `status`.
+ */
+statuses?: string, category?: string, chief_id?: number, exclude_chief_id?: number, 
+/**
+ * Only missions this brawler is a crew member of.
+ */
+member_id?: number, 
+/**
+ * Open missions that still have room in the crew (or, when false, those that don't).
+ */
+has_open_slots?: boolean, 
+/**
+ * Inclusive creation date range.
+ */
+created_from?: string, created_to?: string, 
+/**
+ * Full-text query over name, description and tags, tolerant of small typos.
+ */
+q?: string, 
+/**
+ * Comma-separated tag names; a mission must carry all of them.
+ */
+tags?: string, sort?: MissionSort, direction?: SortDirection, 
+/**
+ * Opaque `next_cursor` from a previous page; takes precedence over `page`.
+ */
+cursor?: string, page?: number, limit?: number, };
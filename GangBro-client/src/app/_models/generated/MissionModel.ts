@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MissionStatuses } from "./MissionStatuses";
+
+export type MissionModel = { id: number, name: string, description: string | null, status: MissionStatuses, chief_id: number, chief_display_name: string, crew_count: number, created_at: string, updated_at: string, category: string, category_label: string, tags: Array<string>, ends_at: string | null, 
+/**
+ * When the mission was completed or failed.
+ */
+finished_at: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MissionModel } from "./MissionModel";
+
+export type MissionPageModel = { items: Array<MissionModel>, total: number, 
+/**
+ * Only set for page/limit requests; cursor requests have no page number.
+ */
+page: number | null, limit: number, total_pages: number, next_cursor: string | null, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MissionSort = "relevance" | "created_at" | "updated_at" | "name" | "crew_count" | "ends_at";
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Role } from "./Role";
+
+export type Passport = { user_id: number, token: string, display_name: string, avatar_url: string | null, role: Role, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Declared from least to most privileged, so roles compare by rank.
+ */
+export type Role = "member" | "moderator" | "admin";
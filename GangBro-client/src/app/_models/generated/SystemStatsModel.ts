@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SystemStatsModel = { active_members: number, missions_completed: number, missions_failed: number, success_rate: number, };
@@ -0,0 +1,23 @@
+// Generated by server/tests/typescript_bindings.rs. Do not edit this file manually.
+export * from './AchievementProgressModel';
+export * from './AddMissionChatModel';
+export * from './AddMissionModel';
+export * from './BrawlerAchievementModel';
+export * from './BrawlerAchievementsModel';
+export * from './BrawlerModel';
+export * from './BrawlerProfileModel';
+export * from './EditMissionModel';
+export * from './LoginModel';
+export * from './MissionChatModel';
+export * from './MissionFilter';
+export * from './MissionModel';
+export * from './MissionPageModel';
+export * from './MissionSort';
+export * from './MissionStatuses';
+export * from './NotificationModel';
+export * from './Passport';
+export * from './RegisterBrawlerModel';
+export * from './Role';
+export * from './SortDirection';
+export * from './SystemStatsModel';
+export * from './UploadedImage';
@@ -1,14 +0,0 @@
-export interface MissionFilter {
-    name?: string
-    status?: MissionStatus
-    category?: string
-    exclude_chief_id?: number
-    page?: number
-    limit?: number
-}
-
-export type MissionStatus =
-    'Open' |
-    'InProgress' |
-    'Completed' |
-    'Failed'
\ No newline at end of file
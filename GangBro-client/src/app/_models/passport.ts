@@ -1,17 +0,0 @@
-export interface Passport {
-    user_id: number;
-    token: string;
-    display_name: string;
-    avatar_url?: string;
-}
-
-export interface RegisterModel {
-    username: string,
-    password: string,
-    display_name: string
-}
-
-export interface LoginModel {
-    username: string,
-    password: string
-}
\ No newline at end of file
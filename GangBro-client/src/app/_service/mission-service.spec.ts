@@ -1,6 +1,6 @@
 import { describe, it, expect, beforeEach, vi } from 'vitest';
 import { MissionService } from './mission-service';
-import { MissionFilter } from '../_models/mission-filter';
+import { MissionFilter } from '../_models/generated';
 
 describe('MissionService', () => {
     let service: any;
@@ -1,11 +1,8 @@
 import { inject, Injectable } from '@angular/core'
 import { environment } from '../../environments/environment'
 import { HttpClient } from '@angular/common/http'
-import { MissionFilter } from '../_models/mission-filter'
+import { AddMissionModel, BrawlerModel, MissionFilter, MissionModel, MissionPageModel } from '../_models/generated'
 import { firstValueFrom } from 'rxjs'
-import { Mission } from '../_models/mission'
-import { AddMission } from '../_models/add-mission'
-import { Brawler } from '../_models/brawler'
 
 @Injectable({
   providedIn: 'root',
@@ -23,12 +20,12 @@ export class MissionService {
   /**
    * Fetches missions based on filter criteria.
    * @param filter The filter parameters.
-   * @returns A promise of an array of missions.
+   * @returns A promise of a page of missions with paging metadata.
    */
-  async getByFilter(filter: MissionFilter): Promise<Mission[]> {
+  async getByFilter(filter: MissionFilter): Promise<MissionPageModel> {
     const queryString = this.createQueryString(filter)
     const url = this._base_url + '/view/gets?' + queryString
-    return await firstValueFrom(this._http.get<Mission[]>(url))
+    return await firstValueFrom(this._http.get<MissionPageModel>(url))
   }
 
   /**
@@ -37,10 +34,10 @@ export class MissionService {
    * @param silent If true, skips the global loading interceptor.
    * @returns A promise of the mission.
    */
-  async getById(id: number, silent = false): Promise<Mission> {
+  async getById(id: number, silent = false): Promise<MissionModel> {
     const url = this._base_url + `/view/${id}`
     const headers = silent ? { 'X-Skip-Loading': 'true' } : undefined
-    return await firstValueFrom(this._http.get<Mission>(url, { headers }))
+    return await firstValueFrom(this._http.get<MissionModel>(url, { headers }))
   }
 
   /**
@@ -49,10 +46,10 @@ export class MissionService {
    * @param silent If true, skips the global loading interceptor.
    * @returns A promise of an array of brawlers.
    */
-  async getRoster(id: number, silent = false): Promise<Brawler[]> {
+  async getRoster(id: number, silent = false): Promise<BrawlerModel[]> {
     const url = this._base_url + `/view/roster/${id}`
     const headers = silent ? { 'X-Skip-Loading': 'true' } : undefined
-    return await firstValueFrom(this._http.get<Brawler[]>(url, { headers }))
+    return await firstValueFrom(this._http.get<BrawlerModel[]>(url, { headers }))
   }
 
   /**
@@ -113,7 +110,7 @@ export class MissionService {
    * @param mission The mission data.
    * @returns A promise of the new mission ID.
    */
-  async add(mission: AddMission): Promise<number> {
+  async add(mission: AddMissionModel): Promise<number> {
     const url = this._base_url + '/mission-management'
     const resp = await firstValueFrom(this._http.post<{ mission_id: number }>(url, mission))
     return resp.mission_id
@@ -123,9 +120,9 @@ export class MissionService {
    * Fetches missions associated with the current brawler.
    * @returns A promise of an array of missions.
    */
-  async getMyMissions(): Promise<Mission[]> {
+  async getMyMissions(): Promise<MissionModel[]> {
     const url = this._base_url + '/brawler/my-missions'
-    return await firstValueFrom(this._http.get<Mission[]>(url))
+    return await firstValueFrom(this._http.get<MissionModel[]>(url))
   }
 
   /**
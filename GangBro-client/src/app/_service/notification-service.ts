@@ -2,7 +2,7 @@ import { inject, Injectable, signal, OnDestroy, NgZone } from '@angular/core';
 import { MatSnackBar } from '@angular/material/snack-bar';
 import { PassportService } from './passport-service';
 import { MissionService } from './mission-service';
-import { Mission } from '../_models/mission';
+import { MissionModel } from '../_models/generated';
 import { APP_CONFIG } from '../_constants/config.constants';
 import { MissionStatus } from '../_enums/mission-status.enum';
 
@@ -32,8 +32,8 @@ export class NotificationService implements OnDestroy {
     unreadCount = signal(0);
 
     private _pollingHandle: any;
-    private _lastMissions: Mission[] = [];
-    private _lastMyMissions: Mission[] = [];
+    private _lastMissions: MissionModel[] = [];
+    private _lastMyMissions: MissionModel[] = [];
 
     constructor() {
         this.startPolling();
@@ -1,7 +1,7 @@
 import { HttpClient } from '@angular/common/http'
 import { inject, Injectable, signal } from '@angular/core'
 import { environment } from '../../environments/environment'
-import { LoginModel, Passport, RegisterModel } from '../_models/passport'
+import { LoginModel, Passport, RegisterBrawlerModel } from '../_models/generated'
 import { firstValueFrom } from 'rxjs'
 import { getAvatar } from '../_helpers/util'
 
@@ -104,7 +104,7 @@ export class PassportService {
    * @param register The registration data.
    * @returns A promise of an error message if failed, otherwise null.
    */
-  async register(register: RegisterModel): Promise<null | string> {
+  async register(register: RegisterBrawlerModel): Promise<null | string> {
     const api_url = this._base_url + '/brawler/register'
     return await this.fetchPassport(api_url, register)
   }
@@ -112,7 +112,7 @@ export class PassportService {
   /**
    * Internal helper to fetch passport from API and handle storage/sanitization.
    */
-  private async fetchPassport(api_url: string, model: LoginModel | RegisterModel): Promise<string | null> {
+  private async fetchPassport(api_url: string, model: LoginModel | RegisterBrawlerModel): Promise<string | null> {
     try {
       const result = this._http.post<Passport>(api_url, model)
       const passport = await firstValueFrom(result)
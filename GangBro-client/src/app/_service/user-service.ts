@@ -4,7 +4,7 @@ import { HttpClient } from '@angular/common/http'
 import { PassportService } from './passport-service'
 import { fileToBase64 } from '../_helpers/file'
 import { firstValueFrom } from 'rxjs'
-import { CloudinaryImage } from '../_models/cloudinary-image'
+import { UploadedImage } from '../_models/generated'
 
 @Injectable({
   providedIn: 'root',
@@ -29,7 +29,7 @@ export class UserService {
       'base64_string': base64string.split(',')[1]
     }
     try {
-      const cloudinaryImg = await firstValueFrom(this._http.post<CloudinaryImage>(url, uploadImg))
+      const cloudinaryImg = await firstValueFrom(this._http.post<UploadedImage>(url, uploadImg))
       this._passport.saveAvatarImgUrl(cloudinaryImg.url)
     } catch (error: any) {
       return error.error as string
@@ -3,8 +3,7 @@ import { CommonModule } from '@angular/common';
 import { ActivatedRoute, RouterLink } from '@angular/router';
 import { MissionService } from '../_service/mission-service';
 import { PassportService } from '../_service/passport-service';
-import { Mission } from '../_models/mission';
-import { Brawler } from '../_models/brawler';
+import { BrawlerModel, MissionModel } from '../_models/generated';
 import { MatIconModule } from '@angular/material/icon';
 import { FormsModule } from '@angular/forms';
 import { MatSnackBar } from '@angular/material/snack-bar';
@@ -30,8 +29,8 @@ export class AboutMission implements OnInit, OnDestroy {
   private _dialog = inject(MatDialog);
   private _router = inject(Router);
 
-  mission = signal<Mission | null>(null);
-  roster = signal<Brawler[]>([]);
+  mission = signal<MissionModel | null>(null);
+  roster = signal<BrawlerModel[]>([]);
   isLoading = signal(true);
   currentUserName = signal<string>('');
   chatMessages = signal<{ id?: number; user: string; text: string; time: Date }[]>([]);
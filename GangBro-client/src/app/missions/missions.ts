@@ -5,10 +5,8 @@ import { MatMenuModule } from '@angular/material/menu'
 import { MatButtonModule } from '@angular/material/button'
 import { MatSnackBar, MatSnackBarModule } from '@angular/material/snack-bar'
 import { NewMission } from '../_dialog/new-mission/new-mission'
-import { AddMission } from '../_models/add-mission'
+import { AddMissionModel, MissionFilter, MissionModel } from '../_models/generated'
 import { DescriptionMission } from '../_dialog/description-mission/description-mission'
-import { MissionFilter } from '../_models/mission-filter'
-import { Mission } from '../_models/mission'
 import { FormsModule } from '@angular/forms'
 import { BehaviorSubject } from 'rxjs'
 import { AsyncPipe, UpperCasePipe } from '@angular/common'
@@ -48,13 +46,14 @@ export class Missions implements OnInit, OnDestroy {
     limit: 10
   }
 
-  private _missionsSubject = new BehaviorSubject<Mission[]>([])
+  private _missionsSubject = new BehaviorSubject<MissionModel[]>([])
   readonly missions$ = this._missionsSubject.asObservable()
   isSignin: Signal<boolean>
   myUserId: Signal<number | undefined>
 
-  selectedMission: Mission | null = null
+  selectedMission: MissionModel | null = null
   isLoading = signal(false)
+  totalPages = signal(1)
   private _pollingHandle: any
 
   constructor() {
@@ -94,7 +93,9 @@ export class Missions implements OnInit, OnDestroy {
     try {
       if (!silent) this.isLoading.set(true)
 
-      const allMissions = await this._mission.getByFilter(this.filter)
+      const missionPage = await this._mission.getByFilter(this.filter)
+      const allMissions = missionPage.items
+      this.totalPages.set(Math.max(missionPage.total_pages, 1))
 
       let myJoinedMissionIds: number[] = []
       if (this.isSignin()) {
@@ -130,6 +131,7 @@ export class Missions implements OnInit, OnDestroy {
   }
 
   async onNextPage() {
+    if ((this.filter.page || 1) >= this.totalPages()) return;
     this.filter.page = (this.filter.page || 1) + 1;
     await this.loadMyMission();
   }
@@ -143,7 +145,7 @@ export class Missions implements OnInit, OnDestroy {
 
   onAdd() {
     const dialogRef = this._dialog.open(NewMission)
-    dialogRef.afterClosed().subscribe(async (result: AddMission) => {
+    dialogRef.afterClosed().subscribe(async (result: AddMissionModel) => {
       if (result) {
         try {
           const missionId = await this._mission.add(result)
@@ -158,7 +160,7 @@ export class Missions implements OnInit, OnDestroy {
     })
   }
 
-  onView(mission: Mission) {
+  onView(mission: MissionModel) {
     this.selectedMission = mission
   }
 
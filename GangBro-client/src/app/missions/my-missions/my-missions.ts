@@ -2,12 +2,11 @@ import { Component, inject, signal, computed, OnDestroy } from '@angular/core';
 import { CommonModule } from '@angular/common';
 import { MissionService } from '../../_service/mission-service';
 import { PassportService } from '../../_service/passport-service';
-import { Mission } from '../../_models/mission';
+import { AddMissionModel, MissionModel } from '../../_models/generated';
 import { MatDialog, MatDialogModule } from '@angular/material/dialog';
 import { MatMenuModule } from '@angular/material/menu';
 import { MatButtonModule } from '@angular/material/button';
 import { NewMission } from '../../_dialog/new-mission/new-mission';
-import { AddMission } from '../../_models/add-mission';
 import { Router } from '@angular/router';
 import { MatSnackBar, MatSnackBarModule } from '@angular/material/snack-bar';
 import { MatIconModule } from '@angular/material/icon';
@@ -31,12 +30,12 @@ export class MyMissions implements OnDestroy {
   private _router = inject(Router);
   private _snackBar = inject(MatSnackBar);
 
-  missions = signal<Mission[]>([]);
-  allMissions = signal<Mission[]>([]);
+  missions = signal<MissionModel[]>([]);
+  allMissions = signal<MissionModel[]>([]);
   isLoading = signal(true);
   myId = signal<number | undefined>(undefined);
   selectedMissionId = signal<number | null>(null);
-  selectedMission = signal<Mission | null>(null);
+  selectedMission = signal<MissionModel | null>(null);
 
   isMobile = signal(window.innerWidth <= 768);
   display_name = computed(() => this._passportService.data()?.display_name);
@@ -56,7 +55,7 @@ export class MyMissions implements OnDestroy {
     window.removeEventListener('resize', this._resizeListener);
   }
 
-  handleCardClick(m: Mission) {
+  handleCardClick(m: MissionModel) {
     if (this.isMobile()) {
       this.onViewAbout(m.id);
     } else {
@@ -82,7 +81,7 @@ export class MyMissions implements OnDestroy {
     return 'assignment';
   }
 
-  selectMission(m: Mission) {
+  selectMission(m: MissionModel) {
     if (this.selectedMissionId() === m.id) {
       this.selectedMissionId.set(null);
       this.selectedMission.set(null);
@@ -132,7 +131,7 @@ export class MyMissions implements OnDestroy {
     this._router.navigate(['/about-mission', id]);
   }
 
-  isChief(mission: Mission): boolean {
+  isChief(mission: MissionModel): boolean {
     return mission.chief_id === this.myId();
   }
 
@@ -158,7 +157,7 @@ export class MyMissions implements OnDestroy {
 
   onAdd() {
     const ref = this._dialog.open(NewMission);
-    ref.afterClosed().subscribe(async (result: AddMission) => {
+    ref.afterClosed().subscribe(async (result: AddMissionModel) => {
       if (result) {
         this.performAction(async () => { await this._missionService.add(result) });
       }
@@ -185,10 +184,10 @@ export class MyMissions implements OnDestroy {
     await confirmAndExecute(this._dialog, MISSION_MESSAGES.DELETE, () => this._missionService.delete(id)) && this.loadMissions();
   }
 
-  onEdit(mission: Mission) {
+  onEdit(mission: MissionModel) {
     const ref = this._dialog.open(NewMission, { data: mission });
 
-    ref.afterClosed().subscribe(async (result: AddMission | any) => {
+    ref.afterClosed().subscribe(async (result: AddMissionModel | any) => {
       if (!result) return;
       this.performAction(() => this._missionService.update(mission.id, result));
     });
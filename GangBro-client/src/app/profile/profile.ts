@@ -11,13 +11,12 @@ import { MatIconModule } from '@angular/material/icon'
 import { MatButtonModule } from '@angular/material/button'
 import { MatSnackBar, MatSnackBarModule } from '@angular/material/snack-bar'
 import { ActivatedRoute, Router } from '@angular/router'
-import { Mission } from '../_models/mission'
+import { AddMissionModel, MissionModel } from '../_models/generated'
 import { MissionStatus } from '../_enums/mission-status.enum'
 import { confirmAndExecute } from '../_helpers/dialog.helper'
 import { MISSION_MESSAGES } from '../_constants/messages.constants'
 import { APP_CONFIG } from '../_constants/config.constants'
 import { NewMission } from '../_dialog/new-mission/new-mission'
-import { AddMission } from '../_models/add-mission'
 import { getAvatar } from '../_helpers/util'
 
 @Component({
@@ -43,7 +42,7 @@ export class Profile implements OnDestroy {
   activeTab = signal<'active' | 'archives' | 'training'>('active');
 
   // Updated signal management for missions
-  allMissions = signal<Mission[]>([]);
+  allMissions = signal<MissionModel[]>([]);
   isLoading = signal(true);
   myId = signal<number | undefined>(undefined);
 
@@ -110,7 +109,7 @@ export class Profile implements OnDestroy {
   // Mission Actions integrated from my-missions.ts
   onAdd() {
     const ref = this._dialog.open(NewMission);
-    ref.afterClosed().subscribe(async (result: AddMission) => {
+    ref.afterClosed().subscribe(async (result: AddMissionModel) => {
       if (result) {
         this.performAction(async () => { await this._missionService.add(result) });
       }
@@ -121,18 +120,18 @@ export class Profile implements OnDestroy {
     this._router.navigate(['/about-mission', id]);
   }
 
-  isChief(mission: Mission): boolean {
+  isChief(mission: MissionModel): boolean {
     return mission.chief_id === this.myId();
   }
 
-  getExecuteText(m: Mission): string {
+  getExecuteText(m: MissionModel): string {
     if (!this.isChief(m)) return 'VIEW';
     if (m.status === MissionStatus.Open) return 'START';
     if (m.status === MissionStatus.InProgress) return 'COMPLETE';
     return 'LOCKED';
   }
 
-  onExecute(m: Mission) {
+  onExecute(m: MissionModel) {
     if (!this.isChief(m)) {
       this.onViewAbout(m.id);
       return;
@@ -149,7 +148,7 @@ export class Profile implements OnDestroy {
     await confirmAndExecute(this._dialog, MISSION_MESSAGES.COMPLETE, () => this._missionService.complete(id)) && this.loadMissions();
   }
 
-  onEdit(mission: Mission) {
+  onEdit(mission: MissionModel) {
     const ref = this._dialog.open(NewMission, { data: mission });
     ref.afterClosed().subscribe(async (result: any) => {
       if (!result) return;
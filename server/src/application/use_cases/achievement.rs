@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    domain::{
+        entities::brawler_achievements::AwardAchievementEntity,
+        repositories::{
+            achievement::AchievementRepository,
+            notification_publisher::{NotificationPublisher, NotificationSubscription},
+        },
+        value_objects::{
+            achievement_model::{BrawlerAchievementModel, BrawlerAchievementsModel},
+            achievements::{ACHIEVEMENTS, AchievementDefinition},
+            notification_model::NotificationModel,
+        },
+    },
+};
+
+pub struct AchievementUseCase<T1, T2>
+where
+    T1: AchievementRepository + Send + Sync + ?Sized,
+    T2: NotificationPublisher + Send + Sync + ?Sized,
+{
+    achievement_repository: Arc<T1>,
+    notification_publisher: Arc<T2>,
+}
+
+impl<T1, T2> AchievementUseCase<T1, T2>
+where
+    T1: AchievementRepository + Send + Sync + ?Sized,
+    T2: NotificationPublisher + Send + Sync + ?Sized,
+{
+    pub fn new(achievement_repository: Arc<T1>, notification_publisher: Arc<T2>) -> Self {
+        Self {
+            achievement_repository,
+            notification_publisher,
+        }
+    }
+
+    pub fn get_definitions(&self) -> &'static [AchievementDefinition] {
+        ACHIEVEMENTS
+    }
+
+    /// Awards every badge whose rule the brawler now satisfies and returns the new ones.
+    #[tracing::instrument(name = "achievement.evaluate", skip_all)]
+    pub async fn evaluate(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementModel>> {
+        let progress = self.achievement_repository.get_progress(brawler_id).await?;
+
+        let earned = ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| achievement.rule.is_met(&progress))
+            .map(|achievement| AwardAchievementEntity {
+                brawler_id,
+                achievement_code: achievement.code.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        if earned.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let awarded = self
+            .achievement_repository
+            .award(earned)
+            .await?
+            .iter()
+            .filter_map(|entity| entity.to_model())
+            .collect::<Vec<_>>();
+
+        for achievement in &awarded {
+            self.notification_publisher.publish(
+                brawler_id,
+                NotificationModel::AchievementAwarded(achievement.clone()),
+            );
+        }
+
+        Ok(awarded)
+    }
+
+    #[tracing::instrument(name = "achievement.evaluate_mission", skip_all)]
+    pub async fn evaluate_mission(&self, mission_id: i32) -> Result<Vec<BrawlerAchievementModel>> {
+        let participants = self
+            .achievement_repository
+            .get_participants(mission_id)
+            .await?;
+
+        let mut awarded = Vec::new();
+        for brawler_id in participants {
+            awarded.extend(self.evaluate(brawler_id).await?);
+        }
+
+        Ok(awarded)
+    }
+
+    #[tracing::instrument(name = "achievement.get_brawler_achievements", skip_all)]
+    pub async fn get_brawler_achievements(
+        &self,
+        brawler_id: i32,
+    ) -> Result<BrawlerAchievementsModel> {
+        let progress = self.achievement_repository.get_progress(brawler_id).await?;
+
+        let achievements = self
+            .achievement_repository
+            .get_awarded(brawler_id)
+            .await?
+            .iter()
+            .filter_map(|entity| entity.to_model())
+            .collect();
+
+        Ok(BrawlerAchievementsModel {
+            progress,
+            achievements,
+        })
+    }
+
+    pub fn subscribe(&self, brawler_id: i32) -> NotificationSubscription {
+        self.notification_publisher.subscribe(brawler_id)
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use tracing::warn;
+
+use crate::{
+    domain::{
+        entities::audit_events::AddAuditEventEntity,
+        repositories::audit::AuditRepository,
+        value_objects::{
+            audit_model::{AuditEventFilter, AuditEventModel, MAX_AUDIT_PAGE, diff_snapshots},
+            invalid_filter::InvalidFilter,
+        },
+    },
+    infrastructure::request_context::current_request_id,
+};
+
+/// One thing a brawler did, as handed to `AuditUseCase::record`.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    actor_id: i32,
+    action: &'static str,
+    target_type: &'static str,
+    target_id: i32,
+    details: Value,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl AuditEvent {
+    pub fn new(actor_id: i32, action: &'static str, target_type: &'static str, target_id: i32) -> Self {
+        Self {
+            actor_id,
+            action,
+            target_type,
+            target_id,
+            details: json!({}),
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Snapshots of the target around the change; only differing keys are stored.
+    pub fn change(mut self, before: Option<Value>, after: Option<Value>) -> Self {
+        (self.before, self.after) = diff_snapshots(before, after);
+        self
+    }
+}
+
+pub struct AuditUseCase<T>
+where
+    T: AuditRepository + Send + Sync + ?Sized,
+{
+    audit_repository: Arc<T>,
+}
+
+impl<T> AuditUseCase<T>
+where
+    T: AuditRepository + Send + Sync + ?Sized,
+{
+    pub fn new(audit_repository: Arc<T>) -> Self {
+        Self { audit_repository }
+    }
+
+    /// The audited action has already happened by the time this runs, so a failed
+    /// write is logged rather than surfaced to the caller.
+    #[tracing::instrument(name = "audit.record", skip_all)]
+    pub async fn record(&self, event: AuditEvent) {
+        let entity = AddAuditEventEntity {
+            actor_id: event.actor_id,
+            action: event.action.to_string(),
+            target_type: event.target_type.to_string(),
+            target_id: event.target_id,
+            details: event.details,
+            before: event.before,
+            after: event.after,
+            request_id: current_request_id(),
+        };
+
+        if let Err(e) = self.audit_repository.record(entity).await {
+            warn!(action = event.action, error = %e, "Failed to record audit event");
+        }
+    }
+
+    #[tracing::instrument(name = "audit.query", skip_all)]
+    pub async fn query(&self, filter: &AuditEventFilter) -> Result<Vec<AuditEventModel>> {
+        if filter.page() < 1 || filter.limit() < 1 {
+            return Err(InvalidFilter::new("Page and limit must be positive!").into());
+        }
+        // Keeps the offset from overflowing
+        if filter.page() > MAX_AUDIT_PAGE {
+            return Err(InvalidFilter(format!("Page must be at most {}!", MAX_AUDIT_PAGE)).into());
+        }
+        if let (Some(from), Some(to)) = (filter.from, filter.to) {
+            if from >= to {
+                return Err(InvalidFilter::new("from must be before to!").into());
+            }
+        }
+
+        self.audit_repository.query(filter).await
+    }
+}
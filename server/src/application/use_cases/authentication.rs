@@ -1,40 +1,252 @@
-use std::sync::Arc;
+use std::{fmt, net::IpAddr, sync::Arc};
 
 use anyhow::Result;
 
 use crate::{
-    domain::repositories::brawlers::BrawlerRepository,
+    config::config_model::JwtEnv,
+    domain::{repositories::brawlers::BrawlerRepository, value_objects::roles::Role},
     infrastructure::{
+        access_cache::{AccessCache, BrawlerAccess},
         argon2,
         jwt::{authentication_model::LoginModel, jwt_model::Passport},
+        login_throttle::LoginThrottle,
     },
 };
+
+/// Why a valid token no longer grants access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessDenied {
+    UnknownBrawler,
+    Banned,
+}
+
+impl fmt::Display for AccessDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessDenied::UnknownBrawler => write!(f, "Brawler no longer exists"),
+            AccessDenied::Banned => write!(f, "This brawler has been banned"),
+        }
+    }
+}
+
+impl std::error::Error for AccessDenied {}
+
 pub struct AuthenticationUseCase<T>
 where
-    T: BrawlerRepository + Send + Sync,
+    T: BrawlerRepository + Send + Sync + ?Sized,
 {
     brawler_repository: Arc<T>,
+    login_throttle: Arc<LoginThrottle>,
+    access_cache: Arc<AccessCache>,
+    jwt_env: JwtEnv,
 }
 impl<T> AuthenticationUseCase<T>
 where
-    T: BrawlerRepository + Sync + Send,
+    T: BrawlerRepository + Sync + Send + ?Sized,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T>,
+        login_throttle: Arc<LoginThrottle>,
+        access_cache: Arc<AccessCache>,
+        jwt_env: JwtEnv,
+    ) -> Self {
+        argon2::warm_up();
+        Self {
+            brawler_repository,
+            login_throttle,
+            access_cache,
+            jwt_env,
+        }
+    }
+
+    /// The role a signed-in brawler has now. Tokens outlive bans and role
+    /// changes, so this reads the database (through `AccessCache`) instead of
+    /// trusting the role in the claims. Fails with `AccessDenied` for brawlers
+    /// that were banned or removed.
+    #[tracing::instrument(name = "authentication.authorize", skip_all)]
+    pub async fn authorize(&self, brawler_id: i32) -> Result<Role> {
+        let access = match self.access_cache.get(brawler_id) {
+            Some(access) => access,
+            None => {
+                let brawler = self
+                    .brawler_repository
+                    .find_by_id(brawler_id)
+                    .await?
+                    .ok_or(AccessDenied::UnknownBrawler)?;
+                let access = BrawlerAccess {
+                    role: brawler.role.parse::<Role>()?,
+                    banned: brawler.banned_at.is_some(),
+                };
+                self.access_cache.insert(brawler_id, access);
+                access
+            }
+        };
+
+        if access.banned {
+            return Err(AccessDenied::Banned.into());
+        }
+
+        Ok(access.role)
     }
 
-    pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+    /// Unknown usernames and wrong passwords fail the same way, after the same
+    /// amount of hashing work, so a login can't be used to probe for accounts.
+    #[tracing::instrument(name = "authentication.login", skip_all)]
+    pub async fn login(&self, login_model: LoginModel, client_ip: IpAddr) -> Result<Passport> {
         let username = login_model.username.clone();
+        self.login_throttle.check(&username, client_ip)?;
 
         //find this user in database
-        let user = self.brawler_repository.find_by_username(username).await?;
-        let hashed_password = user.password;
+        let user = self.brawler_repository.find_by_username(username.clone()).await?;
+        let verified = match &user {
+            Some(user) => argon2::verify(login_model.password, user.password.clone())?,
+            None => {
+                argon2::verify_dummy(login_model.password);
+                false
+            }
+        };
+
+        let user = match user {
+            Some(user) if verified => user,
+            _ => {
+                self.login_throttle.record_failure(&username, client_ip);
+                return Err(anyhow::anyhow!("Invalid credentials"));
+            }
+        };
+        self.login_throttle.record_success(&username);
 
-        if !argon2::verify(login_model.password, hashed_password)? {
-            return Err(anyhow::anyhow!("Invalid Password !!"));
+        if user.banned_at.is_some() {
+            return Err(AccessDenied::Banned.into());
         }
 
-        let passport = Passport::new(user.id, user.display_name, user.avatar_url)?;
+        let role = user.role.parse::<Role>()?;
+        let passport = Passport::new(user.id, user.display_name, user.avatar_url, role, &self.jwt_env)?;
         Ok(passport)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            entities::brawlers::BrawlerEntity, repositories::brawlers::MockBrawlerRepository,
+        },
+        infrastructure::login_throttle::TooManyAttempts,
+    };
+    use chrono::NaiveDateTime;
+    use std::{net::Ipv4Addr, sync::LazyLock};
+
+    static PASSWORD_HASH: LazyLock<String> =
+        LazyLock::new(|| argon2::hash("password1".to_string()).expect("hash"));
+
+    const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    fn brawler(role: &str, banned: bool) -> BrawlerEntity {
+        BrawlerEntity {
+            id: 5,
+            username: "rookie".to_string(),
+            password: PASSWORD_HASH.clone(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            display_name: "Rookie".to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            role: role.to_string(),
+            banned_at: banned.then(NaiveDateTime::default),
+            ban_reason: None,
+        }
+    }
+
+    fn login(username: &str, password: &str) -> LoginModel {
+        LoginModel {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    fn use_case(brawler: Option<BrawlerEntity>) -> AuthenticationUseCase<MockBrawlerRepository> {
+        let mut brawler_repository = MockBrawlerRepository::new();
+        brawler_repository
+            .expect_find_by_username()
+            .returning(move |_| Ok(brawler.clone()));
+
+        AuthenticationUseCase::new(
+            Arc::new(brawler_repository),
+            Arc::new(LoginThrottle::new()),
+            Arc::new(AccessCache::new()),
+            JwtEnv {
+                secret: "test-secret".to_string(),
+                lift_time_days: 1,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn login_rules() {
+        let cases = [
+            (
+                "member",
+                Some(brawler("member", false)),
+                "password1",
+                Ok(Role::Member),
+            ),
+            (
+                "admin",
+                Some(brawler("admin", false)),
+                "password1",
+                Ok(Role::Admin),
+            ),
+            (
+                "wrong password",
+                Some(brawler("member", false)),
+                "password2",
+                Err("Invalid credentials"),
+            ),
+            (
+                "unknown user",
+                None,
+                "password1",
+                Err("Invalid credentials"),
+            ),
+            (
+                "banned",
+                Some(brawler("member", true)),
+                "password1",
+                Err("This brawler has been banned"),
+            ),
+        ];
+
+        for (name, brawler, password, expected) in cases {
+            let use_case = use_case(brawler);
+
+            let result = use_case.login(login("rookie", password), CLIENT_IP).await;
+
+            assert_eq!(
+                result
+                    .map(|passport| passport.role)
+                    .map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn repeated_failures_are_throttled() {
+        let use_case = use_case(Some(brawler("member", false)));
+
+        let mut throttled = false;
+        for _ in 0..10 {
+            if let Err(e) = use_case
+                .login(login("rookie", "password2"), CLIENT_IP)
+                .await
+            {
+                throttled |= e.downcast_ref::<TooManyAttempts>().is_some();
+            }
+        }
+
+        assert!(throttled);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
+    application::use_cases::achievement::AchievementUseCase,
+    config::config_model::JwtEnv,
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        repositories::{
+            achievement::AchievementRepository, brawlers::BrawlerRepository,
+            image_storage::ImageStorage, notification_publisher::NotificationPublisher,
+        },
         value_objects::{
-            base64_image::Base64Image, brawler_model::RegisterBrawlerModel,
-            mission_model::MissionModel, uploaded_image::UploadedImage,
+            base64_image::Base64Image,
+            brawler_model::{BrawlerProfileModel, RegisterBrawlerModel},
+            mission_model::MissionModel, roles::Role, uploaded_image::UploadedImage,
         },
     },
     infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
@@ -11,21 +17,41 @@ use crate::{
 use anyhow::{Ok, Result};
 use std::sync::Arc;
 
-pub struct BrawlersUseCase<T>
+pub struct BrawlersUseCase<T1, T2, T3, T4>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    image_storage: Arc<T2>,
+    achievement_use_case: Arc<AchievementUseCase<T3, T4>>,
+    jwt_env: JwtEnv,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2, T3, T4> BrawlersUseCase<T1, T2, T3, T4>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        image_storage: Arc<T2>,
+        achievement_use_case: Arc<AchievementUseCase<T3, T4>>,
+        jwt_env: JwtEnv,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            image_storage,
+            achievement_use_case,
+            jwt_env,
+        }
     }
 
+    #[tracing::instrument(name = "brawlers.register", skip_all)]
     pub async fn register(
         &self,
         mut register_brawler_model: RegisterBrawlerModel,
@@ -36,11 +62,14 @@ where
 
         let register_entity = register_brawler_model.to_entity();
 
-        let passport = self.brawler_repository.register(register_entity).await?;
+        let display_name = register_entity.display_name.clone();
+        let user_id = self.brawler_repository.register(register_entity).await?;
+        let passport = Passport::new(user_id, display_name, None, Role::Member, &self.jwt_env)?;
 
         Ok(passport)
     }
 
+    #[tracing::instrument(name = "brawlers.upload_base64img", skip_all)]
     pub async fn upload_base64img(
         &self,
         user_id: i32,
@@ -54,16 +83,243 @@ where
 
         let base64img = Base64Image::new(base64string)?;
 
-        let uploaded = self
-            .brawler_repository
-            .upload_base64img(user_id, base64img, opt)
+        let uploaded = self.image_storage.upload(base64img, opt).await?;
+        self.brawler_repository
+            .update_avatar(user_id, uploaded.clone())
             .await?;
 
         Ok(uploaded)
     }
 
+    #[tracing::instrument(name = "brawlers.get_missions", skip_all)]
     pub async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
         let missions = self.brawler_repository.get_missions(brawler_id).await?;
         Ok(missions)
     }
-}
\ No newline at end of file
+
+    #[tracing::instrument(name = "brawlers.get_profile", skip_all)]
+    pub async fn get_profile(&self, brawler_id: i32) -> Result<BrawlerProfileModel> {
+        let brawler = self
+            .brawler_repository
+            .find_by_id(brawler_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Brawler not found"))?;
+
+        let achievements = self
+            .achievement_use_case
+            .get_brawler_achievements(brawler_id)
+            .await?;
+
+        Ok(BrawlerProfileModel {
+            brawler_id: brawler.id,
+            display_name: brawler.display_name,
+            avatar_url: brawler.avatar_url,
+            role: brawler.role.parse()?,
+            achievements,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::achievement_use_case,
+        domain::{
+            entities::{
+                brawler_achievements::BrawlerAchievementEntity, brawlers::BrawlerEntity,
+            },
+            repositories::{
+                achievement::MockAchievementRepository, brawlers::MockBrawlerRepository,
+                image_storage::MockImageStorage, notification_publisher::MockNotificationPublisher,
+            },
+            value_objects::achievement_model::AchievementProgressModel,
+        },
+        infrastructure::argon2,
+    };
+    use chrono::NaiveDateTime;
+    use std::result::Result::Ok;
+
+    // The 8-byte PNG signature is enough for the type sniffing
+    const PNG: &str = "iVBORw0KGgo=";
+
+    fn jwt_env() -> JwtEnv {
+        JwtEnv {
+            secret: "test-secret".to_string(),
+            lift_time_days: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn register_stores_a_hash_and_returns_a_member_passport() {
+        let mut brawler_repository = MockBrawlerRepository::new();
+        brawler_repository
+            .expect_register()
+            .withf(|entity| {
+                entity.username == "rookie"
+                    && entity.password != "password1"
+                    && argon2::verify("password1".to_string(), entity.password.clone())
+                        .unwrap_or(false)
+            })
+            .times(1)
+            .returning(|_| Ok(42));
+        let use_case = BrawlersUseCase::new(
+            Arc::new(brawler_repository),
+            Arc::new(MockImageStorage::new()),
+            achievement_use_case(),
+            jwt_env(),
+        );
+
+        let passport = use_case
+            .register(RegisterBrawlerModel {
+                username: "rookie".to_string(),
+                password: "password1".to_string(),
+                display_name: "Rookie".to_string(),
+                upload_avatar: None,
+            })
+            .await
+            .expect("register succeeds");
+
+        assert_eq!(passport.user_id, 42);
+        assert_eq!(passport.display_name, "Rookie");
+        assert_eq!(passport.role, Role::Member);
+        assert!(!passport.token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn avatar_upload_rules() {
+        let cases = [
+            ("png", PNG, Ok(()), true),
+            (
+                "empty",
+                "",
+                Err("Base64 image data cannot be empty !!"),
+                false,
+            ),
+            (
+                "not base64",
+                "%%%",
+                Err("Invalid base64 image data."),
+                false,
+            ),
+            (
+                "not an image",
+                "aGVsbG8=",
+                Err("Unsupported or invalid base64 image type."),
+                false,
+            ),
+            ("storage down", PNG, Err("storage down"), true),
+        ];
+
+        for (name, data, expected, reaches_storage) in cases {
+            let storage_result = expected;
+            let mut image_storage = MockImageStorage::new();
+            image_storage
+                .expect_upload()
+                .withf(|_, opt| {
+                    opt.folder.as_deref() == Some("avatar") && opt.public_id.as_deref() == Some("7")
+                })
+                .times(usize::from(reaches_storage))
+                .returning(move |_, _| match storage_result {
+                    Ok(()) => Ok(UploadedImage::new(
+                        "https://img/7.png".to_string(),
+                        "avatar/7".to_string(),
+                    )),
+                    Err(e) => Err(anyhow::anyhow!(e)),
+                });
+
+            // Only a stored image is linked to the brawler
+            let mut brawler_repository = MockBrawlerRepository::new();
+            brawler_repository
+                .expect_update_avatar()
+                .withf(|user_id, uploaded| *user_id == 7 && uploaded.url == "https://img/7.png")
+                .times(usize::from(expected.is_ok()))
+                .returning(|_, _| Ok(()));
+
+            let use_case = BrawlersUseCase::new(
+                Arc::new(brawler_repository),
+                Arc::new(image_storage),
+                achievement_use_case(),
+                jwt_env(),
+            );
+
+            let result = use_case.upload_base64img(7, data.to_string()).await;
+
+            assert_eq!(
+                result
+                    .map(|uploaded| uploaded.public_id)
+                    .map_err(|e| e.to_string()),
+                expected
+                    .map(|_| "avatar/7".to_string())
+                    .map_err(str::to_string),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn profile_embeds_the_earned_badges() {
+        let mut brawler_repository = MockBrawlerRepository::new();
+        brawler_repository.expect_find_by_id().returning(|brawler_id| {
+            Ok((brawler_id == 7).then(|| BrawlerEntity {
+                id: 7,
+                username: "rookie".to_string(),
+                password: "hash".to_string(),
+                created_at: NaiveDateTime::default(),
+                updated_at: NaiveDateTime::default(),
+                display_name: "Rookie".to_string(),
+                avatar_url: None,
+                avatar_public_id: None,
+                role: "moderator".to_string(),
+                banned_at: None,
+                ban_reason: None,
+            }))
+        });
+
+        let mut achievement_repository = MockAchievementRepository::new();
+        achievement_repository
+            .expect_get_progress()
+            .returning(|_| {
+                Ok(AchievementProgressModel {
+                    missions_joined: 1,
+                    ..AchievementProgressModel::default()
+                })
+            });
+        achievement_repository.expect_get_awarded().returning(|brawler_id| {
+            Ok(vec![BrawlerAchievementEntity {
+                brawler_id,
+                achievement_code: "first_mission_joined".to_string(),
+                awarded_at: NaiveDateTime::default(),
+            }])
+        });
+
+        let use_case = BrawlersUseCase::new(
+            Arc::new(brawler_repository),
+            Arc::new(MockImageStorage::new()),
+            Arc::new(AchievementUseCase::new(
+                Arc::new(achievement_repository),
+                Arc::new(MockNotificationPublisher::new()),
+            )),
+            jwt_env(),
+        );
+
+        let profile = use_case.get_profile(7).await.expect("profile loads");
+        assert_eq!(profile.display_name, "Rookie");
+        assert_eq!(profile.role, Role::Moderator);
+        assert_eq!(profile.achievements.progress.missions_joined, 1);
+        let codes = profile
+            .achievements
+            .achievements
+            .iter()
+            .map(|achievement| achievement.code.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["first_mission_joined"]);
+
+        let missing = use_case.get_profile(8).await;
+        assert_eq!(
+            missing.map(|_| ()).map_err(|e| e.to_string()),
+            Err("Brawler not found".to_string())
+        );
+    }
+}
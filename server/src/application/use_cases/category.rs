@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::categories::DEFAULT_CATEGORY_SLUG,
+    repositories::category::CategoryRepository,
+    value_objects::category_model::{AddCategoryModel, CategoryModel, slugify},
+};
+
+const MAX_CATEGORY_LENGTH: usize = 50;
+
+pub struct CategoryUseCase<T>
+where
+    T: CategoryRepository + Send + Sync + ?Sized,
+{
+    category_repository: Arc<T>,
+}
+
+impl<T> CategoryUseCase<T>
+where
+    T: CategoryRepository + Send + Sync + ?Sized,
+{
+    pub fn new(category_repository: Arc<T>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "category.get_all", skip_all)]
+    pub async fn get_all(&self) -> Result<Vec<CategoryModel>> {
+        self.category_repository.get_all().await
+    }
+
+    #[tracing::instrument(name = "category.add", skip_all)]
+    pub async fn add(&self, add_category_model: AddCategoryModel) -> Result<String> {
+        let label = add_category_model.label.trim();
+        if label.is_empty() || label.chars().count() > MAX_CATEGORY_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Category label must be between 1 and {} characters long!",
+                MAX_CATEGORY_LENGTH
+            ));
+        }
+
+        let slug = slugify(add_category_model.slug.as_deref().unwrap_or(label));
+        if slug.is_empty() || slug.len() > MAX_CATEGORY_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Category slug must be between 1 and {} characters long!",
+                MAX_CATEGORY_LENGTH
+            ));
+        }
+
+        for existing in [slug.clone(), label.to_string()] {
+            if self.category_repository.find_active(existing).await?.is_some() {
+                return Err(anyhow::anyhow!("Category already exists!"));
+            }
+        }
+
+        self.category_repository
+            .add(add_category_model.to_entity(slug))
+            .await
+    }
+
+    #[tracing::instrument(name = "category.retire", skip_all)]
+    pub async fn retire(&self, slug: String) -> Result<()> {
+        if slug == DEFAULT_CATEGORY_SLUG {
+            return Err(anyhow::anyhow!("The default category cannot be retired!"));
+        }
+
+        self.category_repository.retire(slug).await
+    }
+}
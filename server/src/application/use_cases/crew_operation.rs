@@ -1,41 +1,71 @@
-use crate::domain::{
-    entities::crew_memberships::CrewMembershipEntity,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::{
+        achievement::AchievementUseCase,
+        audit::{AuditEvent, AuditUseCase},
+    },
+    domain::{
+        entities::crew_memberships::CrewMembershipEntity,
+        repositories::{
+            achievement::AchievementRepository, audit::AuditRepository,
+            crew_operation::CrewOperationRepository,
+            mission_metrics::MissionMetrics,
+            mission_viewing::MissionViewingRepository,
+            notification_publisher::NotificationPublisher,
+        },
+        value_objects::mission_statuses::MissionStatuses,
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 
 use anyhow::Result;
+use serde_json::json;
 use std::sync::Arc;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T1, T2, T3, T4, T5, T6>
 where
-    T1: CrewOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T1: CrewOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: AuditRepository + Send + Sync + ?Sized,
+    T5: NotificationPublisher + Send + Sync + ?Sized,
+    T6: MissionMetrics + Send + Sync + ?Sized,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    achievement_use_case: Arc<AchievementUseCase<T3, T5>>,
+    audit_use_case: Arc<AuditUseCase<T4>>,
+    mission_metrics: Arc<T6>,
+    max_crew_per_mission: u32,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T1, T2, T3, T4, T5, T6> CrewOperationUseCase<T1, T2, T3, T4, T5, T6>
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T1: CrewOperationRepository + Send + Sync + 'static + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: AuditRepository + Send + Sync + ?Sized,
+    T5: NotificationPublisher + Send + Sync + ?Sized,
+    T6: MissionMetrics + Send + Sync + ?Sized,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        achievement_use_case: Arc<AchievementUseCase<T3, T5>>,
+        audit_use_case: Arc<AuditUseCase<T4>>,
+        mission_metrics: Arc<T6>,
+        max_crew_per_mission: u32,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            achievement_use_case,
+            audit_use_case,
+            mission_metrics,
+            max_crew_per_mission,
         }
     }
 
+    #[tracing::instrument(name = "crew_operation.join", skip_all)]
     pub async fn join(&self, mission_id: i32, brawler_id: i32, allow_bypass: bool) -> Result<()> {
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .trim()
-            .parse()?;
-
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
         if !allow_bypass && mission.chief_id == brawler_id {
@@ -54,7 +84,7 @@ where
         if !mission_status_condition {
             return Err(anyhow::anyhow!("Mission is not joinable"));
         }
-        let crew_count_condition = crew_count < max_crew_per_mission;
+        let crew_count_condition = crew_count < i64::from(self.max_crew_per_mission);
         if !crew_count_condition {
             return Err(anyhow::anyhow!("Mission is full"));
         }
@@ -66,9 +96,19 @@ where
             }, allow_bypass)
             .await?;
 
+        self.audit_use_case
+            .record(
+                AuditEvent::new(brawler_id, "crew.join", "mission", mission_id)
+                    .details(json!({ "bypass": allow_bypass })),
+            )
+            .await;
+        self.mission_metrics.crew_joined();
+        let _ = self.achievement_use_case.evaluate(brawler_id).await;
+
         Ok(())
     }
 
+    #[tracing::instrument(name = "crew_operation.leave", skip_all)]
     pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
@@ -84,6 +124,187 @@ where
             })
             .await?;
 
+        self.audit_use_case
+            .record(AuditEvent::new(brawler_id, "crew.leave", "mission", mission_id))
+            .await;
+        self.mission_metrics.crew_left();
+        let _ = self.achievement_use_case.evaluate(brawler_id).await;
+
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::{
+            achievement_use_case, audit_use_case, mission, MAX_CREW_PER_MISSION,
+        },
+        domain::repositories::{
+            achievement::MockAchievementRepository, audit::MockAuditRepository,
+            crew_operation::MockCrewOperationRepository,
+            mission_metrics::MockMissionMetrics,
+            mission_viewing::MockMissionViewingRepository,
+            notification_publisher::MockNotificationPublisher,
+        },
+    };
+
+    const CHIEF_ID: i32 = 1;
+    const BRAWLER_ID: i32 = 2;
+
+    type TestUseCase = CrewOperationUseCase<
+        MockCrewOperationRepository,
+        MockMissionViewingRepository,
+        MockAchievementRepository,
+        MockAuditRepository,
+        MockNotificationPublisher,
+        MockMissionMetrics,
+    >;
+
+    /// `joins` and `leaves` are how often the rules should let the write through.
+    fn use_case(
+        status: MissionStatuses,
+        crew_count: i64,
+        joins: usize,
+        leaves: usize,
+    ) -> TestUseCase {
+        let mut mission_viewing = MockMissionViewingRepository::new();
+        mission_viewing
+            .expect_get_one()
+            .returning(move |_| Ok(mission(CHIEF_ID, status.clone())));
+        mission_viewing
+            .expect_crew_counting()
+            .returning(move |_| Ok(crew_count));
+
+        let mut crew_operation = MockCrewOperationRepository::new();
+        crew_operation
+            .expect_join()
+            .times(joins)
+            .returning(|_, _| Ok(()));
+        crew_operation
+            .expect_leave()
+            .times(leaves)
+            .returning(|_| Ok(()));
+
+        let mut mission_metrics = MockMissionMetrics::new();
+        mission_metrics.expect_crew_joined().times(joins).return_const(());
+        mission_metrics.expect_crew_left().times(leaves).return_const(());
+
+        CrewOperationUseCase::new(
+            Arc::new(crew_operation),
+            Arc::new(mission_viewing),
+            achievement_use_case(),
+            audit_use_case(),
+            Arc::new(mission_metrics),
+            MAX_CREW_PER_MISSION,
+        )
+    }
+
+    #[tokio::test]
+    async fn join_rules() {
+        let max = i64::from(MAX_CREW_PER_MISSION);
+        let cases = [
+            (
+                "open with room",
+                MissionStatuses::Open,
+                1,
+                BRAWLER_ID,
+                false,
+                Ok(()),
+            ),
+            (
+                "failed missions reopen",
+                MissionStatuses::Failed,
+                1,
+                BRAWLER_ID,
+                false,
+                Ok(()),
+            ),
+            (
+                "last seat",
+                MissionStatuses::Open,
+                max - 1,
+                BRAWLER_ID,
+                false,
+                Ok(()),
+            ),
+            (
+                "full",
+                MissionStatuses::Open,
+                max,
+                BRAWLER_ID,
+                false,
+                Err("Mission is full"),
+            ),
+            (
+                "in progress",
+                MissionStatuses::InProgress,
+                1,
+                BRAWLER_ID,
+                false,
+                Err("Mission is not joinable"),
+            ),
+            (
+                "completed",
+                MissionStatuses::Completed,
+                1,
+                BRAWLER_ID,
+                false,
+                Err("Mission is not joinable"),
+            ),
+            (
+                "chief self-join",
+                MissionStatuses::Open,
+                1,
+                CHIEF_ID,
+                false,
+                Err("Chiefs cannot join their own missions as crew members"),
+            ),
+            (
+                "chief with bypass",
+                MissionStatuses::Open,
+                0,
+                CHIEF_ID,
+                true,
+                Ok(()),
+            ),
+        ];
+
+        for (name, status, crew_count, brawler_id, bypass, expected) in cases {
+            let use_case = use_case(status, crew_count, usize::from(expected.is_ok()), 0);
+
+            let result = use_case.join(1, brawler_id, bypass).await;
+
+            assert_eq!(
+                result.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn leave_rules() {
+        let cases = [
+            (MissionStatuses::Open, Ok(())),
+            (MissionStatuses::Failed, Ok(())),
+            (MissionStatuses::InProgress, Err("Mission is not leavable")),
+            (MissionStatuses::Completed, Err("Mission is not leavable")),
+        ];
+
+        for (status, expected) in cases {
+            let use_case = use_case(status.clone(), 2, 0, usize::from(expected.is_ok()));
+
+            let result = use_case.leave(1, BRAWLER_ID).await;
+
+            assert_eq!(
+                result.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "{}",
+                status
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::domain::{
+    repositories::{health::HealthRepository, image_storage::ImageStorage},
+    value_objects::health_model::{HealthCheckModel, HealthStatus, ReadinessModel},
+};
+
+/// Longest a single check may take before it counts as failed.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How long a storage check is reused, so frequent probes don't spend the
+/// image host's API quota.
+const STORAGE_CHECK_TTL: Duration = Duration::from_secs(60);
+
+async fn timed<F>(check: F) -> HealthCheckModel
+where
+    F: Future<Output = Result<()>>,
+{
+    let started_at = Instant::now();
+    let result = match tokio::time::timeout(CHECK_TIMEOUT, check).await {
+        Ok(result) => result,
+        Err(_) => Err(anyhow::anyhow!("Timed out after {}s", CHECK_TIMEOUT.as_secs())),
+    };
+
+    HealthCheckModel {
+        status: if result.is_ok() { HealthStatus::Ok } else { HealthStatus::Fail },
+        latency_ms: started_at.elapsed().as_millis() as u64,
+        error: result.err().map(|e| format!("{:#}", e)),
+    }
+}
+
+pub struct HealthUseCase<T1, T2>
+where
+    T1: HealthRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+{
+    health_repository: Arc<T1>,
+    image_storage: Arc<T2>,
+    storage_check: Mutex<Option<(Instant, HealthCheckModel)>>,
+}
+
+impl<T1, T2> HealthUseCase<T1, T2>
+where
+    T1: HealthRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+{
+    pub fn new(health_repository: Arc<T1>, image_storage: Arc<T2>) -> Self {
+        Self {
+            health_repository,
+            image_storage,
+            storage_check: Mutex::new(None),
+        }
+    }
+
+    /// Only image storage that is configured gets pinged, and its last result
+    /// is reused for `STORAGE_CHECK_TTL`.
+    async fn storage(&self) -> HealthCheckModel {
+        if !self.image_storage.is_enabled() {
+            return HealthCheckModel::disabled();
+        }
+        if let Some((checked_at, check)) = self.storage_check.lock().unwrap().as_ref() {
+            if checked_at.elapsed() < STORAGE_CHECK_TTL {
+                return check.clone();
+            }
+        }
+
+        let mut check = timed(self.image_storage.ping()).await;
+        if check.status == HealthStatus::Fail {
+            check.status = HealthStatus::Degraded;
+        }
+        *self.storage_check.lock().unwrap() = Some((Instant::now(), check.clone()));
+
+        check
+    }
+
+    /// Runs every check concurrently. Only the database and migrations can
+    /// fail readiness: without image storage the API still serves everything
+    /// but uploads, so an unreachable one degrades it and a missing one is
+    /// reported as disabled.
+    #[tracing::instrument(name = "health.readiness", skip_all)]
+    pub async fn readiness(&self) -> ReadinessModel {
+        let migrations = async {
+            match self.health_repository.pending_migrations().await? {
+                0 => Ok(()),
+                pending => Err(anyhow::anyhow!("{} migrations have not run", pending)),
+            }
+        };
+        let (database, migrations, storage) = tokio::join!(
+            timed(self.health_repository.ping()),
+            timed(migrations),
+            self.storage(),
+        );
+
+        ReadinessModel::new(BTreeMap::from([
+            ("database".to_string(), database),
+            ("migrations".to_string(), migrations),
+            ("storage".to_string(), storage),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::{
+        health::MockHealthRepository, image_storage::MockImageStorage,
+    };
+
+    fn health_repository(pending_migrations: usize) -> MockHealthRepository {
+        let mut repository = MockHealthRepository::new();
+        repository.expect_ping().returning(|| Ok(()));
+        repository
+            .expect_pending_migrations()
+            .returning(move || Ok(pending_migrations));
+        repository
+    }
+
+    fn image_storage(enabled: bool) -> MockImageStorage {
+        let mut storage = MockImageStorage::new();
+        storage.expect_is_enabled().return_const(enabled);
+        storage
+            .expect_ping()
+            .times(..=1)
+            .returning(|| Err(anyhow::anyhow!("401 Unauthorized")));
+        storage
+    }
+
+    #[tokio::test]
+    async fn ready_without_optional_storage() {
+        let use_case = HealthUseCase::new(Arc::new(health_repository(0)), Arc::new(image_storage(false)));
+
+        let readiness = use_case.readiness().await;
+        assert_eq!(readiness.status, HealthStatus::Ok);
+        assert_eq!(readiness.checks["database"].status, HealthStatus::Ok);
+        assert_eq!(readiness.checks["storage"].status, HealthStatus::Disabled);
+    }
+
+    #[tokio::test]
+    async fn pending_migrations_fail_readiness() {
+        let use_case = HealthUseCase::new(Arc::new(health_repository(2)), Arc::new(image_storage(false)));
+
+        let readiness = use_case.readiness().await;
+        assert_eq!(readiness.status, HealthStatus::Fail);
+        assert_eq!(readiness.checks["migrations"].status, HealthStatus::Fail);
+        assert_eq!(
+            readiness.checks["migrations"].error.as_deref(),
+            Some("2 migrations have not run")
+        );
+    }
+
+    #[tokio::test]
+    async fn unreachable_configured_storage_only_degrades_readiness() {
+        let use_case = HealthUseCase::new(Arc::new(health_repository(0)), Arc::new(image_storage(true)));
+
+        let readiness = use_case.readiness().await;
+        assert_eq!(readiness.status, HealthStatus::Degraded);
+        assert_eq!(readiness.checks["storage"].status, HealthStatus::Degraded);
+        assert_eq!(readiness.checks["storage"].error.as_deref(), Some("401 Unauthorized"));
+
+        // Served from the cache; the mock allows a single ping
+        let readiness = use_case.readiness().await;
+        assert_eq!(readiness.checks["storage"].status, HealthStatus::Degraded);
+    }
+}
@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::{
+    application::use_cases::moderation::normalize_reason,
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        repositories::{
+            brawlers::BrawlerRepository, maintenance::MaintenanceRepository,
+            moderation::ModerationRepository,
+        },
+        value_objects::{brawler_model::RegisterBrawlerModel, roles::Role},
+    },
+    infrastructure::argon2::hash,
+};
+
+/// What `gangbro-admin` does to brawlers and missions. Brawlers are named by
+/// username, as operators know them. Nothing here is audited: the audit trail
+/// records which brawler acted, and an operator is none.
+pub struct MaintenanceUseCase<T1, T2, T3>
+where
+    T1: MaintenanceRepository + Send + Sync + ?Sized,
+    T2: BrawlerRepository + Send + Sync + ?Sized,
+    T3: ModerationRepository + Send + Sync + ?Sized,
+{
+    maintenance_repository: Arc<T1>,
+    brawler_repository: Arc<T2>,
+    moderation_repository: Arc<T3>,
+}
+
+impl<T1, T2, T3> MaintenanceUseCase<T1, T2, T3>
+where
+    T1: MaintenanceRepository + Send + Sync + ?Sized,
+    T2: BrawlerRepository + Send + Sync + ?Sized,
+    T3: ModerationRepository + Send + Sync + ?Sized,
+{
+    pub fn new(
+        maintenance_repository: Arc<T1>,
+        brawler_repository: Arc<T2>,
+        moderation_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            maintenance_repository,
+            brawler_repository,
+            moderation_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "maintenance.find_brawler", skip_all)]
+    pub async fn find_brawler(&self, username: &str) -> Result<BrawlerEntity> {
+        self.brawler_repository
+            .find_by_username(username.to_string())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("No brawler is named {}", username))
+    }
+
+    #[tracing::instrument(name = "maintenance.create_admin", skip_all)]
+    pub async fn create_admin(&self, mut register_brawler_model: RegisterBrawlerModel) -> Result<i32> {
+        if register_brawler_model.password.is_empty() {
+            return Err(anyhow::anyhow!("Password must not be empty!"));
+        }
+        if self
+            .brawler_repository
+            .find_by_username(register_brawler_model.username.clone())
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!(
+                "{} is already taken",
+                register_brawler_model.username
+            ));
+        }
+
+        register_brawler_model.password = hash(register_brawler_model.password)?;
+        self.maintenance_repository
+            .register_with_role(register_brawler_model.to_entity(), Role::Admin)
+            .await
+    }
+
+    #[tracing::instrument(name = "maintenance.reset_password", skip_all)]
+    pub async fn reset_password(&self, username: &str, password: String) -> Result<i32> {
+        if password.is_empty() {
+            return Err(anyhow::anyhow!("Password must not be empty!"));
+        }
+
+        let brawler = self.find_brawler(username).await?;
+        self.maintenance_repository
+            .set_password(brawler.id, hash(password)?)
+            .await?;
+
+        Ok(brawler.id)
+    }
+
+    #[tracing::instrument(name = "maintenance.ban", skip_all)]
+    pub async fn ban(&self, username: &str, reason: Option<String>) -> Result<i32> {
+        let reason = normalize_reason(reason)?;
+        let brawler = self.find_brawler(username).await?;
+        self.moderation_repository.ban(brawler.id, reason).await?;
+
+        Ok(brawler.id)
+    }
+
+    #[tracing::instrument(name = "maintenance.unban", skip_all)]
+    pub async fn unban(&self, username: &str) -> Result<i32> {
+        let brawler = self.find_brawler(username).await?;
+        self.moderation_repository.unban(brawler.id).await?;
+
+        Ok(brawler.id)
+    }
+
+    /// Returns the ids of the purged missions.
+    #[tracing::instrument(name = "maintenance.purge_deleted_missions", skip_all)]
+    pub async fn purge_deleted_missions(&self, older_than_days: u32) -> Result<Vec<i32>> {
+        let deleted_before = Utc::now().naive_utc() - Duration::days(older_than_days.into());
+
+        self.maintenance_repository
+            .purge_deleted_missions(deleted_before)
+            .await
+    }
+
+    #[tracing::instrument(name = "maintenance.brawler_ids", skip_all)]
+    pub async fn brawler_ids(&self) -> Result<Vec<i32>> {
+        self.maintenance_repository.brawler_ids().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::repositories::{
+            brawlers::MockBrawlerRepository, maintenance::MockMaintenanceRepository,
+            moderation::MockModerationRepository,
+        },
+        infrastructure::argon2,
+    };
+    use chrono::NaiveDateTime;
+
+    fn brawler(id: i32, username: &str) -> BrawlerEntity {
+        BrawlerEntity {
+            id,
+            username: username.to_string(),
+            password: String::new(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            display_name: username.to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            role: Role::Member.to_string(),
+            banned_at: None,
+            ban_reason: None,
+        }
+    }
+
+    fn brawler_repository(existing: Option<BrawlerEntity>) -> MockBrawlerRepository {
+        let mut brawler_repository = MockBrawlerRepository::new();
+        brawler_repository
+            .expect_find_by_username()
+            .returning(move |_| Ok(existing.clone()));
+        brawler_repository
+    }
+
+    fn register(username: &str) -> RegisterBrawlerModel {
+        RegisterBrawlerModel {
+            username: username.to_string(),
+            password: "password1".to_string(),
+            display_name: "Boss".to_string(),
+            upload_avatar: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_admin_registers_as_admin() {
+        let mut maintenance_repository = MockMaintenanceRepository::new();
+        maintenance_repository
+            .expect_register_with_role()
+            .withf(|entity, role| {
+                *role == Role::Admin
+                    && argon2::verify("password1".to_string(), entity.password.clone())
+                        .unwrap_or(false)
+            })
+            .times(1)
+            .returning(|_, _| Ok(7));
+        let use_case = MaintenanceUseCase::new(
+            Arc::new(maintenance_repository),
+            Arc::new(brawler_repository(None)),
+            Arc::new(MockModerationRepository::new()),
+        );
+
+        assert_eq!(use_case.create_admin(register("boss")).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn create_admin_refuses_a_taken_username() {
+        let use_case = MaintenanceUseCase::new(
+            Arc::new(MockMaintenanceRepository::new()),
+            Arc::new(brawler_repository(Some(brawler(3, "boss")))),
+            Arc::new(MockModerationRepository::new()),
+        );
+
+        let error = use_case.create_admin(register("boss")).await.unwrap_err();
+        assert_eq!(error.to_string(), "boss is already taken");
+    }
+
+    #[tokio::test]
+    async fn reset_password_stores_a_hash_for_the_named_brawler() {
+        let mut maintenance_repository = MockMaintenanceRepository::new();
+        maintenance_repository
+            .expect_set_password()
+            .withf(|brawler_id, hashed_password| {
+                *brawler_id == 3
+                    && argon2::verify("new-password".to_string(), hashed_password.clone())
+                        .unwrap_or(false)
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let use_case = MaintenanceUseCase::new(
+            Arc::new(maintenance_repository),
+            Arc::new(brawler_repository(Some(brawler(3, "rookie")))),
+            Arc::new(MockModerationRepository::new()),
+        );
+
+        assert_eq!(
+            use_case
+                .reset_password("rookie", "new-password".to_string())
+                .await
+                .unwrap(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_usernames_are_reported() {
+        let use_case = MaintenanceUseCase::new(
+            Arc::new(MockMaintenanceRepository::new()),
+            Arc::new(brawler_repository(None)),
+            Arc::new(MockModerationRepository::new()),
+        );
+
+        let error = use_case.unban("ghost").await.unwrap_err();
+        assert_eq!(error.to_string(), "No brawler is named ghost");
+    }
+}
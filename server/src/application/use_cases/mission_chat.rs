@@ -5,28 +5,76 @@ use crate::domain::value_objects::mission_chat_model::MissionChatModel;
 
 pub struct MissionChatUseCase<T>
 where
-    T: MissionChatRepository + Send + Sync,
+    T: MissionChatRepository + Send + Sync + ?Sized,
 {
     repo: Arc<T>,
 }
 
 impl<T> MissionChatUseCase<T>
 where
-    T: MissionChatRepository + Send + Sync,
+    T: MissionChatRepository + Send + Sync + ?Sized,
 {
     pub fn new(repo: Arc<T>) -> Self {
         Self { repo }
     }
 
+    #[tracing::instrument(name = "mission_chat.add_message", skip_all)]
     pub async fn add_message(&self, mission_id: i32, brawler_id: i32, message: &str) -> Result<MissionChatModel> {
         self.repo.add_message(mission_id, brawler_id, message).await
     }
 
+    #[tracing::instrument(name = "mission_chat.get_messages", skip_all)]
     pub async fn get_messages(&self, mission_id: i32) -> Result<Vec<MissionChatModel>> {
         self.repo.get_messages(mission_id).await
     }
 
+    #[tracing::instrument(name = "mission_chat.delete_messages", skip_all)]
     pub async fn delete_messages(&self, mission_id: i32) -> Result<()> {
         self.repo.delete_messages(mission_id).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::mission_chat::MockMissionChatRepository;
+    use chrono::NaiveDateTime;
+
+    fn chat(mission_id: i32, brawler_id: i32, message: &str) -> MissionChatModel {
+        MissionChatModel {
+            id: 1,
+            mission_id,
+            brawler_id,
+            brawler_name: "Brawler".to_string(),
+            message: message.to_string(),
+            created_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn messages_pass_through_to_the_repository() {
+        let mut repository = MockMissionChatRepository::new();
+        repository
+            .expect_add_message()
+            .times(1)
+            .withf(|mission_id, brawler_id, message| {
+                *mission_id == 7 && *brawler_id == 3 && message == "on my way"
+            })
+            .returning(|mission_id, brawler_id, message| Ok(chat(mission_id, brawler_id, message)));
+        repository
+            .expect_get_messages()
+            .times(1)
+            .returning(|mission_id| Ok(vec![chat(mission_id, 3, "on my way")]));
+        repository
+            .expect_delete_messages()
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = MissionChatUseCase::new(Arc::new(repository));
+
+        let added = use_case.add_message(7, 3, "on my way").await.unwrap();
+        assert_eq!((added.mission_id, added.brawler_id), (7, 3));
+        let messages = use_case.get_messages(7).await.unwrap();
+        assert_eq!(messages.len(), 1);
+        use_case.delete_messages(7).await.unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::domain::{
+    entities::mission_feedbacks::{MAX_FEEDBACK_COMMENT_LENGTH, MISSION_FEEDBACK_WINDOW_DAYS},
+    repositories::{
+        mission_feedback::MissionFeedbackRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_feedback_model::{
+            AddMissionFeedbackModel, BrawlerFeedbackSummaryModel, MissionFeedbackModel,
+        },
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionFeedbackUseCase<T1, T2>
+where
+    T1: MissionFeedbackRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+{
+    mission_feedback_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionFeedbackUseCase<T1, T2>
+where
+    T1: MissionFeedbackRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+{
+    pub fn new(mission_feedback_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_feedback_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "mission_feedback.add", skip_all)]
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        mut add_mission_feedback_model: AddMissionFeedbackModel,
+    ) -> Result<i32> {
+        if !(1..=5).contains(&add_mission_feedback_model.rating) {
+            return Err(anyhow::anyhow!("Rating must be between 1 and 5!"));
+        }
+
+        if add_mission_feedback_model.ratee_id == rater_id {
+            return Err(anyhow::anyhow!("Brawlers cannot rate themselves!"));
+        }
+
+        if let Some(comment) = add_mission_feedback_model.comment {
+            let comment = comment.trim().to_string();
+            if comment.chars().count() > MAX_FEEDBACK_COMMENT_LENGTH {
+                return Err(anyhow::anyhow!(
+                    "Feedback comment must be at most {} characters long!",
+                    MAX_FEEDBACK_COMMENT_LENGTH
+                ));
+            }
+            add_mission_feedback_model.comment = (!comment.is_empty()).then_some(comment);
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let is_status_finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        if !is_status_finished {
+            return Err(anyhow::anyhow!(
+                "Feedback is only open once the mission is completed or failed!"
+            ));
+        }
+
+        // Edits bump `updated_at`, so the window runs from the terminal status instead
+        let finished_at = mission.finished_at.unwrap_or(mission.updated_at);
+        let window_closes_at = finished_at + Duration::days(MISSION_FEEDBACK_WINDOW_DAYS);
+        if Utc::now().naive_utc() > window_closes_at {
+            return Err(anyhow::anyhow!("Feedback window for this mission has closed!"));
+        }
+
+        let participants = self
+            .mission_feedback_repository
+            .get_participants(mission_id)
+            .await?;
+
+        if !participants.contains(&rater_id) {
+            return Err(anyhow::anyhow!("Only mission participants can leave feedback!"));
+        }
+
+        if !participants.contains(&add_mission_feedback_model.ratee_id) {
+            return Err(anyhow::anyhow!("Rated brawler was not part of this mission!"));
+        }
+
+        let has_rated = self
+            .mission_feedback_repository
+            .has_rated(mission_id, rater_id, add_mission_feedback_model.ratee_id)
+            .await?;
+        if has_rated {
+            return Err(anyhow::anyhow!(
+                "You have already rated this brawler for this mission!"
+            ));
+        }
+
+        let feedback_id = self
+            .mission_feedback_repository
+            .add(add_mission_feedback_model.to_entity(mission_id, rater_id))
+            .await?;
+
+        Ok(feedback_id)
+    }
+
+    #[tracing::instrument(name = "mission_feedback.get_mission_feedbacks", skip_all)]
+    pub async fn get_mission_feedbacks(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Vec<MissionFeedbackModel>> {
+        let participants = self
+            .mission_feedback_repository
+            .get_participants(mission_id)
+            .await?;
+
+        if !participants.contains(&brawler_id) {
+            return Err(anyhow::anyhow!("Only mission participants can view feedback!"));
+        }
+
+        self.mission_feedback_repository
+            .get_by_mission(mission_id)
+            .await
+    }
+
+    #[tracing::instrument(name = "mission_feedback.get_brawler_summary", skip_all)]
+    pub async fn get_brawler_summary(&self, brawler_id: i32) -> Result<BrawlerFeedbackSummaryModel> {
+        let rating = self
+            .mission_feedback_repository
+            .get_rating(brawler_id)
+            .await?;
+
+        let feedbacks = self
+            .mission_feedback_repository
+            .get_by_ratee(brawler_id)
+            .await?;
+
+        Ok(BrawlerFeedbackSummaryModel { rating, feedbacks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::mission,
+        domain::repositories::{
+            mission_feedback::MockMissionFeedbackRepository,
+            mission_viewing::MockMissionViewingRepository,
+        },
+    };
+
+    const CHIEF_ID: i32 = 1;
+    const BRAWLER_ID: i32 = 2;
+
+    fn use_case(
+        finished_days_ago: i64,
+        updated_days_ago: i64,
+    ) -> MissionFeedbackUseCase<MockMissionFeedbackRepository, MockMissionViewingRepository> {
+        let mut mission_viewing = MockMissionViewingRepository::new();
+        mission_viewing.expect_get_one().returning(move |_| {
+            let now = Utc::now().naive_utc();
+            let mut mission = mission(CHIEF_ID, MissionStatuses::Completed);
+            mission.finished_at = Some(now - Duration::days(finished_days_ago));
+            mission.updated_at = now - Duration::days(updated_days_ago);
+            Ok(mission)
+        });
+
+        let mut mission_feedback = MockMissionFeedbackRepository::new();
+        mission_feedback
+            .expect_get_participants()
+            .returning(|_| Ok(vec![CHIEF_ID, BRAWLER_ID]));
+        mission_feedback
+            .expect_has_rated()
+            .returning(|_, _, _| Ok(false));
+        mission_feedback.expect_add().returning(|_| Ok(1));
+
+        MissionFeedbackUseCase::new(Arc::new(mission_feedback), Arc::new(mission_viewing))
+    }
+
+    #[tokio::test]
+    async fn window_runs_from_the_terminal_status() {
+        let window = MISSION_FEEDBACK_WINDOW_DAYS;
+        let cases = [
+            ("just finished", 1, 1, Ok(1)),
+            ("finished without touching updated_at", 1, window + 1, Ok(1)),
+            (
+                "edited after the window closed",
+                window + 1,
+                0,
+                Err("Feedback window for this mission has closed!"),
+            ),
+        ];
+
+        for (name, finished_days_ago, updated_days_ago, expected) in cases {
+            let use_case = use_case(finished_days_ago, updated_days_ago);
+            let feedback = AddMissionFeedbackModel {
+                ratee_id: BRAWLER_ID,
+                rating: 5,
+                comment: None,
+            };
+
+            let result = use_case.add(1, CHIEF_ID, feedback).await;
+
+            assert_eq!(
+                result.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "{}",
+                name
+            );
+        }
+    }
+}
@@ -1,49 +1,164 @@
 use std::sync::Arc;
 
-use crate::{domain::{
+use crate::{application::use_cases::{achievement::AchievementUseCase, audit::{AuditEvent, AuditUseCase}}, domain::{
     repositories::{
+        achievement::AchievementRepository, audit::AuditRepository, category::CategoryRepository,
         crew_operation::CrewOperationRepository, mission_chat::MissionChatRepository,
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+        mission_management::MissionManagementRepository,
+        mission_metrics::MissionMetrics,
+        notification_publisher::NotificationPublisher,
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
-}, domain::entities::crew_memberships::CrewMembershipEntity};
+    value_objects::{
+        category_model::slugify,
+        mission_statuses::MissionStatuses,
+        mission_model::{AddMissionModel, EditMissionModel},
+    },
+}, domain::entities::{
+    categories::DEFAULT_CATEGORY_SLUG,
+    missions::MissionEntity,
+    crew_memberships::CrewMembershipEntity,
+    tags::{MAX_TAG_LENGTH, MAX_TAGS_PER_MISSION},
+}};
+use chrono::{NaiveDateTime, Utc};
+use serde_json::{Value, json};
 
-pub struct MissionManagementUseCase<T1, T2, T3>
+pub struct MissionManagementUseCase<T1, T2, T3, T4, T5, T6, T7, T8>
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionChatRepository + Send + Sync,
-    T3: CrewOperationRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + ?Sized,
+    T2: MissionChatRepository + Send + Sync + ?Sized,
+    T3: CrewOperationRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: CategoryRepository + Send + Sync + ?Sized,
+    T6: AuditRepository + Send + Sync + ?Sized,
+    T7: NotificationPublisher + Send + Sync + ?Sized,
+    T8: MissionMetrics + Send + Sync + ?Sized,
 {
     mission_management_repository: Arc<T1>,
     mission_chat_repository: Arc<T2>,
     crew_operation_repository: Arc<T3>,
+    achievement_use_case: Arc<AchievementUseCase<T4, T7>>,
+    category_repository: Arc<T5>,
+    audit_use_case: Arc<AuditUseCase<T6>>,
+    mission_metrics: Arc<T8>,
 }
 
 use anyhow::Result;
-impl<T1, T2, T3> MissionManagementUseCase<T1, T2, T3>
+
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut normalized = tags
+        .iter()
+        .map(|tag| slugify(tag))
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>();
+    normalized.sort();
+    normalized.dedup();
+
+    if normalized.len() > MAX_TAGS_PER_MISSION {
+        return Err(anyhow::anyhow!(
+            "A mission can have at most {} tags!",
+            MAX_TAGS_PER_MISSION
+        ));
+    }
+    if let Some(tag) = normalized.iter().find(|tag| tag.len() > MAX_TAG_LENGTH) {
+        return Err(anyhow::anyhow!(
+            "Tag '{}' must be at most {} characters long!",
+            tag,
+            MAX_TAG_LENGTH
+        ));
+    }
+
+    Ok(normalized)
+}
+
+/// The user-editable part of a mission, as stored in audit before/after columns.
+fn mission_snapshot(mission: &MissionEntity, tags: &[String]) -> Value {
+    json!({
+        "name": mission.name,
+        "description": mission.description,
+        "status": mission.status,
+        "category": mission.category,
+        "ends_at": mission.ends_at,
+        "tags": tags,
+    })
+}
+
+fn validate_ends_at(ends_at: Option<NaiveDateTime>) -> Result<()> {
+    match ends_at {
+        Some(ends_at) if ends_at <= Utc::now().naive_utc() => {
+            Err(anyhow::anyhow!("Mission end time must be in the future!"))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> MissionManagementUseCase<T1, T2, T3, T4, T5, T6, T7, T8>
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionChatRepository + Send + Sync,
-    T3: CrewOperationRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + ?Sized,
+    T2: MissionChatRepository + Send + Sync + ?Sized,
+    T3: CrewOperationRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: CategoryRepository + Send + Sync + ?Sized,
+    T6: AuditRepository + Send + Sync + ?Sized,
+    T7: NotificationPublisher + Send + Sync + ?Sized,
+    T8: MissionMetrics + Send + Sync + ?Sized,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_chat_repository: Arc<T2>,
         crew_operation_repository: Arc<T3>,
+        achievement_use_case: Arc<AchievementUseCase<T4, T7>>,
+        category_repository: Arc<T5>,
+        audit_use_case: Arc<AuditUseCase<T6>>,
+        mission_metrics: Arc<T8>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_chat_repository,
             crew_operation_repository,
+            achievement_use_case,
+            category_repository,
+            audit_use_case,
+            mission_metrics,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
+    async fn snapshot(&self, mission_id: i32) -> Option<Value> {
+        let mission = self.mission_management_repository.find(mission_id).await.ok()?;
+        let tags = self
+            .mission_management_repository
+            .get_tags(mission_id)
+            .await
+            .unwrap_or_default();
+
+        Some(mission_snapshot(&mission, &tags))
+    }
+
+    async fn resolve_category(&self, category: &str) -> Result<String> {
+        match self
+            .category_repository
+            .find_active(category.trim().to_string())
+            .await?
+        {
+            Some(category) => Ok(category.slug),
+            None => Err(anyhow::anyhow!("Unknown category '{}'!", category.trim())),
+        }
+    }
+
+    #[tracing::instrument(name = "mission_management.add", skip_all)]
+    pub async fn add(&self, chief_id: i32, mut add_mission_model: AddMissionModel) -> Result<i32> {
         
         if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len()<3 {
             return Err(anyhow::anyhow!("Mission name must be at least 3 characters long!"));
         }
 
+        add_mission_model.category = Some(match add_mission_model.category.as_deref() {
+            Some(category) if !category.trim().is_empty() => self.resolve_category(category).await?,
+            _ => DEFAULT_CATEGORY_SLUG.to_string(),
+        });
+
+        validate_ends_at(add_mission_model.ends_at)?;
+        let tags = normalize_tags(add_mission_model.tags.as_deref().unwrap_or_default())?;
+
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
 
         let mission_id = self
@@ -51,15 +166,30 @@ where
             .add(insert_mission_entity)
             .await?;
 
+        if !tags.is_empty() {
+            self.mission_management_repository
+                .set_tags(mission_id, tags)
+                .await?;
+        }
+
         // Chief joins the mission automatically
         let _ = self.crew_operation_repository.join(CrewMembershipEntity {
             brawler_id: chief_id,
             mission_id,
         }, true).await;
 
+        let after = self.snapshot(mission_id).await;
+        self.audit_use_case
+            .record(AuditEvent::new(chief_id, "mission.create", "mission", mission_id).change(None, after))
+            .await;
+        self.mission_metrics.mission_created();
+
+        let _ = self.achievement_use_case.evaluate(chief_id).await;
+
         Ok(mission_id)
     }
 
+    #[tracing::instrument(name = "mission_management.edit", skip_all)]
     pub async fn edit(
         &self,
         mission_id: i32,
@@ -77,6 +207,21 @@ where
             }
         }
 
+        if let Some(category) = edit_mission_model.category {
+            edit_mission_model.category = if category.trim().is_empty() {
+                None
+            } else {
+                Some(self.resolve_category(&category).await?)
+            };
+        }
+
+        validate_ends_at(edit_mission_model.ends_at)?;
+        let tags = edit_mission_model
+            .tags
+            .as_deref()
+            .map(normalize_tags)
+            .transpose()?;
+
         // let crew_count = self
         //     .mission_viewing_repository
         //     .crew_counting(mission_id)
@@ -88,15 +233,28 @@ where
         // }
 
         let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        let before = self.snapshot(mission_id).await;
 
         let result = self
             .mission_management_repository
             .edit(mission_id, edit_mission_entity)
             .await?;
 
+        if let Some(tags) = tags {
+            self.mission_management_repository
+                .set_tags(mission_id, tags)
+                .await?;
+        }
+
+        let after = self.snapshot(mission_id).await;
+        self.audit_use_case
+            .record(AuditEvent::new(chief_id, "mission.edit", "mission", mission_id).change(before, after))
+            .await;
+
         Ok(result)
     }
 
+    #[tracing::instrument(name = "mission_management.remove", skip_all)]
     pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
         // let crew_count = self
         //     .mission_viewing_repository
@@ -108,13 +266,226 @@ where
         //     ));
         // }
 
+        let before = self.snapshot(mission_id).await;
         self.mission_management_repository
             .remove(mission_id, chief_id)
             .await?;
+
+        // Only open missions are removable; anything else was a no-op.
+        let was_open = before
+            .as_ref()
+            .is_some_and(|before| before["status"] == MissionStatuses::Open.to_string());
+        if was_open {
+            self.audit_use_case
+                .record(AuditEvent::new(chief_id, "mission.delete", "mission", mission_id).change(before, None))
+                .await;
+        }
         
         // Delete mission chats after removal
         let _ = self.mission_chat_repository.delete_messages(mission_id).await;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::{achievement_use_case, audit_use_case},
+        domain::{
+            entities::categories::CategoryEntity,
+            repositories::{
+                achievement::MockAchievementRepository, audit::MockAuditRepository,
+                category::MockCategoryRepository, crew_operation::MockCrewOperationRepository,
+                mission_chat::MockMissionChatRepository,
+                mission_management::MockMissionManagementRepository,
+                mission_metrics::MockMissionMetrics,
+                notification_publisher::MockNotificationPublisher,
+            },
+        },
+    };
+    use chrono::Duration;
+
+    const CHIEF_ID: i32 = 1;
+    const MISSION_ID: i32 = 7;
+
+    type TestUseCase = MissionManagementUseCase<
+        MockMissionManagementRepository,
+        MockMissionChatRepository,
+        MockCrewOperationRepository,
+        MockAchievementRepository,
+        MockCategoryRepository,
+        MockAuditRepository,
+        MockNotificationPublisher,
+        MockMissionMetrics,
+    >;
+
+    fn add_model(name: &str) -> AddMissionModel {
+        AddMissionModel {
+            name: name.to_string(),
+            description: None,
+            category: None,
+            tags: None,
+            ends_at: None,
+        }
+    }
+
+    fn edit_model(name: &str) -> EditMissionModel {
+        EditMissionModel {
+            name: Some(name.to_string()),
+            description: None,
+            category: None,
+            tags: None,
+            ends_at: None,
+        }
+    }
+
+    /// `adds` and `edits` are how often the rules should let a write through.
+    fn use_case(adds: usize, edits: usize) -> TestUseCase {
+        let mut mission_management = MockMissionManagementRepository::new();
+        mission_management
+            .expect_add()
+            .times(adds)
+            .returning(|_| Ok(MISSION_ID));
+        mission_management
+            .expect_edit()
+            .times(edits)
+            .returning(|mission_id, _| Ok(mission_id));
+        mission_management
+            .expect_set_tags()
+            .returning(|_, _| Ok(()));
+        mission_management
+            .expect_find()
+            .returning(|_| Err(anyhow::anyhow!("not under test")));
+        mission_management
+            .expect_get_tags()
+            .returning(|_| Ok(Vec::new()));
+
+        // The chief always takes the first seat of a new mission
+        let mut crew_operation = MockCrewOperationRepository::new();
+        crew_operation
+            .expect_join()
+            .withf(|membership, bypass| membership.brawler_id == CHIEF_ID && *bypass)
+            .returning(|_, _| Ok(()));
+
+        let mut mission_metrics = MockMissionMetrics::new();
+        mission_metrics.expect_mission_created().times(adds).return_const(());
+
+        let mut category = MockCategoryRepository::new();
+        category.expect_find_active().returning(|slug_or_label| {
+            Ok((slug_or_label == "heist").then(|| CategoryEntity {
+                slug: "heist".to_string(),
+                label: "Heist".to_string(),
+                description: None,
+                icon: None,
+                created_at: NaiveDateTime::default(),
+                retired_at: None,
+            }))
+        });
+
+        MissionManagementUseCase::new(
+            Arc::new(mission_management),
+            Arc::new(MockMissionChatRepository::new()),
+            Arc::new(crew_operation),
+            achievement_use_case(),
+            Arc::new(category),
+            audit_use_case(),
+            Arc::new(mission_metrics),
+        )
+    }
+
+    #[tokio::test]
+    async fn name_length_rules() {
+        let too_short = "Mission name must be at least 3 characters long!";
+        let cases = [
+            ("", Err(too_short)),
+            ("   ", Err(too_short)),
+            ("ab", Err(too_short)),
+            ("  ab  ", Err(too_short)),
+            ("abc", Ok(MISSION_ID)),
+            ("  Night heist  ", Ok(MISSION_ID)),
+        ];
+
+        for (name, expected) in cases {
+            let use_case = use_case(usize::from(expected.is_ok()), 0);
+
+            let added = use_case.add(CHIEF_ID, add_model(name)).await;
+            assert_eq!(
+                added.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "add {:?}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn edit_ignores_a_blank_name_but_rejects_a_short_one() {
+        let cases = [
+            ("", Ok(MISSION_ID)),
+            (
+                "ab",
+                Err("Mission name must be at least 3 characters long!"),
+            ),
+            ("abc", Ok(MISSION_ID)),
+        ];
+
+        for (name, expected) in cases {
+            let use_case = use_case(0, usize::from(expected.is_ok()));
+
+            let edited = use_case.edit(MISSION_ID, CHIEF_ID, edit_model(name)).await;
+            assert_eq!(
+                edited.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "edit {:?}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn category_tag_and_end_time_rules() {
+        let too_many_tags = (0..=MAX_TAGS_PER_MISSION)
+            .map(|index| format!("tag{}", index))
+            .collect::<Vec<_>>();
+        let long_tag = "x".repeat(MAX_TAG_LENGTH + 1);
+
+        let cases = [
+            ("known category", Some("heist"), None, None, true),
+            ("unknown category", Some("picnic"), None, None, false),
+            ("blank category falls back", Some("  "), None, None, true),
+            (
+                "duplicate tags collapse",
+                None,
+                Some(vec!["Stealth".to_string(), "stealth".to_string()]),
+                None,
+                true,
+            ),
+            ("too many tags", None, Some(too_many_tags), None, false),
+            ("tag too long", None, Some(vec![long_tag]), None, false),
+            (
+                "ends in the future",
+                None,
+                None,
+                Some(Duration::days(1)),
+                true,
+            ),
+            ("ended already", None, None, Some(-Duration::days(1)), false),
+        ];
+
+        for (name, category, tags, ends_in, allowed) in cases {
+            let use_case = use_case(usize::from(allowed), 0);
+            let model = AddMissionModel {
+                category: category.map(str::to_string),
+                tags,
+                ends_at: ends_in.map(|ends_in| Utc::now().naive_utc() + ends_in),
+                ..add_model("Night heist")
+            };
+
+            let result = use_case.add(CHIEF_ID, model).await;
+
+            assert_eq!(result.is_ok(), allowed, "{}: {:?}", name, result.err());
+        }
+    }
+}
@@ -1,44 +1,88 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use serde_json::json;
 
-use crate::domain::{
-    repositories::{
-        mission_chat::MissionChatRepository, mission_operation::MissionOperationRepository,
-        mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::{
+        achievement::AchievementUseCase,
+        audit::{AuditEvent, AuditUseCase},
+    },
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, audit::AuditRepository,
+            mission_chat::MissionChatRepository,
+            mission_operation::MissionOperationRepository,
+            mission_metrics::MissionMetrics,
+            mission_viewing::MissionViewingRepository,
+            notification_publisher::NotificationPublisher,
+        },
+        value_objects::mission_statuses::MissionStatuses,
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 
-pub struct MissionOperationUseCase<T1, T2, T3>
+pub struct MissionOperationUseCase<T1, T2, T3, T4, T5, T6, T7>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: MissionChatRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: MissionChatRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: AuditRepository + Send + Sync + ?Sized,
+    T6: NotificationPublisher + Send + Sync + ?Sized,
+    T7: MissionMetrics + Send + Sync + ?Sized,
 {
     mission_operation_repository: Arc<T1>,
     missiom_viewing_repository: Arc<T2>,
     mission_chat_repository: Arc<T3>,
+    achievement_use_case: Arc<AchievementUseCase<T4, T6>>,
+    audit_use_case: Arc<AuditUseCase<T5>>,
+    mission_metrics: Arc<T7>,
+    max_crew_per_mission: u32,
 }
 
-impl<T1, T2, T3> MissionOperationUseCase<T1, T2, T3>
+impl<T1, T2, T3, T4, T5, T6, T7> MissionOperationUseCase<T1, T2, T3, T4, T5, T6, T7>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: MissionChatRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: MissionChatRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: AuditRepository + Send + Sync + ?Sized,
+    T6: NotificationPublisher + Send + Sync + ?Sized,
+    T7: MissionMetrics + Send + Sync + ?Sized,
 {
     pub fn new(
         mission_operation_repository: Arc<T1>,
         missiom_viewing_repository: Arc<T2>,
         mission_chat_repository: Arc<T3>,
+        achievement_use_case: Arc<AchievementUseCase<T4, T6>>,
+        audit_use_case: Arc<AuditUseCase<T5>>,
+        mission_metrics: Arc<T7>,
+        max_crew_per_mission: u32,
     ) -> Self {
         Self {
             mission_operation_repository,
             missiom_viewing_repository,
             mission_chat_repository,
+            achievement_use_case,
+            audit_use_case,
+            mission_metrics,
+            max_crew_per_mission,
         }
     }
 
+    async fn record_status_change(&self, mission_id: i32, chief_id: i32, from: String, to: MissionStatuses) {
+        self.mission_metrics.status_changed(&from, &to.to_string());
+        self.audit_use_case
+            .record(
+                AuditEvent::new(chief_id, "mission.status_change", "mission", mission_id).change(
+                    Some(json!({ "status": from })),
+                    Some(json!({ "status": to.to_string() })),
+                ),
+            )
+            .await;
+    }
+
+    #[tracing::instrument(name = "mission_operation.in_progress", skip_all)]
     pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
@@ -50,11 +94,6 @@ where
         let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
             || mission.status == MissionStatuses::Failed.to_string();
 
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .trim()
-            .parse()?;
-
         if !is_status_open_or_fail {
             return Err(anyhow::anyhow!("Invalid mission status to start!"));
         }
@@ -67,7 +106,7 @@ where
             return Err(anyhow::anyhow!("Mission requires at least one crew member besides the chief to start!"));
         }
 
-        if crew_count > max_crew_per_mission {
+        if crew_count > i64::from(self.max_crew_per_mission) {
             return Err(anyhow::anyhow!("Mission crew exceeds maximum limit!"));
         }
 
@@ -76,8 +115,14 @@ where
             .to_progress(mission_id, chief_id)
             .await?;
 
+        self.record_status_change(mission_id, chief_id, mission.status, MissionStatuses::InProgress)
+            .await;
+
+        let _ = self.achievement_use_case.evaluate_mission(mission_id).await;
+
         Ok(result)
     }
+    #[tracing::instrument(name = "mission_operation.to_completed", skip_all)]
     pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
@@ -91,11 +136,17 @@ where
             .to_completed(mission_id, chief_id)
             .await?;
 
+        self.record_status_change(mission_id, chief_id, mission.status, MissionStatuses::Completed)
+            .await;
+
         // Delete mission chats after completion
         let _ = self.mission_chat_repository.delete_messages(mission_id).await;
 
+        let _ = self.achievement_use_case.evaluate_mission(mission_id).await;
+
         Ok(result)
     }
+    #[tracing::instrument(name = "mission_operation.to_failed", skip_all)]
     pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
         let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
@@ -109,9 +160,178 @@ where
             .to_failed(mission_id, chief_id)
             .await?;
 
+        self.record_status_change(mission_id, chief_id, mission.status, MissionStatuses::Failed)
+            .await;
+
         // Delete mission chats after failure
         let _ = self.mission_chat_repository.delete_messages(mission_id).await;
 
+        let _ = self.achievement_use_case.evaluate_mission(mission_id).await;
+
         Ok(result)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::{
+            achievement_use_case, audit_use_case, mission, mission_metrics, MAX_CREW_PER_MISSION,
+        },
+        domain::repositories::{
+            achievement::MockAchievementRepository, audit::MockAuditRepository,
+            mission_chat::MockMissionChatRepository,
+            mission_metrics::MockMissionMetrics,
+            mission_operation::MockMissionOperationRepository,
+            mission_viewing::MockMissionViewingRepository,
+            notification_publisher::MockNotificationPublisher,
+        },
+    };
+
+    const CHIEF_ID: i32 = 1;
+    const BRAWLER_ID: i32 = 2;
+
+    type TestUseCase = MissionOperationUseCase<
+        MockMissionOperationRepository,
+        MockMissionViewingRepository,
+        MockMissionChatRepository,
+        MockAchievementRepository,
+        MockAuditRepository,
+        MockNotificationPublisher,
+        MockMissionMetrics,
+    >;
+
+    /// `transitions` is how often the rules should let a status change through.
+    fn use_case(status: MissionStatuses, crew_count: i64, transitions: usize) -> TestUseCase {
+        let mut mission_viewing = MockMissionViewingRepository::new();
+        mission_viewing
+            .expect_get_one()
+            .returning(move |_| Ok(mission(CHIEF_ID, status.clone())));
+        mission_viewing
+            .expect_crew_counting()
+            .returning(move |_| Ok(crew_count));
+
+        let mut mission_operation = MockMissionOperationRepository::new();
+        mission_operation
+            .expect_to_progress()
+            .returning(|mission_id, _| Ok(mission_id));
+        mission_operation
+            .expect_to_completed()
+            .returning(|mission_id, _| Ok(mission_id));
+        mission_operation
+            .expect_to_failed()
+            .returning(|mission_id, _| Ok(mission_id));
+
+        // Chat is dropped once a mission is over
+        let mut mission_chat = MockMissionChatRepository::new();
+        mission_chat
+            .expect_delete_messages()
+            .times(transitions)
+            .returning(|_| Ok(()));
+
+        MissionOperationUseCase::new(
+            Arc::new(mission_operation),
+            Arc::new(mission_viewing),
+            Arc::new(mission_chat),
+            achievement_use_case(),
+            audit_use_case(),
+            mission_metrics(),
+            MAX_CREW_PER_MISSION,
+        )
+    }
+
+    #[tokio::test]
+    async fn in_progress_rules() {
+        let max = i64::from(MAX_CREW_PER_MISSION);
+        let cases = [
+            ("open with crew", MissionStatuses::Open, 2, CHIEF_ID, Ok(1)),
+            (
+                "failed retried",
+                MissionStatuses::Failed,
+                2,
+                CHIEF_ID,
+                Ok(1),
+            ),
+            ("full crew", MissionStatuses::Open, max, CHIEF_ID, Ok(1)),
+            (
+                "chief alone",
+                MissionStatuses::Open,
+                1,
+                CHIEF_ID,
+                Err("Mission requires at least one crew member besides the chief to start!"),
+            ),
+            (
+                "crew over the limit",
+                MissionStatuses::Open,
+                max + 1,
+                CHIEF_ID,
+                Err("Mission crew exceeds maximum limit!"),
+            ),
+            (
+                "not the chief",
+                MissionStatuses::Open,
+                2,
+                BRAWLER_ID,
+                Err("Only the chief can start the mission!"),
+            ),
+            (
+                "already started",
+                MissionStatuses::InProgress,
+                2,
+                CHIEF_ID,
+                Err("Invalid mission status to start!"),
+            ),
+            (
+                "completed",
+                MissionStatuses::Completed,
+                2,
+                CHIEF_ID,
+                Err("Invalid mission status to start!"),
+            ),
+        ];
+
+        for (name, status, crew_count, chief_id, expected) in cases {
+            let use_case = use_case(status, crew_count, 0);
+
+            let result = use_case.in_progress(1, chief_id).await;
+
+            assert_eq!(
+                result.map_err(|e| e.to_string()),
+                expected.map_err(str::to_string),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn finishing_rules() {
+        let cases = [
+            (MissionStatuses::InProgress, CHIEF_ID, true),
+            (MissionStatuses::InProgress, BRAWLER_ID, false),
+            (MissionStatuses::Open, CHIEF_ID, false),
+            (MissionStatuses::Failed, CHIEF_ID, false),
+            (MissionStatuses::Completed, CHIEF_ID, false),
+        ];
+
+        for (status, chief_id, allowed) in cases {
+            let use_case = use_case(status.clone(), 2, 2 * usize::from(allowed));
+
+            let completed = use_case.to_completed(1, chief_id).await;
+            let failed = use_case.to_failed(1, chief_id).await;
+
+            for result in [completed, failed] {
+                match result {
+                    Ok(mission_id) => {
+                        assert!(allowed && mission_id == 1, "{} by {}", status, chief_id)
+                    }
+                    Err(e) => {
+                        assert!(!allowed, "{} by {}: {}", status, chief_id, e);
+                        assert_eq!(e.to_string(), "Invalid condition to change stages!");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,45 +5,252 @@ use anyhow::{Ok, Result};
 use crate::domain::{
     repositories::mission_viewing::MissionViewingRepository,
     value_objects::{
-        brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+        brawler_model::BrawlerModel,
+        invalid_filter::InvalidFilter,
+        mission_cursor::MissionCursor,
+        mission_filter::{MAX_MISSION_PAGE, MissionFilter},
+        mission_model::MissionModel,
+        mission_page_model::MissionPageModel,
     },
 };
 pub struct MissionViewingUseCase<T>
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
     mission_viewing_repository: Arc<T>,
+    max_crew_per_mission: u32,
 }
 
 impl<T> MissionViewingUseCase<T>
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
-    pub fn new(mission_viewing_repository: Arc<T>) -> Self {
+    pub fn new(mission_viewing_repository: Arc<T>, max_crew_per_mission: u32) -> Self {
         Self {
             mission_viewing_repository,
+            max_crew_per_mission,
         }
     }
 
+    #[tracing::instrument(name = "mission_viewing.get_one", skip_all)]
     pub async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
         let model = self.mission_viewing_repository.get_one(mission_id).await?;
 
         Ok(model)
     }
 
+    #[tracing::instrument(name = "mission_viewing.get_crew", skip_all)]
     pub async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
         let result = self.mission_viewing_repository.get_crew(mission_id).await?;
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let models = self.mission_viewing_repository.get_all(filter).await?;
+    #[tracing::instrument(name = "mission_viewing.get_all", skip_all)]
+    pub async fn get_all(&self, filter: &MissionFilter) -> Result<MissionPageModel> {
+        if filter.page() < 1 || filter.limit() < 1 {
+            return Err(InvalidFilter::new("Page and limit must be positive!").into());
+        }
+        // Deeper pages are what cursors are for, and keep the offset from overflowing
+        if filter.page() > MAX_MISSION_PAGE {
+            return Err(InvalidFilter(format!("Page must be at most {}!", MAX_MISSION_PAGE)).into());
+        }
+        if let (Some(from), Some(to)) = (filter.created_from, filter.created_to) {
+            if from > to {
+                return Err(
+                    InvalidFilter::new("created_from must not be after created_to!").into(),
+                );
+            }
+        }
+        // Surface a bad status list before hitting the database
+        filter
+            .status_list()
+            .map_err(|e| InvalidFilter(e.to_string()))?;
+
+        let cursor = match filter.cursor.as_deref() {
+            Some(cursor) if !cursor.trim().is_empty() => {
+                let cursor =
+                    MissionCursor::decode(cursor).map_err(|e| InvalidFilter(e.to_string()))?;
+                if (cursor.sort.clone(), cursor.direction.clone()) != filter.resolved_sort() {
+                    return Err(
+                        InvalidFilter::new("Cursor does not match the requested sort!").into(),
+                    );
+                }
+                Some(cursor)
+            }
+            _ => None,
+        };
+
+        let max_crew_per_mission = i64::from(self.max_crew_per_mission);
+
+        let (models, next_cursor) = self
+            .mission_viewing_repository
+            .get_all(filter, cursor.clone(), max_crew_per_mission)
+            .await?;
+        let total = self
+            .mission_viewing_repository
+            .count_all(filter, max_crew_per_mission)
+            .await?;
 
-      
-        Ok(models)
+        let next_cursor = next_cursor.map(|cursor| cursor.encode()).transpose()?;
+
+        Ok(MissionPageModel::new(
+            models,
+            total,
+            cursor.is_none().then(|| filter.page()),
+            filter.limit(),
+            next_cursor,
+        ))
     }
 
+    #[tracing::instrument(name = "mission_viewing.get_mission_count", skip_all)]
     pub async fn get_mission_count(&self, mission_id: i32) -> Result<i64> {
         self.mission_viewing_repository.crew_counting(mission_id).await
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::use_cases::test_support::{mission, MAX_CREW_PER_MISSION},
+        domain::{
+            repositories::mission_viewing::MockMissionViewingRepository,
+            value_objects::{
+                mission_filter::{MissionSort, SortDirection},
+                mission_statuses::MissionStatuses,
+            },
+        },
+    };
+    use chrono::NaiveDate;
+
+    /// `queries` is how often the filter should reach the repository.
+    fn use_case(queries: usize) -> MissionViewingUseCase<MockMissionViewingRepository> {
+        let mut mission_viewing = MockMissionViewingRepository::new();
+        mission_viewing
+            .expect_get_all()
+            .times(queries)
+            .withf(|_, _, max_crew_per_mission| {
+                *max_crew_per_mission == i64::from(MAX_CREW_PER_MISSION)
+            })
+            .returning(|_, _, _| Ok((vec![mission(1, MissionStatuses::Open)], None)));
+        mission_viewing
+            .expect_count_all()
+            .times(queries)
+            .returning(|_, _| Ok(1));
+
+        MissionViewingUseCase::new(Arc::new(mission_viewing), MAX_CREW_PER_MISSION)
+    }
+
+    fn cursor(sort: MissionSort, direction: SortDirection) -> String {
+        MissionCursor {
+            sort,
+            direction,
+            bucket: 0,
+            key: "2026-01-01".to_string(),
+            id: 1,
+        }
+        .encode()
+        .expect("cursor encodes")
+    }
+
+    #[tokio::test]
+    async fn filter_rules() {
+        let day = |day| NaiveDate::from_ymd_opt(2026, 1, day);
+        let cases = [
+            ("defaults", MissionFilter::default(), true),
+            (
+                "page zero",
+                MissionFilter {
+                    page: Some(0),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "page too large",
+                MissionFilter {
+                    page: Some(i64::MAX),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "negative limit",
+                MissionFilter {
+                    limit: Some(-1),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "dates in order",
+                MissionFilter {
+                    created_from: day(1),
+                    created_to: day(2),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                "dates reversed",
+                MissionFilter {
+                    created_from: day(2),
+                    created_to: day(1),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "known statuses",
+                MissionFilter {
+                    statuses: Some("Open, Failed".to_string()),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                "unknown status",
+                MissionFilter {
+                    statuses: Some("Open,Lost".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "cursor for the same sort",
+                MissionFilter {
+                    cursor: Some(cursor(MissionSort::CreatedAt, SortDirection::Desc)),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                "cursor for another sort",
+                MissionFilter {
+                    cursor: Some(cursor(MissionSort::Name, SortDirection::Asc)),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                "garbled cursor",
+                MissionFilter {
+                    cursor: Some("not-a-cursor".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+        ];
+
+        for (name, filter, allowed) in cases {
+            let use_case = use_case(usize::from(allowed));
+
+            let result = use_case.get_all(&filter).await;
+
+            assert_eq!(result.is_ok(), allowed, "{}: {:?}", name, result.as_ref().err());
+            if let Err(e) = result {
+                assert!(e.downcast_ref::<InvalidFilter>().is_some(), "{}: {:?}", name, e);
+            }
+        }
+    }
+}
@@ -1,9 +1,19 @@
+pub mod achievement;
+pub mod audit;
 pub mod authentication;
 pub mod brawlers;
+pub mod category;
 pub mod mission_chat;
+pub mod mission_feedback;
 pub mod crew_operation;
+pub mod health;
+pub mod maintenance;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod moderation;
 pub mod system;
 
+
+#[cfg(test)]
+mod test_support;
@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::{
+    application::use_cases::audit::{AuditEvent, AuditUseCase},
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        repositories::{
+            audit::AuditRepository, mission_metrics::MissionMetrics,
+            moderation::ModerationRepository,
+        },
+        value_objects::{
+            moderation_model::{
+                BanBrawlerModel, ChangeRoleModel, MAX_BAN_REASON_LENGTH, ModerationReasonModel,
+            },
+            mission_statuses::MissionStatuses,
+            roles::Role,
+        },
+    },
+    infrastructure::access_cache::AccessCache,
+};
+
+pub(crate) fn normalize_reason(reason: Option<String>) -> Result<Option<String>> {
+    let reason = reason
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+
+    if let Some(reason) = &reason {
+        if reason.chars().count() > MAX_BAN_REASON_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Reason must be at most {} characters long!",
+                MAX_BAN_REASON_LENGTH
+            ));
+        }
+    }
+
+    Ok(reason)
+}
+
+pub struct ModerationUseCase<T1, T2, T3>
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    moderation_repository: Arc<T1>,
+    audit_use_case: Arc<AuditUseCase<T2>>,
+    mission_metrics: Arc<T3>,
+    access_cache: Arc<AccessCache>,
+}
+
+impl<T1, T2, T3> ModerationUseCase<T1, T2, T3>
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    pub fn new(
+        moderation_repository: Arc<T1>,
+        audit_use_case: Arc<AuditUseCase<T2>>,
+        mission_metrics: Arc<T3>,
+        access_cache: Arc<AccessCache>,
+    ) -> Self {
+        Self {
+            moderation_repository,
+            audit_use_case,
+            mission_metrics,
+            access_cache,
+        }
+    }
+
+    /// Admins and the acting brawler themselves are off limits, so nobody can lock
+    /// the last admin out.
+    async fn ensure_can_moderate(&self, actor_id: i32, brawler_id: i32) -> Result<BrawlerEntity> {
+        if actor_id == brawler_id {
+            return Err(anyhow::anyhow!("You cannot moderate yourself!"));
+        }
+
+        let brawler = self.moderation_repository.find_brawler(brawler_id).await?;
+        if brawler.role.parse::<Role>()? == Role::Admin {
+            return Err(anyhow::anyhow!("Admins cannot be moderated!"));
+        }
+
+        Ok(brawler)
+    }
+
+    #[tracing::instrument(name = "moderation.ban", skip_all)]
+    pub async fn ban(
+        &self,
+        actor_id: i32,
+        brawler_id: i32,
+        ban_brawler_model: BanBrawlerModel,
+    ) -> Result<()> {
+        let reason = normalize_reason(ban_brawler_model.reason)?;
+        let brawler = self.ensure_can_moderate(actor_id, brawler_id).await?;
+
+        self.moderation_repository
+            .ban(brawler_id, reason.clone())
+            .await?;
+        self.access_cache.invalidate(brawler_id);
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "brawler.ban", "brawler", brawler_id)
+                    .details(json!({ "reason": reason }))
+                    .change(
+                        Some(json!({ "banned": brawler.banned_at.is_some() })),
+                        Some(json!({ "banned": true })),
+                    ),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "moderation.unban", skip_all)]
+    pub async fn unban(&self, actor_id: i32, brawler_id: i32) -> Result<()> {
+        let brawler = self.moderation_repository.find_brawler(brawler_id).await?;
+        self.moderation_repository.unban(brawler_id).await?;
+        self.access_cache.invalidate(brawler_id);
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "brawler.unban", "brawler", brawler_id).change(
+                    Some(json!({ "banned": brawler.banned_at.is_some(), "ban_reason": brawler.ban_reason })),
+                    Some(json!({ "banned": false, "ban_reason": null })),
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "moderation.change_role", skip_all)]
+    pub async fn change_role(
+        &self,
+        actor_id: i32,
+        brawler_id: i32,
+        change_role_model: ChangeRoleModel,
+    ) -> Result<()> {
+        let previous = self.ensure_can_moderate(actor_id, brawler_id).await?.role;
+        self.moderation_repository
+            .set_role(brawler_id, change_role_model.role)
+            .await?;
+        self.access_cache.invalidate(brawler_id);
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "brawler.role_change", "brawler", brawler_id).change(
+                    Some(json!({ "role": previous })),
+                    Some(json!({ "role": change_role_model.role })),
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "moderation.force_fail_mission", skip_all)]
+    pub async fn force_fail_mission(
+        &self,
+        actor_id: i32,
+        mission_id: i32,
+        reason_model: ModerationReasonModel,
+    ) -> Result<()> {
+        let reason = normalize_reason(reason_model.reason)?;
+        let previous_status = self
+            .moderation_repository
+            .force_fail_mission(mission_id)
+            .await?;
+        self.mission_metrics
+            .status_changed(&previous_status, &MissionStatuses::Failed.to_string());
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "mission.force_fail", "mission", mission_id)
+                    .details(json!({ "reason": reason, "moderated": true }))
+                    .change(
+                        Some(json!({ "status": previous_status })),
+                        Some(json!({ "status": MissionStatuses::Failed.to_string() })),
+                    ),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "moderation.delete_mission", skip_all)]
+    pub async fn delete_mission(
+        &self,
+        actor_id: i32,
+        mission_id: i32,
+        reason_model: ModerationReasonModel,
+    ) -> Result<()> {
+        let reason = normalize_reason(reason_model.reason)?;
+        self.moderation_repository.delete_mission(mission_id).await?;
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "mission.delete", "mission", mission_id)
+                    .details(json!({ "reason": reason, "moderated": true })),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "moderation.delete_chat_message", skip_all)]
+    pub async fn delete_chat_message(&self, actor_id: i32, message_id: i32) -> Result<()> {
+        let mission_id = self
+            .moderation_repository
+            .delete_chat_message(message_id)
+            .await?;
+
+        self.audit_use_case
+            .record(
+                AuditEvent::new(actor_id, "chat_message.delete", "chat_message", message_id)
+                    .details(json!({ "mission_id": mission_id, "moderated": true })),
+            )
+            .await;
+
+        Ok(())
+    }
+}
@@ -1,17 +1,49 @@
 use crate::domain::repositories::system::SystemRepository;
 use crate::domain::value_objects::system_stats::SystemStatsModel;
 use anyhow::Result;
+use std::sync::Arc;
 
-pub struct SystemUseCase<T: SystemRepository> {
-    repo: T,
+pub struct SystemUseCase<T>
+where
+    T: SystemRepository + Send + Sync + ?Sized,
+{
+    repo: Arc<T>,
 }
 
-impl<T: SystemRepository> SystemUseCase<T> {
-    pub fn new(repo: T) -> Self {
+impl<T> SystemUseCase<T>
+where
+    T: SystemRepository + Send + Sync + ?Sized,
+{
+    pub fn new(repo: Arc<T>) -> Self {
         Self { repo }
     }
 
+    #[tracing::instrument(name = "system.get_stats", skip_all)]
     pub async fn get_stats(&self) -> Result<SystemStatsModel> {
         self.repo.get_stats().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::system::MockSystemRepository;
+
+    #[tokio::test]
+    async fn stats_come_from_the_repository() {
+        let mut repository = MockSystemRepository::new();
+        repository.expect_get_stats().times(1).returning(|| {
+            Ok(SystemStatsModel {
+                active_members: 4,
+                missions_completed: 3,
+                missions_failed: 1,
+                success_rate: 75.0,
+            })
+        });
+        let use_case = SystemUseCase::new(Arc::new(repository));
+
+        let stats = use_case.get_stats().await.unwrap();
+        assert_eq!(stats.active_members, 4);
+        assert_eq!(stats.success_rate, 75.0);
+    }
+}
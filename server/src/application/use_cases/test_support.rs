@@ -0,0 +1,73 @@
+//! Builders shared by the use case unit tests.
+
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+
+use crate::{
+    application::use_cases::{achievement::AchievementUseCase, audit::AuditUseCase},
+    domain::{
+        repositories::{
+            achievement::MockAchievementRepository, audit::MockAuditRepository,
+            mission_metrics::MockMissionMetrics, notification_publisher::MockNotificationPublisher,
+        },
+        value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+    },
+};
+
+pub const MAX_CREW_PER_MISSION: u32 = 3;
+
+pub fn mission(chief_id: i32, status: MissionStatuses) -> MissionModel {
+    MissionModel {
+        id: 1,
+        name: "Night heist".to_string(),
+        description: None,
+        status: status.to_string(),
+        chief_id,
+        chief_display_name: "Chief".to_string(),
+        crew_count: 1,
+        created_at: NaiveDateTime::default(),
+        updated_at: NaiveDateTime::default(),
+        category: "general".to_string(),
+        category_label: "General".to_string(),
+        tags: Vec::new(),
+        ends_at: None,
+        finished_at: None,
+    }
+}
+
+/// Achievements are a side effect the use cases ignore failures of; this one
+/// never awards anything.
+pub fn achievement_use_case(
+) -> Arc<AchievementUseCase<MockAchievementRepository, MockNotificationPublisher>> {
+    let mut repository = MockAchievementRepository::new();
+    repository
+        .expect_get_progress()
+        .returning(|_| Err(anyhow::anyhow!("not under test")));
+    repository
+        .expect_get_participants()
+        .returning(|_| Ok(Vec::new()));
+
+    Arc::new(AchievementUseCase::new(
+        Arc::new(repository),
+        Arc::new(MockNotificationPublisher::new()),
+    ))
+}
+
+pub fn audit_use_case() -> Arc<AuditUseCase<MockAuditRepository>> {
+    let mut repository = MockAuditRepository::new();
+    repository.expect_record().returning(|_| Ok(()));
+
+    Arc::new(AuditUseCase::new(Arc::new(repository)))
+}
+
+/// Counts nothing, whatever the use case records.
+pub fn mission_metrics() -> Arc<MockMissionMetrics> {
+    let mut metrics = MockMissionMetrics::new();
+    metrics.expect_mission_created().return_const(());
+    metrics.expect_crew_joined().return_const(());
+    metrics.expect_crew_left().return_const(());
+    metrics.expect_status_changed().return_const(());
+
+    Arc::new(metrics)
+}
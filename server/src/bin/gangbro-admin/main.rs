@@ -0,0 +1,347 @@
+//! Operator tasks against the database named by the server's configuration.
+//! Run `gangbro-admin --help` for the commands; `--json` prints each result, or
+//! `{"error": "..."}`, as a single JSON document instead of text.
+
+mod seed;
+
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use serde_json::{Value, json};
+use server::{
+    application::use_cases::maintenance::MaintenanceUseCase,
+    config::{config_loader::load, config_model::DotEnvyConfig},
+    domain::value_objects::brawler_model::RegisterBrawlerModel,
+    infrastructure::{
+        cloudinary::CloudinaryStorage,
+        database::postgresql_connection::{
+            PgPoolSquad, connection_pool, migration_states, revert_migrations, run_migrations,
+        },
+        http::app_state::{AppState, Repositories},
+    },
+};
+
+#[derive(Parser)]
+#[command(name = "gangbro-admin", version, about = "Maintenance tasks for a GangBro database")]
+struct Cli {
+    /// Print results as JSON
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Apply, revert or list schema migrations
+    Migrations {
+        #[command(subcommand)]
+        command: MigrationsCommand,
+    },
+    /// Register a brawler with the admin role
+    CreateAdmin {
+        username: String,
+        #[arg(long)]
+        display_name: Option<String>,
+        /// Read from standard input when left out
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Give a brawler a new password
+    ResetPassword {
+        username: String,
+        /// Read from standard input when left out
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Lock a brawler out; unlike the API, admins can be banned too
+    Ban {
+        username: String,
+        #[arg(long)]
+        reason: Option<String>,
+    },
+    /// Lift a brawler's ban
+    Unban {
+        username: String,
+    },
+    /// Remove deleted missions for good, with their crew, chat, feedback and tags
+    PurgeDeletedMissions {
+        /// Only missions deleted at least this long ago
+        #[arg(long, default_value_t = 30)]
+        older_than_days: u32,
+    },
+    /// Award achievements every brawler has earned but is missing, then show the totals
+    RecomputeStats,
+    /// Create demo brawlers and missions in every status
+    SeedDemo {
+        /// Shared by every demo brawler
+        #[arg(long, default_value = "password1")]
+        password: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrationsCommand {
+    /// Apply every pending migration
+    Run,
+    /// Revert the most recent migrations
+    Revert {
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+    },
+    /// Show every migration and whether it has been applied
+    List,
+}
+
+/// A command's result: `json` for `--json`, `text` otherwise.
+pub struct Report {
+    json: Value,
+    text: String,
+}
+
+impl Report {
+    pub fn new(json: Value, text: impl Into<String>) -> Self {
+        Self {
+            json,
+            text: text.into(),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    // Only problems, on stderr, so stdout stays parseable
+    tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_max_level(tracing::Level::WARN)
+        .init();
+
+    match run(cli.command).await {
+        Ok(report) => {
+            if cli.json {
+                println!("{}", report.json);
+            } else {
+                println!("{}", report.text);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            if cli.json {
+                println!("{}", json!({ "error": format!("{:#}", e) }));
+            } else {
+                eprintln!("Error: {:#}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(command: Command) -> Result<Report> {
+    let config = Arc::new(load()?);
+    let db_pool = Arc::new(connection_pool(&config.database)?);
+
+    if let Command::Migrations { command } = command {
+        return migrations(&db_pool, command);
+    }
+    ensure_migrated(&db_pool)?;
+
+    let repositories = Repositories::new(&db_pool, &config);
+    let maintenance_use_case = MaintenanceUseCase::new(
+        Arc::clone(&repositories.maintenance),
+        Arc::clone(&repositories.brawler),
+        Arc::clone(&repositories.moderation),
+    );
+
+    match command {
+        Command::Migrations { .. } => unreachable!("handled above"),
+        Command::CreateAdmin {
+            username,
+            display_name,
+            password,
+        } => {
+            let brawler_id = maintenance_use_case
+                .create_admin(RegisterBrawlerModel {
+                    display_name: display_name.unwrap_or_else(|| username.clone()),
+                    username: username.clone(),
+                    password: password_or_stdin(password)?,
+                    upload_avatar: None,
+                })
+                .await?;
+
+            Ok(Report::new(
+                json!({ "brawler_id": brawler_id, "username": username, "role": "admin" }),
+                format!("Created admin {} (id {})", username, brawler_id),
+            ))
+        }
+        Command::ResetPassword { username, password } => {
+            let brawler_id = maintenance_use_case
+                .reset_password(&username, password_or_stdin(password)?)
+                .await?;
+
+            Ok(Report::new(
+                json!({ "brawler_id": brawler_id, "username": username }),
+                format!("Reset the password of {} (id {})", username, brawler_id),
+            ))
+        }
+        Command::Ban { username, reason } => {
+            let brawler_id = maintenance_use_case.ban(&username, reason).await?;
+
+            Ok(Report::new(
+                json!({ "brawler_id": brawler_id, "username": username, "banned": true }),
+                format!("Banned {} (id {})", username, brawler_id),
+            ))
+        }
+        Command::Unban { username } => {
+            let brawler_id = maintenance_use_case.unban(&username).await?;
+
+            Ok(Report::new(
+                json!({ "brawler_id": brawler_id, "username": username, "banned": false }),
+                format!("Unbanned {} (id {})", username, brawler_id),
+            ))
+        }
+        Command::PurgeDeletedMissions { older_than_days } => {
+            let mission_ids = maintenance_use_case
+                .purge_deleted_missions(older_than_days)
+                .await?;
+
+            Ok(Report::new(
+                json!({ "purged_mission_ids": mission_ids }),
+                format!(
+                    "Purged {} missions deleted more than {} days ago",
+                    mission_ids.len(),
+                    older_than_days
+                ),
+            ))
+        }
+        Command::RecomputeStats => {
+            let state = app_state(config, db_pool, repositories);
+            let brawler_ids = maintenance_use_case.brawler_ids().await?;
+
+            let mut awarded = Vec::new();
+            for brawler_id in &brawler_ids {
+                awarded.extend(state.achievement_use_case.evaluate(*brawler_id).await?);
+            }
+            let stats = state.system_use_case.get_stats().await?;
+
+            let mut text = format!(
+                "Evaluated {} brawlers, awarded {} achievements",
+                brawler_ids.len(),
+                awarded.len()
+            );
+            for achievement in &awarded {
+                text.push_str(&format!("\n  brawler {}: {}", achievement.brawler_id, achievement.name));
+            }
+            text.push_str(&format!(
+                "\nMembers: {}, missions completed: {}, failed: {}, success rate: {:.1}%",
+                stats.active_members, stats.missions_completed, stats.missions_failed, stats.success_rate
+            ));
+
+            Ok(Report::new(
+                json!({
+                    "brawlers_evaluated": brawler_ids.len(),
+                    "awarded": awarded
+                        .iter()
+                        .map(|achievement| json!({ "brawler_id": achievement.brawler_id, "code": achievement.code }))
+                        .collect::<Vec<_>>(),
+                    "stats": stats,
+                }),
+                text,
+            ))
+        }
+        Command::SeedDemo { password } => {
+            let brawler_repository = Arc::clone(&repositories.brawler);
+            let state = app_state(config, db_pool, repositories);
+            seed::seed(&state, brawler_repository, &password).await
+        }
+    }
+}
+
+fn migrations(db_pool: &PgPoolSquad, command: MigrationsCommand) -> Result<Report> {
+    let mut conn = db_pool.get().context("Failed to get connection")?;
+
+    match command {
+        MigrationsCommand::Run => {
+            let applied = run_migrations(&mut conn)?;
+            let text = if applied.is_empty() {
+                "No pending migrations".to_string()
+            } else {
+                format!("Applied {} migrations:\n  {}", applied.len(), applied.join("\n  "))
+            };
+
+            Ok(Report::new(json!({ "applied": applied }), text))
+        }
+        MigrationsCommand::Revert { steps } => {
+            let reverted = revert_migrations(&mut conn, steps)?;
+            let text = if reverted.is_empty() {
+                "No migrations to revert".to_string()
+            } else {
+                format!("Reverted {} migrations:\n  {}", reverted.len(), reverted.join("\n  "))
+            };
+
+            Ok(Report::new(json!({ "reverted": reverted }), text))
+        }
+        MigrationsCommand::List => {
+            let migrations = migration_states(&mut conn)?;
+            let text = migrations
+                .iter()
+                .map(|migration| {
+                    let state = if migration.applied { "applied" } else { "pending" };
+                    format!("{:<8} {}", state, migration.name)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            Ok(Report::new(json!({ "migrations": migrations }), text))
+        }
+    }
+}
+
+/// The other commands assume the schema the repositories were written for.
+fn ensure_migrated(db_pool: &PgPoolSquad) -> Result<()> {
+    let mut conn = db_pool.get().context("Failed to get connection")?;
+    let pending = migration_states(&mut conn)?
+        .iter()
+        .filter(|migration| !migration.applied)
+        .count();
+
+    if pending > 0 {
+        return Err(anyhow::anyhow!(
+            "{} migrations are pending, apply them with `gangbro-admin migrations run` first",
+            pending
+        ));
+    }
+
+    Ok(())
+}
+
+fn app_state(
+    config: Arc<DotEnvyConfig>,
+    db_pool: Arc<PgPoolSquad>,
+    repositories: Repositories,
+) -> AppState {
+    let image_storage = Arc::new(CloudinaryStorage::new(config.cloudinary.clone()));
+    AppState::new(config, db_pool, repositories, image_storage)
+}
+
+/// Keeps passwords out of the shell history unless given explicitly.
+fn password_or_stdin(password: Option<String>) -> Result<String> {
+    if let Some(password) = password {
+        return Ok(password);
+    }
+
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        eprint!("Password: ");
+        io::stderr().flush()?;
+    }
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+use server::{
+    domain::value_objects::{
+        brawler_model::RegisterBrawlerModel,
+        mission_feedback_model::AddMissionFeedbackModel,
+        mission_filter::{MAX_MISSION_PAGE_SIZE, MissionFilter},
+        mission_model::AddMissionModel,
+        mission_statuses::MissionStatuses,
+    },
+    infrastructure::http::app_state::{AppState, DynBrawlerRepository},
+};
+
+use crate::Report;
+
+const CHIEF: &str = "demo_chief";
+const ACE: &str = "demo_ace";
+const ROOKIE: &str = "demo_rookie";
+
+/// Goes through the use cases, so the demo gets the same audit trail and
+/// achievements real activity would. Those don't share a transaction, so
+/// instead every step is skipped when it already happened: a run that stopped
+/// halfway can simply be repeated, and a finished one changes nothing.
+pub async fn seed(
+    state: &AppState,
+    brawler_repository: Arc<DynBrawlerRepository>,
+    password: &str,
+) -> Result<Report> {
+    let seeder = Seeder {
+        state,
+        brawler_repository,
+        password,
+    };
+
+    let chief = seeder.brawler(CHIEF, "Demo Chief").await?;
+    let ace = seeder.brawler(ACE, "Demo Ace").await?;
+    let rookie = seeder.brawler(ROOKIE, "Demo Rookie").await?;
+
+    // Open, with a crew member chatting
+    let stakeout = seeder.mission(chief, "Warehouse stakeout", "general", &["night"]).await?;
+    seeder.join(stakeout, ace).await?;
+    seeder.chat(stakeout, chief, "Meet at the docks at nine.").await?;
+    seeder.chat(stakeout, ace, "On my way.").await?;
+
+    // Completed, with feedback both ways
+    let run_club = seeder.mission(chief, "Morning run club", "fitness", &["outdoors"]).await?;
+    seeder.join(run_club, ace).await?;
+    seeder.join(run_club, rookie).await?;
+    seeder.finish(run_club, chief, MissionStatuses::Completed).await?;
+    seeder.rate(run_club, chief, ace, 5, "Set the pace all morning").await?;
+    seeder.rate(run_club, ace, chief, 4, "Good route").await?;
+
+    // Failed
+    let ranked = seeder.mission(ace, "Ranked grind", "gaming", &["ranked", "night"]).await?;
+    seeder.join(ranked, rookie).await?;
+    seeder.finish(ranked, ace, MissionStatuses::Failed).await?;
+
+    // Open, nobody has joined yet
+    let study = seeder.mission(rookie, "Study group", "learning", &[]).await?;
+
+    let brawlers = [(CHIEF, chief), (ACE, ace), (ROOKIE, rookie)];
+    let missions = [stakeout, run_club, ranked, study];
+
+    Ok(Report::new(
+        json!({
+            "brawlers": brawlers
+                .iter()
+                .map(|(username, brawler_id)| json!({ "brawler_id": brawler_id, "username": username }))
+                .collect::<Vec<_>>(),
+            "mission_ids": missions,
+        }),
+        format!(
+            "Seeded brawlers {} and missions {:?}",
+            brawlers.map(|(username, _)| username).join(", "),
+            missions
+        ),
+    ))
+}
+
+struct Seeder<'a> {
+    state: &'a AppState,
+    brawler_repository: Arc<DynBrawlerRepository>,
+    password: &'a str,
+}
+
+impl Seeder<'_> {
+    /// A brawler left by an earlier run keeps the password it was seeded with.
+    async fn brawler(&self, username: &str, display_name: &str) -> Result<i32> {
+        if let Some(brawler) = self
+            .brawler_repository
+            .find_by_username(username.to_string())
+            .await?
+        {
+            return Ok(brawler.id);
+        }
+
+        let register_brawler_model = RegisterBrawlerModel {
+            username: username.to_string(),
+            password: self.password.to_string(),
+            display_name: display_name.to_string(),
+            upload_avatar: None,
+        };
+        Ok(self
+            .state
+            .brawlers_use_case
+            .register(register_brawler_model)
+            .await?
+            .user_id)
+    }
+
+    /// Missions are told apart by chief and name.
+    async fn mission(&self, chief_id: i32, name: &str, category: &str, tags: &[&str]) -> Result<i32> {
+        let filter = MissionFilter {
+            name: Some(name.to_string()),
+            chief_id: Some(chief_id),
+            limit: Some(MAX_MISSION_PAGE_SIZE),
+            ..Default::default()
+        };
+        let existing = self.state.mission_viewing_use_case.get_all(&filter).await?;
+        if let Some(mission) = existing.items.iter().find(|mission| mission.name == name) {
+            return Ok(mission.id);
+        }
+
+        let add_mission_model = AddMissionModel {
+            name: name.to_string(),
+            description: Some(format!("{}, seeded for the demo.", name)),
+            category: Some(category.to_string()),
+            tags: Some(tags.iter().map(|tag| tag.to_string()).collect()),
+            ends_at: None,
+        };
+        self.state
+            .mission_management_use_case
+            .add(chief_id, add_mission_model)
+            .await
+    }
+
+    async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+        let crew = self.state.mission_viewing_use_case.get_crew(mission_id).await?;
+        if crew.iter().any(|member| member.brawler_id == brawler_id) {
+            return Ok(());
+        }
+
+        self.state
+            .crew_operation_use_case
+            .join(mission_id, brawler_id, false)
+            .await
+    }
+
+    async fn chat(&self, mission_id: i32, brawler_id: i32, message: &str) -> Result<()> {
+        let messages = self.state.mission_chat_use_case.get_messages(mission_id).await?;
+        if messages
+            .iter()
+            .any(|chat| chat.brawler_id == brawler_id && chat.message == message)
+        {
+            return Ok(());
+        }
+
+        self.state
+            .mission_chat_use_case
+            .add_message(mission_id, brawler_id, message)
+            .await?;
+        Ok(())
+    }
+
+    /// Walks an open mission through `InProgress` to `status`, picking up from
+    /// wherever an earlier run left it.
+    async fn finish(&self, mission_id: i32, chief_id: i32, status: MissionStatuses) -> Result<()> {
+        let operation = &self.state.mission_operation_use_case;
+        let mission = self.state.mission_viewing_use_case.get_one(mission_id).await?;
+
+        if mission.status == MissionStatuses::Open.to_string() {
+            operation.in_progress(mission_id, chief_id).await?;
+        } else if mission.status != MissionStatuses::InProgress.to_string() {
+            return Ok(());
+        }
+
+        match status {
+            MissionStatuses::Completed => operation.to_completed(mission_id, chief_id).await?,
+            _ => operation.to_failed(mission_id, chief_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+        rating: i32,
+        comment: &str,
+    ) -> Result<()> {
+        let feedback = &self.state.mission_feedback_use_case;
+        let existing = feedback.get_mission_feedbacks(mission_id, rater_id).await?;
+        if existing
+            .iter()
+            .any(|given| given.rater_id == rater_id && given.ratee_id == ratee_id)
+        {
+            return Ok(());
+        }
+
+        let add_mission_feedback_model = AddMissionFeedbackModel {
+            ratee_id,
+            rating,
+            comment: Some(comment.to_string()),
+        };
+        feedback
+            .add(mission_id, rater_id, add_mission_feedback_model)
+            .await?;
+        Ok(())
+    }
+}
@@ -1,83 +1,505 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
 use anyhow::Result;
+use axum::http::{HeaderValue, Method};
+use tracing_subscriber::EnvFilter;
+
+use crate::{
+    config::{
+        config_model::{
+            CloudinaryEnv, Cors, Database, DotEnvyConfig, JwtEnv, LogFormat, Logging, Metrics,
+            Missions, RateLimit, RateLimitStore, SecurityHeaders, Server, Telemetry,
+        },
+        config_source::ConfigSource,
+        stage::Stage,
+    },
+    domain::{
+        entities::crew_memberships::MAX_CREW_MEMBERSHIPS_PER_MISSION,
+        value_objects::rate_limit::RateLimitPolicy,
+    },
+};
+
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Shortest JWT secret accepted in production.
+const MIN_PRODUCTION_SECRET_LENGTH: usize = 32;
+
+/// The SPA loads its fonts from Google and images from Cloudinary and other hosts. The
+/// hash allows the `onload="this.media='all'"` Angular puts on its deferred stylesheet.
+const DEFAULT_CONTENT_SECURITY_POLICY: &str = "default-src 'self'; \
+script-src 'self' 'unsafe-hashes' 'sha256-MhtPZXr7+LpJUY5qtMutB+qWfQtMaPccfe7QXtCcEYc='; \
+style-src 'self' 'unsafe-inline' https://fonts.googleapis.com; font-src 'self' data: https://fonts.gstatic.com; \
+img-src 'self' data: blob: https:; connect-src 'self'; object-src 'none'; base-uri 'self'; \
+form-action 'self'; frame-ancestors 'none'";
+
+/// Every missing or invalid setting found while loading, reported together.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid configuration:")?;
+        for problem in &self.problems {
+            write!(f, "\n  - {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Reads typed values out of a `ConfigSource`, collecting problems instead of
+/// stopping at the first one. Invalid values fall back to a default so loading
+/// can carry on; `load` refuses the result if anything was recorded.
+struct Reader {
+    source: ConfigSource,
+    problems: Vec<String>,
+}
+
+impl Reader {
+    fn problem(&mut self, problem: String) {
+        self.problems.push(problem);
+    }
+
+    fn required(&mut self, key: &str) -> String {
+        match self.source.get(key) {
+            Some(value) => value,
+            None => {
+                self.problem(format!("{} is missing", key));
+                String::new()
+            }
+        }
+    }
+
+    fn parse_value<T>(&mut self, key: &str, value: &str, fallback: T) -> T
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match value.parse::<T>() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.problem(format!("{} has invalid value '{}': {}", key, value, e));
+                fallback
+            }
+        }
+    }
+
+    fn parse_or<T>(&mut self, key: &str, default: T) -> T
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.source.get(key) {
+            Some(value) => self.parse_value(key, &value, default),
+            None => default,
+        }
+    }
+
+    fn parse_required<T>(&mut self, key: &str) -> T
+    where
+        T: FromStr + Default,
+        T::Err: fmt::Display,
+    {
+        match self.source.get(key) {
+            Some(value) => self.parse_value(key, &value, T::default()),
+            None => {
+                self.problem(format!("{} is missing", key));
+                T::default()
+            }
+        }
+    }
+
+    fn at_least<T>(&mut self, key: &str, value: T, minimum: T) -> T
+    where
+        T: PartialOrd + fmt::Display + Copy,
+    {
+        if value < minimum {
+            self.problem(format!("{} must be at least {}", key, minimum));
+        }
+        value
+    }
 
-use crate::config::{config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server}, stage::Stage};
+    fn flag(&mut self, key: &str, default: bool) -> bool {
+        match self.source.get(key).map(|value| value.to_lowercase()) {
+            Some(value) if value == "true" => true,
+            Some(value) if value == "false" => false,
+            Some(value) => {
+                self.problem(format!("{} must be true or false, got '{}'", key, value));
+                default
+            }
+            None => default,
+        }
+    }
+
+    fn list(&mut self, key: &str) -> Option<Vec<String>> {
+        let value = self.source.get(key)?;
 
+        Some(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+}
+
+/// Loads the whole configuration once at startup, from (lowest to highest
+/// precedence) `config.toml` or `CONFIG_FILE`, its stage overlay such as
+/// `config.production.toml`, then the environment and `.env`.
 pub fn load() -> Result<DotEnvyConfig> {
     if let Err(e) = dotenvy::dotenv() {
         eprintln!("Warning: Error loading .env file: {}", e);
     }
-    
-    // Render typically provides PORT environment variable
-    let port = std::env::var("PORT")
-        .or_else(|_| std::env::var("SERVER_PORT"))
-        .unwrap_or_else(|_| "8080".to_string())
-        .trim()
-        .parse()
-        .unwrap_or(8080);
 
-    let server = Server {
-        port,
-        body_limit: std::env::var("SERVER_BODY_LIMIT")
-            .unwrap_or_else(|_| "10".to_string())
-            .trim()
-            .parse()
-            .unwrap_or(10),
-        timeout: std::env::var("SERVER_TIMEOUT")
-            .unwrap_or_else(|_| "90".to_string())
-            .trim()
-            .parse()
-            .unwrap_or(90),
+    let mut reader = Reader {
+        source: ConfigSource::default(),
+        problems: Vec::new(),
     };
 
-    let database = Database {
-        url: std::env::var("DATABASE_URL").expect("DATABASE_URL must be set in environment variables"),
+    let config_file = match std::env::var("CONFIG_FILE") {
+        Ok(path) => {
+            let path = PathBuf::from(path);
+            if !path.exists() {
+                reader.problem(format!("CONFIG_FILE {} does not exist", path.display()));
+            }
+            path
+        }
+        Err(_) => PathBuf::from(DEFAULT_CONFIG_FILE),
     };
+    load_file(&mut reader, &config_file);
+
+    let stage = reader.parse_or("STAGE", Stage::default());
+    load_file(&mut reader, &stage_overlay(&config_file, &stage));
 
-    let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET not set");
+    let server = load_server(&mut reader);
+    let database = load_database(&mut reader);
+    let jwt = load_jwt(&mut reader, &stage);
+    let cloudinary = load_cloudinary(&mut reader);
+    let missions = load_missions(&mut reader);
+    let rate_limit = load_rate_limit(&mut reader);
+    let cors = load_cors(&mut reader, &stage);
+    let security_headers = load_security_headers(&mut reader, &stage);
+    let metrics = load_metrics(&mut reader, &stage, server.port);
+    let logging = load_logging(&mut reader, &stage);
+    let telemetry = load_telemetry(&mut reader);
 
-    let config = DotEnvyConfig {
+    if !reader.problems.is_empty() {
+        return Err(ConfigError {
+            problems: reader.problems,
+        }
+        .into());
+    }
+
+    Ok(DotEnvyConfig {
+        stage,
         server,
         database,
+        jwt,
+        cloudinary,
+        missions,
+        rate_limit,
+        cors,
+        security_headers,
+        metrics,
+        logging,
+        telemetry,
+    })
+}
+
+fn load_file(reader: &mut Reader, path: &Path) {
+    if !path.exists() {
+        return;
+    }
+    if let Err(e) = reader.source.load_file(path) {
+        reader.problem(format!("{:#}", e));
+    }
+}
+
+/// `config.toml` becomes `config.production.toml`.
+fn stage_overlay(config_file: &Path, stage: &Stage) -> PathBuf {
+    let stem = config_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    config_file.with_file_name(format!("{}.{}.toml", stem, stage))
+}
+
+fn load_server(reader: &mut Reader) -> Server {
+    // Render provides PORT
+    let port_key = if reader.source.get("PORT").is_some() {
+        "PORT"
+    } else {
+        "SERVER_PORT"
+    };
+    let port = reader.parse_or(port_key, 8080);
+
+    let body_limit = reader.parse_or("SERVER_BODY_LIMIT", 10);
+    let timeout = reader.parse_or("SERVER_TIMEOUT", 90);
+
+    Server {
+        port,
+        body_limit: reader.at_least("SERVER_BODY_LIMIT", body_limit, 1),
+        timeout: reader.at_least("SERVER_TIMEOUT", timeout, 1),
+        shutdown_drain: reader.parse_or("SERVER_SHUTDOWN_DRAIN", 5),
+        trust_proxy_headers: reader.flag("TRUST_PROXY_HEADERS", false),
+    }
+}
+
+fn load_database(reader: &mut Reader) -> Database {
+    let url = reader.required("DATABASE_URL");
+    if !url.is_empty() && !url.starts_with("postgres://") && !url.starts_with("postgresql://") {
+        reader.problem("DATABASE_URL must be a postgres:// URL".to_string());
+    }
+
+    // 5 is safe for small Supabase instances
+    let pool_size = reader.parse_or("DATABASE_POOL_SIZE", 5);
+
+    Database {
+        url,
+        pool_size: reader.at_least("DATABASE_POOL_SIZE", pool_size, 1),
+        run_migrations: reader.flag("DATABASE_RUN_MIGRATIONS", true),
+    }
+}
+
+fn load_jwt(reader: &mut Reader, stage: &Stage) -> JwtEnv {
+    let secret = reader.required("JWT_USER_SECRET");
+    if *stage == Stage::Production && !secret.is_empty() && secret.len() < MIN_PRODUCTION_SECRET_LENGTH {
+        reader.problem(format!(
+            "JWT_USER_SECRET must be at least {} characters in production",
+            MIN_PRODUCTION_SECRET_LENGTH
+        ));
+    }
+
+    let lift_time_days = reader.parse_required("JWT_LIFETIME_DAYS");
+
+    JwtEnv {
         secret,
+        lift_time_days: reader.at_least("JWT_LIFETIME_DAYS", lift_time_days, 1),
+    }
+}
+
+fn load_cloudinary(reader: &mut Reader) -> Option<CloudinaryEnv> {
+    const KEYS: [&str; 3] = [
+        "CLOUDINARY_CLOUD_NAME",
+        "CLOUDINARY_API_KEY",
+        "CLOUDINARY_API_SECRET",
+    ];
+
+    let values = KEYS.map(|key| reader.source.get(key));
+    match values {
+        [Some(cloud_name), Some(api_key), Some(api_secret)] => Some(CloudinaryEnv {
+            cloud_name,
+            api_key,
+            api_secret,
+        }),
+        [None, None, None] => None,
+        _ => {
+            for (key, value) in KEYS.iter().zip(&values) {
+                if value.is_none() {
+                    reader.problem(format!("{} is missing (Cloudinary is partially configured)", key));
+                }
+            }
+            None
+        }
+    }
+}
+
+fn load_missions(reader: &mut Reader) -> Missions {
+    let max_crew_per_mission = reader.parse_or("MAX_CREW_PER_MISSION", MAX_CREW_MEMBERSHIPS_PER_MISSION);
+
+    Missions {
+        max_crew_per_mission: reader.at_least("MAX_CREW_PER_MISSION", max_crew_per_mission, 1),
+    }
+}
+
+fn load_rate_limit(reader: &mut Reader) -> RateLimit {
+    let enabled = reader.flag("RATE_LIMIT_ENABLED", true);
+    let store = match reader.source.get("RATE_LIMIT_STORE").as_deref() {
+        None | Some("memory") => RateLimitStore::Memory,
+        Some("postgres") => RateLimitStore::Postgres,
+        Some(other) => {
+            reader.problem(format!("RATE_LIMIT_STORE must be memory or postgres, got '{}'", other));
+            RateLimitStore::Memory
+        }
+    };
+
+    let mut policy = |key: &str, default: &str| {
+        let default = default.parse::<RateLimitPolicy>().expect("valid default rate limit");
+        reader.parse_or(key, default)
     };
 
-    Ok(config)
+    RateLimit {
+        enabled,
+        store,
+        login: policy("RATE_LIMIT_LOGIN", "10/60"),
+        register: policy("RATE_LIMIT_REGISTER", "5/3600"),
+        upload: policy("RATE_LIMIT_UPLOAD", "10/600"),
+        chat: policy("RATE_LIMIT_CHAT", "30/60"),
+        write: policy("RATE_LIMIT_WRITE", "60/60"),
+        read: policy("RATE_LIMIT_READ", "300/60"),
+    }
 }
 
-pub fn get_stage() ->Stage {
-    dotenvy::dotenv().ok();
+/// Development accepts any origin; production only the origins it is told about,
+/// since the SPA is served from the API's own origin there.
+fn load_cors(reader: &mut Reader, stage: &Stage) -> Cors {
+    let allowed_origins = match reader.list("CORS_ALLOWED_ORIGINS") {
+        Some(origins) if origins.iter().any(|origin| origin == "*") => {
+            if *stage == Stage::Production {
+                reader.problem("CORS_ALLOWED_ORIGINS cannot be '*' in production".to_string());
+            }
+            None
+        }
+        Some(origins) => Some(
+            origins
+                .iter()
+                .filter_map(|origin| match HeaderValue::from_str(origin) {
+                    Ok(origin) => Some(origin),
+                    Err(_) => {
+                        reader.problem(format!("CORS_ALLOWED_ORIGINS has invalid origin '{}'", origin));
+                        None
+                    }
+                })
+                .collect(),
+        ),
+        None if *stage == Stage::Production => Some(Vec::new()),
+        None => None,
+    };
 
-    let stage_str = std::env::var("STAGE").unwrap_or_else(|_| "".to_string());
+    let allowed_methods = reader
+        .list("CORS_ALLOWED_METHODS")
+        .unwrap_or_else(|| {
+            ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"]
+                .map(str::to_string)
+                .to_vec()
+        })
+        .iter()
+        .filter_map(|method| match method.to_uppercase().parse::<Method>() {
+            Ok(method) => Some(method),
+            Err(_) => {
+                reader.problem(format!("CORS_ALLOWED_METHODS has invalid method '{}'", method));
+                None
+            }
+        })
+        .collect();
 
-    Stage::from_str(&stage_str).unwrap_or_default()
+    let allow_credentials = reader.flag("CORS_ALLOW_CREDENTIALS", false);
+    if allow_credentials && allowed_origins.is_none() {
+        reader.problem("CORS_ALLOW_CREDENTIALS needs an explicit CORS_ALLOWED_ORIGINS list".to_string());
+    }
+
+    Cors {
+        allowed_origins,
+        allowed_methods,
+        allow_credentials,
+    }
 }
-pub fn get_user_secret() -> Result<String>{
-    let secret_env = std::env::var("JWT_SECRET")
-    .map_err(|_| anyhow::anyhow!("JWT_SECRET not set"))?;
-    Ok(secret_env)
+
+fn load_security_headers(reader: &mut Reader, stage: &Stage) -> SecurityHeaders {
+    let content_security_policy = reader
+        .source
+        .get("CONTENT_SECURITY_POLICY")
+        .unwrap_or_else(|| DEFAULT_CONTENT_SECURITY_POLICY.to_string());
+    let content_security_policy = match HeaderValue::from_str(&content_security_policy) {
+        Ok(value) => value,
+        Err(_) => {
+            reader.problem("CONTENT_SECURITY_POLICY is not a valid header value".to_string());
+            HeaderValue::from_static(DEFAULT_CONTENT_SECURITY_POLICY)
+        }
+    };
+
+    SecurityHeaders {
+        hsts: reader.flag("HSTS_ENABLED", *stage == Stage::Production),
+        content_security_policy,
+    }
 }
 
+/// Production must keep `/metrics` private, behind a token or on a port of its own.
+fn load_metrics(reader: &mut Reader, stage: &Stage, server_port: u16) -> Metrics {
+    let enabled = reader.flag("METRICS_ENABLED", true);
+    let token = reader
+        .source
+        .get("METRICS_TOKEN")
+        .filter(|token| !token.trim().is_empty());
 
-// pub fn get_user_secret_env() -> Result<JwtEnv> {
-pub fn get_jwt_env() -> Result<JwtEnv> {
-    dotenvy::dotenv().ok();
+    let port = match reader.source.get("METRICS_PORT") {
+        Some(value) => {
+            let port = reader.parse_value("METRICS_PORT", &value, server_port);
+            if port == server_port {
+                reader.problem("METRICS_PORT must differ from the server port".to_string());
+            }
+            Some(port)
+        }
+        None => None,
+    };
 
-    Ok(JwtEnv {
-        secret: std::env::var("JWT_USER_SECRET")?,
-        lift_time_days: std::env::var("JWT_LIFETIME_DAYS")?.parse::<i64>()?,
-    })
+    if enabled && *stage == Stage::Production && token.is_none() && port.is_none() {
+        reader.problem("METRICS_TOKEN or METRICS_PORT must be set in production (or METRICS_ENABLED=false)".to_string());
+    }
+
+    Metrics {
+        enabled,
+        token,
+        port,
+    }
 }
 
-pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
-    dotenvy::dotenv().ok();
+/// Production defaults to `info` as JSON for log shipping, other stages to
+/// readable `debug` output.
+fn load_logging(reader: &mut Reader, stage: &Stage) -> Logging {
+    let (default_level, default_format) = match stage {
+        Stage::Production => ("info", LogFormat::Json),
+        Stage::Local | Stage::Development => ("debug", LogFormat::Pretty),
+    };
+
+    let level = reader
+        .source
+        .get("LOG_LEVEL")
+        .unwrap_or_else(|| default_level.to_string());
+    if let Err(e) = EnvFilter::try_new(&level) {
+        reader.problem(format!("LOG_LEVEL has invalid value '{}': {}", level, e));
+    }
 
-    let cloud_name = std::env::var("CLOUDINARY_CLOUD_NAME")?;
-    let api_key = std::env::var("CLOUDINARY_API_KEY")?;
-    let api_secret = std::env::var("CLOUDINARY_API_SECRET")?;
+    let format = match reader.source.get("LOG_FORMAT").as_deref() {
+        None => default_format,
+        Some("pretty") => LogFormat::Pretty,
+        Some("json") => LogFormat::Json,
+        Some(other) => {
+            reader.problem(format!("LOG_FORMAT must be pretty or json, got '{}'", other));
+            default_format
+        }
+    };
 
-    Ok(CloudinaryEnv {
-        cloud_name,
-        api_key,
-        api_secret,
-    })
-}
\ No newline at end of file
+    Logging { level, format }
+}
+
+/// Uses the standard OpenTelemetry variable names so collectors' docs apply as is.
+fn load_telemetry(reader: &mut Reader) -> Telemetry {
+    let otlp_endpoint = reader
+        .source
+        .get("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .filter(|endpoint| !endpoint.trim().is_empty());
+    if let Some(endpoint) = &otlp_endpoint {
+        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+            reader.problem("OTEL_EXPORTER_OTLP_ENDPOINT must be an http:// or https:// URL".to_string());
+        }
+    }
+
+    Telemetry {
+        otlp_endpoint,
+        service_name: reader
+            .source
+            .get("OTEL_SERVICE_NAME")
+            .unwrap_or_else(|| "gangbro-server".to_string()),
+    }
+}
@@ -1,26 +1,117 @@
+use axum::http::{HeaderValue, Method};
+
+use crate::{config::stage::Stage, domain::value_objects::rate_limit::RateLimitPolicy};
+
 #[derive(Debug, Clone)]
 pub struct Server {
     pub port: u16,
     pub body_limit: u64,
     pub timeout: u64,
+    /// Seconds between a stop signal and refusing new connections, so load
+    /// balancers can notice the failing readiness probe first.
+    pub shutdown_drain: u64,
+    /// Whether the server sits behind a proxy whose `X-Forwarded-For` can be trusted.
+    pub trust_proxy_headers: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct Database {
     pub url: String,
+    pub pool_size: u32,
+    /// When false, deployments run `server migrate` beforehand and instances
+    /// only report readiness once the schema has caught up.
+    pub run_migrations: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Missions {
+    pub max_crew_per_mission: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitStore {
+    Memory,
+    Postgres,
+}
+
+/// Requests allowed per route group, keyed by brawler id or client IP.
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub enabled: bool,
+    pub store: RateLimitStore,
+    pub login: RateLimitPolicy,
+    pub register: RateLimitPolicy,
+    pub upload: RateLimitPolicy,
+    pub chat: RateLimitPolicy,
+    pub write: RateLimitPolicy,
+    pub read: RateLimitPolicy,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cors {
+    /// `None` allows any origin; an empty list allows none besides our own.
+    pub allowed_origins: Option<Vec<HeaderValue>>,
+    pub allowed_methods: Vec<Method>,
+    pub allow_credentials: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SecurityHeaders {
+    pub hsts: bool,
+    /// Sent with the SPA; API responses get a policy that loads nothing.
+    pub content_security_policy: HeaderValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct Logging {
+    /// An `EnvFilter` directive such as `info` or `server=debug,tower_http=info`.
+    pub level: String,
+    pub format: LogFormat,
+}
+
+/// OpenTelemetry trace export; disabled without an endpoint.
+#[derive(Debug, Clone)]
+pub struct Telemetry {
+    /// Base URL of an OTLP/HTTP collector, such as `http://localhost:4318`.
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+}
+
+/// Where `/metrics` is served and who may scrape it.
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    pub enabled: bool,
+    /// Scrapers must send `Authorization: Bearer <token>` when set.
+    pub token: Option<String>,
+    /// Moves `/metrics` off the public port onto this one when set.
+    pub port: Option<u16>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DotEnvyConfig {
+    pub stage: Stage,
     pub server: Server,
     pub database: Database,
-    pub secret: String,
-    // pub max_crew_per_mission: u32,
+    pub jwt: JwtEnv,
+    /// Avatar uploads are unavailable without it.
+    pub cloudinary: Option<CloudinaryEnv>,
+    pub missions: Missions,
+    pub rate_limit: RateLimit,
+    pub cors: Cors,
+    pub security_headers: SecurityHeaders,
+    pub metrics: Metrics,
+    pub logging: Logging,
+    pub telemetry: Telemetry,
 }
 
 #[derive(Debug, Clone)]
 pub struct JwtEnv {
-    //pub struct UserSecret {
     pub secret: String,
     pub lift_time_days: i64,
 }
@@ -0,0 +1,60 @@
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Settings read from TOML files, flattened to the names of the environment
+/// variables that override them: `[rate_limit] login = "10/60"` is read as
+/// `RATE_LIMIT_LOGIN`, and a top-level `stage = "production"` as `STAGE`.
+#[derive(Debug, Default)]
+pub struct ConfigSource {
+    values: HashMap<String, String>,
+}
+
+impl ConfigSource {
+    /// The environment wins over files; blank values count as unset.
+    pub fn get(&self, key: &str) -> Option<String> {
+        std::env::var(key)
+            .ok()
+            .or_else(|| self.values.get(key).cloned())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Values from a later file replace those from an earlier one.
+    pub fn load_file(&mut self, path: &Path) -> Result<()> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read {}", path.display()))?;
+        let table = content
+            .parse::<toml::Table>()
+            .with_context(|| format!("cannot parse {}", path.display()))?;
+
+        for (key, value) in &table {
+            flatten(key, value, &mut self.values);
+        }
+
+        Ok(())
+    }
+}
+
+fn flatten(key: &str, value: &toml::Value, values: &mut HashMap<String, String>) {
+    let value = match value {
+        toml::Value::Table(table) => {
+            for (child, value) in table {
+                flatten(&format!("{}_{}", key, child), value, values);
+            }
+            return;
+        }
+        toml::Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                toml::Value::String(item) => item.clone(),
+                item => item.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+        toml::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+
+    values.insert(key.to_uppercase(), value);
+}
@@ -1,3 +1,4 @@
 pub mod config_model;
 pub mod config_loader;
-pub mod stage;
\ No newline at end of file
+pub mod config_source;
+pub mod stage;
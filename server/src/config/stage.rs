@@ -1,8 +1,6 @@
-use std::fmt;
-use anyhow::Result;
-
-#[derive(Debug, Clone, Default, PartialEq)]
+use std::{fmt, str::FromStr};
 
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum Stage{
     Local,
     #[default]
@@ -21,8 +19,10 @@ impl fmt::Display for Stage {
     }
 }
 
-impl Stage {
-    pub fn from_str(stage: &str) ->Result<Self> {
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(stage: &str) -> Result<Self, Self::Err> {
         match stage {
             "local" => Ok(Self::Local),
             "development" => Ok(Self::Development),
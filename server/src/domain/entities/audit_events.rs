@@ -0,0 +1,53 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::audit_model::AuditEventModel,
+    infrastructure::database::schema::audit_events,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = audit_events)]
+pub struct AuditEventEntity {
+    pub id: i32,
+    pub actor_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: serde_json::Value,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl AuditEventEntity {
+    pub fn to_model(&self, actor_display_name: String) -> AuditEventModel {
+        AuditEventModel {
+            id: self.id,
+            actor_id: self.actor_id,
+            actor_display_name,
+            action: self.action.clone(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id,
+            details: self.details.clone(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+            request_id: self.request_id.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = audit_events)]
+pub struct AddAuditEventEntity {
+    pub actor_id: i32,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: serde_json::Value,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+}
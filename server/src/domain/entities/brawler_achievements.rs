@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        achievement_model::BrawlerAchievementModel, achievements::find_achievement,
+    },
+    infrastructure::database::schema::brawler_achievements,
+};
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct BrawlerAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement_code: String,
+    pub awarded_at: NaiveDateTime,
+}
+
+impl BrawlerAchievementEntity {
+    /// Returns `None` for badges that have since been removed from the definitions.
+    pub fn to_model(&self) -> Option<BrawlerAchievementModel> {
+        let definition = find_achievement(&self.achievement_code)?;
+
+        Some(BrawlerAchievementModel {
+            brawler_id: self.brawler_id,
+            code: definition.code.to_string(),
+            name: definition.name.to_string(),
+            description: definition.description.to_string(),
+            awarded_at: self.awarded_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawler_achievements)]
+pub struct AwardAchievementEntity {
+    pub brawler_id: i32,
+    pub achievement_code: String,
+}
@@ -13,7 +13,9 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
-
+    pub role: String,
+    pub banned_at: Option<NaiveDateTime>,
+    pub ban_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::categories;
+
+pub const DEFAULT_CATEGORY_SLUG: &str = "general";
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(primary_key(slug))]
+#[diesel(table_name = categories)]
+pub struct CategoryEntity {
+    pub slug: String,
+    pub label: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub retired_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = categories)]
+pub struct AddCategoryEntity {
+    pub slug: String,
+    pub label: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+}
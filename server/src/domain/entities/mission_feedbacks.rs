@@ -0,0 +1,16 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_feedbacks;
+
+pub const MISSION_FEEDBACK_WINDOW_DAYS: i64 = 7;
+pub const MAX_FEEDBACK_COMMENT_LENGTH: usize = 280;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_feedbacks)]
+pub struct AddMissionFeedbackEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub rating: i32,
+    pub comment: Option<String>,
+}
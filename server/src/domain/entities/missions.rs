@@ -1,11 +1,12 @@
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
+use serde::Serialize;
 
 use crate::{
     domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
 };
 
-#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[derive(Debug, Clone, Serialize, Identifiable, Selectable, Queryable)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 #[diesel(table_name = missions)]
 pub struct MissionEntity {
@@ -18,10 +19,18 @@ pub struct MissionEntity {
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
     pub category: String,
+    pub ends_at: Option<NaiveDateTime>,
+    pub finished_at: Option<NaiveDateTime>,
 }
 
 impl MissionEntity {
-    pub fn to_model(&self, crew_count: i64, chief_display_name: String) -> MissionModel {
+    pub fn to_model(
+        &self,
+        crew_count: i64,
+        chief_display_name: String,
+        category_label: String,
+        tags: Vec<String>,
+    ) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
@@ -33,6 +42,10 @@ impl MissionEntity {
             created_at: self.created_at,
             updated_at: self.updated_at,
             category: self.category.clone(),
+            category_label,
+            tags,
+            ends_at: self.ends_at,
+            finished_at: self.finished_at,
         }
     }
 }
@@ -45,6 +58,7 @@ pub struct AddMissionEntity {
     pub status: String,
     pub description: Option<String>,
     pub category: String,
+    pub ends_at: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -54,4 +68,5 @@ pub struct EditMissionEntity {
     pub name: Option<String>,
     pub description: Option<String>,
     pub category: Option<String>,
+    pub ends_at: Option<NaiveDateTime>,
 }
\ No newline at end of file
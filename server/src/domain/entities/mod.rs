@@ -1,3 +1,9 @@
+pub mod audit_events;
+pub mod brawler_achievements;
 pub mod brawlers;
+pub mod categories;
 pub mod crew_memberships;
-pub mod missions;
\ No newline at end of file
+pub mod mission_feedbacks;
+pub mod missions;
+pub mod rate_limit_buckets;
+pub mod tags;
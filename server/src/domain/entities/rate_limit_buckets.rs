@@ -0,0 +1,13 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::rate_limit_buckets;
+
+#[derive(Debug, Clone, Selectable, Queryable, Insertable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = rate_limit_buckets)]
+pub struct RateLimitBucketEntity {
+    pub key: String,
+    pub tokens: f64,
+    pub updated_at: NaiveDateTime,
+}
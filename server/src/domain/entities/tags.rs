@@ -0,0 +1,26 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{mission_tags, tags};
+
+pub const MAX_TAGS_PER_MISSION: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 30;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = tags)]
+pub struct TagEntity {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = tags)]
+pub struct AddTagEntity {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_tags)]
+pub struct MissionTagEntity {
+    pub mission_id: i32,
+    pub tag_id: i32,
+}
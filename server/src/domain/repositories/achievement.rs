@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::brawler_achievements::{AwardAchievementEntity, BrawlerAchievementEntity},
+    value_objects::achievement_model::AchievementProgressModel,
+};
+
+#[automock]
+#[async_trait]
+pub trait AchievementRepository {
+    async fn get_progress(&self, brawler_id: i32) -> Result<AchievementProgressModel>;
+    async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementEntity>>;
+    async fn award(
+        &self,
+        award_achievement_entities: Vec<AwardAchievementEntity>,
+    ) -> Result<Vec<BrawlerAchievementEntity>>;
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>>;
+}
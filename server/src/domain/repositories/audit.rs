@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::audit_events::AddAuditEventEntity,
+    value_objects::audit_model::{AuditEventFilter, AuditEventModel},
+};
+
+#[automock]
+#[async_trait]
+pub trait AuditRepository {
+    async fn record(&self, add_audit_event_entity: AddAuditEventEntity) -> Result<()>;
+    async fn query(&self, filter: &AuditEventFilter) -> Result<Vec<AuditEventModel>>;
+}
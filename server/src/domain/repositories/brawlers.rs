@@ -1,25 +1,18 @@
-use crate::{
-    domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
-        value_objects::{
-            base64_image::Base64Image, mission_model::MissionModel, uploaded_image::UploadedImage,
-        },
-    },
-    infrastructure::{cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+use crate::domain::{
+    entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+    value_objects::{mission_model::MissionModel, uploaded_image::UploadedImage},
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
+#[automock]
 #[async_trait]
 pub trait BrawlerRepository {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport>;
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
-    async fn upload_base64img(
-        &self,
-        user_id: i32,
-        base64img: Base64Image,
-        opt: UploadImageOptions,
-    ) -> Result<UploadedImage>;
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
+    async fn find_by_username(&self, username: String) -> Result<Option<BrawlerEntity>>;
+    async fn find_by_id(&self, brawler_id: i32) -> Result<Option<BrawlerEntity>>;
+    async fn update_avatar(&self, user_id: i32, uploaded_image: UploadedImage) -> Result<()>;
 
     async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
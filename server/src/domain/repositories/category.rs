@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::categories::{AddCategoryEntity, CategoryEntity},
+    value_objects::category_model::CategoryModel,
+};
+
+#[automock]
+#[async_trait]
+pub trait CategoryRepository {
+    async fn get_all(&self) -> Result<Vec<CategoryModel>>;
+    async fn find_active(&self, slug_or_label: String) -> Result<Option<CategoryEntity>>;
+    async fn add(&self, add_category_entity: AddCategoryEntity) -> Result<String>;
+    async fn retire(&self, slug: String) -> Result<()>;
+}
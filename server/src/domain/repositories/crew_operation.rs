@@ -1,8 +1,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
 use crate::domain::entities::crew_memberships::CrewMembershipEntity;
 
+#[automock]
 #[async_trait]
 pub trait CrewOperationRepository {
     async fn join(&self, crew_member_ships: CrewMembershipEntity, bypass_chief_check: bool) -> Result<()>;
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait HealthRepository {
+    /// Fails when no connection can be had or a trivial query does not run.
+    async fn ping(&self) -> Result<()>;
+    /// Embedded migrations the database has not run yet.
+    async fn pending_migrations(&self) -> Result<usize>;
+}
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::{
+    domain::value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+    infrastructure::cloudinary::UploadImageOptions,
+};
+
+/// Where uploaded images end up; the brawler only keeps the returned URL.
+#[automock]
+#[async_trait]
+pub trait ImageStorage {
+    async fn upload(&self, base64img: Base64Image, opt: UploadImageOptions) -> Result<UploadedImage>;
+    /// Whether uploads are configured at all.
+    fn is_enabled(&self) -> bool;
+    /// Fails when the backend can't be reached or refuses our credentials.
+    async fn ping(&self) -> Result<()>;
+}
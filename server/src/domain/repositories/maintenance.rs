@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{entities::brawlers::RegisterBrawlerEntity, value_objects::roles::Role};
+
+/// Operator tasks behind `gangbro-admin`, with no HTTP route of their own.
+#[automock]
+#[async_trait]
+pub trait MaintenanceRepository {
+    /// Inserts the brawler with `role` already set, so no half-made account
+    /// is left behind if it fails.
+    async fn register_with_role(&self, register_brawler_entity: RegisterBrawlerEntity, role: Role) -> Result<i32>;
+    async fn set_password(&self, brawler_id: i32, hashed_password: String) -> Result<()>;
+    async fn brawler_ids(&self) -> Result<Vec<i32>>;
+    /// Removes missions soft-deleted before `deleted_before` for good, together
+    /// with their crew, chat, feedback and tags, and returns their ids.
+    async fn purge_deleted_missions(&self, deleted_before: NaiveDateTime) -> Result<Vec<i32>>;
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 use crate::domain::value_objects::mission_chat_model::MissionChatModel;
 
+#[automock]
 #[async_trait]
 pub trait MissionChatRepository {
     async fn add_message(&self, mission_id: i32, brawler_id: i32, message: &str) -> Result<MissionChatModel>;
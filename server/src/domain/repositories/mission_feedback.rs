@@ -0,0 +1,19 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::mission_feedbacks::AddMissionFeedbackEntity,
+    value_objects::mission_feedback_model::{BrawlerRatingModel, MissionFeedbackModel},
+};
+
+#[automock]
+#[async_trait]
+pub trait MissionFeedbackRepository {
+    async fn add(&self, add_mission_feedback_entity: AddMissionFeedbackEntity) -> Result<i32>;
+    async fn has_rated(&self, mission_id: i32, rater_id: i32, ratee_id: i32) -> Result<bool>;
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>>;
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionFeedbackModel>>;
+    async fn get_by_ratee(&self, brawler_id: i32) -> Result<Vec<MissionFeedbackModel>>;
+    async fn get_rating(&self, brawler_id: i32) -> Result<BrawlerRatingModel>;
+}
@@ -1,11 +1,16 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
-use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
+use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity, MissionEntity};
 
+#[automock]
 #[async_trait]
 pub trait MissionManagementRepository {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
     async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    async fn set_tags(&self, mission_id: i32, tags: Vec<String>) -> Result<()>;
+    async fn find(&self, mission_id: i32) -> Result<MissionEntity>;
+    async fn get_tags(&self, mission_id: i32) -> Result<Vec<String>>;
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use mockall::automock;
+
+/// Counts mission and crew activity for the metrics endpoint.
+#[automock]
+pub trait MissionMetrics {
+    fn mission_created(&self);
+    fn crew_joined(&self);
+    fn crew_left(&self);
+    /// `from` and `to` are mission statuses.
+    fn status_changed(&self, from: &str, to: &str);
+}
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
+#[automock]
 #[async_trait]
 pub trait MissionOperationRepository {
     async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
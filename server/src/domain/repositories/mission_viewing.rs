@@ -1,14 +1,26 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 
 use crate::domain::value_objects::{
-    brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+    brawler_model::BrawlerModel, mission_cursor::MissionCursor, mission_filter::MissionFilter,
+    mission_model::MissionModel,
 };
 
+#[automock]
 #[async_trait]
 pub trait MissionViewingRepository {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
     async fn get_one(&self, mission_id: i32) -> Result<MissionModel>;
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionModel>>;
+    /// Returns at most `mission_filter.limit()` missions, plus the cursor of the
+    /// last one when more remain.
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        cursor: Option<MissionCursor>,
+        max_crew_per_mission: i64,
+    ) -> Result<(Vec<MissionModel>, Option<MissionCursor>)>;
+    async fn count_all(&self, mission_filter: &MissionFilter, max_crew_per_mission: i64)
+    -> Result<i64>;
     async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
 }
\ No newline at end of file
@@ -1,8 +1,19 @@
+pub mod achievement;
+pub mod audit;
 pub mod brawlers;
+pub mod category;
 pub mod mission_chat;
+pub mod mission_feedback;
 pub mod crew_operation;
+pub mod health;
+pub mod image_storage;
+pub mod maintenance;
 pub mod mission_management;
+pub mod mission_metrics;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod moderation;
+pub mod notification_publisher;
+pub mod rate_limit;
 pub mod system;
 // pub mod transaction_provider;
\ No newline at end of file
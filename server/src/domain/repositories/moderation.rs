@@ -0,0 +1,21 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{entities::brawlers::BrawlerEntity, value_objects::roles::Role};
+
+#[automock]
+#[async_trait]
+pub trait ModerationRepository {
+    async fn find_brawler(&self, brawler_id: i32) -> Result<BrawlerEntity>;
+    async fn ban(&self, brawler_id: i32, reason: Option<String>) -> Result<()>;
+    async fn unban(&self, brawler_id: i32) -> Result<()>;
+    async fn set_role(&self, brawler_id: i32, role: Role) -> Result<()>;
+    /// Fails an open or in-progress mission regardless of its chief and returns the
+    /// status it had before.
+    async fn force_fail_mission(&self, mission_id: i32) -> Result<String>;
+    /// Soft-deletes a mission in any status and drops its chat.
+    async fn delete_mission(&self, mission_id: i32) -> Result<()>;
+    /// Returns the mission the message belonged to.
+    async fn delete_chat_message(&self, message_id: i32) -> Result<i32>;
+}
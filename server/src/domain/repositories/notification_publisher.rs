@@ -0,0 +1,47 @@
+use mockall::automock;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::domain::value_objects::notification_model::NotificationModel;
+
+/// Pushes notifications to whatever connections a brawler has open.
+#[automock]
+pub trait NotificationPublisher {
+    /// Dropped silently when the brawler has no open connection.
+    fn publish(&self, brawler_id: i32, notification: NotificationModel);
+    fn subscribe(&self, brawler_id: i32) -> NotificationSubscription;
+}
+
+/// One connection's feed of a brawler's notifications. Dropping it
+/// unsubscribes.
+pub struct NotificationSubscription {
+    receiver: broadcast::Receiver<NotificationModel>,
+    // Declared after `receiver` so it drops last and sees the final count
+    _unsubscribe: Unsubscribe,
+}
+
+impl NotificationSubscription {
+    /// `unsubscribe` runs once `receiver` has been dropped.
+    pub fn new(
+        receiver: broadcast::Receiver<NotificationModel>,
+        unsubscribe: impl FnOnce() + Send + 'static,
+    ) -> Self {
+        Self {
+            receiver,
+            _unsubscribe: Unsubscribe(Some(Box::new(unsubscribe))),
+        }
+    }
+
+    pub async fn recv(&mut self) -> Result<NotificationModel, RecvError> {
+        self.receiver.recv().await
+    }
+}
+
+struct Unsubscribe(Option<Box<dyn FnOnce() + Send>>);
+
+impl Drop for Unsubscribe {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.0.take() {
+            unsubscribe();
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::rate_limit::{RateLimitDecision, RateLimitPolicy};
+
+#[automock]
+#[async_trait]
+pub trait RateLimitRepository {
+    /// Takes one token from `key`'s bucket, refilling it first according to `policy`.
+    async fn take(&self, key: String, policy: RateLimitPolicy) -> Result<RateLimitDecision>;
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use mockall::automock;
 use crate::domain::value_objects::system_stats::SystemStatsModel;
 
+#[automock]
 #[async_trait]
 pub trait SystemRepository {
     async fn get_stats(&self) -> Result<SystemStatsModel>;
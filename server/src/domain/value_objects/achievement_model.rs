@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::QueryableByName;
+use diesel::sql_types::BigInt;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, QueryableByName, ToSchema, TS)]
+pub struct AchievementProgressModel {
+    #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
+    pub missions_led: i64,
+    #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
+    pub missions_completed: i64,
+    #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
+    pub missions_joined: i64,
+    #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
+    pub categories_explored: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema, TS)]
+pub struct BrawlerAchievementModel {
+    pub brawler_id: i32,
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub awarded_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+pub struct BrawlerAchievementsModel {
+    pub progress: AchievementProgressModel,
+    pub achievements: Vec<BrawlerAchievementModel>,
+}
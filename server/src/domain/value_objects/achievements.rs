@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::value_objects::achievement_model::AchievementProgressModel;
+
+/// Condition a brawler's mission history has to satisfy to earn a badge.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(tag = "kind", content = "threshold")]
+pub enum AchievementRule {
+    MissionsLed(i64),
+    MissionsCompleted(i64),
+    MissionsJoined(i64),
+    CategoriesExplored(i64),
+}
+
+impl AchievementRule {
+    pub fn is_met(&self, progress: &AchievementProgressModel) -> bool {
+        match *self {
+            AchievementRule::MissionsLed(threshold) => progress.missions_led >= threshold,
+            AchievementRule::MissionsCompleted(threshold) => {
+                progress.missions_completed >= threshold
+            }
+            AchievementRule::MissionsJoined(threshold) => progress.missions_joined >= threshold,
+            AchievementRule::CategoriesExplored(threshold) => {
+                progress.categories_explored >= threshold
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AchievementDefinition {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub rule: AchievementRule,
+}
+
+/// Every badge a brawler can earn. Add new badges here; the evaluation
+/// engine picks them up without touching any use case.
+pub const ACHIEVEMENTS: &[AchievementDefinition] = &[
+    AchievementDefinition {
+        code: "first_mission_joined",
+        name: "Fresh Blood",
+        description: "Join your first mission",
+        rule: AchievementRule::MissionsJoined(1),
+    },
+    AchievementDefinition {
+        code: "first_mission_led",
+        name: "Shot Caller",
+        description: "Lead a mission to completion as its chief",
+        rule: AchievementRule::MissionsLed(1),
+    },
+    AchievementDefinition {
+        code: "five_missions_led",
+        name: "Kingpin",
+        description: "Lead 5 missions to completion as their chief",
+        rule: AchievementRule::MissionsLed(5),
+    },
+    AchievementDefinition {
+        code: "first_mission_completed",
+        name: "Made It Out",
+        description: "Complete your first mission",
+        rule: AchievementRule::MissionsCompleted(1),
+    },
+    AchievementDefinition {
+        code: "ten_missions_completed",
+        name: "Veteran",
+        description: "Complete 10 missions",
+        rule: AchievementRule::MissionsCompleted(10),
+    },
+    AchievementDefinition {
+        code: "five_categories_explored",
+        name: "Jack of All Trades",
+        description: "Take part in missions from 5 different categories",
+        rule: AchievementRule::CategoriesExplored(5),
+    },
+];
+
+pub fn find_achievement(code: &str) -> Option<&'static AchievementDefinition> {
+    ACHIEVEMENTS.iter().find(|achievement| achievement.code == code)
+}
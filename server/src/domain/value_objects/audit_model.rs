@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use serde_json::{Map, Value};
+
+pub const DEFAULT_AUDIT_PAGE_SIZE: i64 = 50;
+pub const MAX_AUDIT_PAGE_SIZE: i64 = 200;
+pub const MAX_AUDIT_PAGE: i64 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct AuditEventModel {
+    pub id: i32,
+    pub actor_id: i32,
+    pub actor_display_name: String,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: i32,
+    pub details: Value,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub request_id: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditEventFilter {
+    pub actor_id: Option<i32>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<i32>,
+    /// Inclusive lower and exclusive upper bound on `created_at`.
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl AuditEventFilter {
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1)
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_AUDIT_PAGE_SIZE)
+            .min(MAX_AUDIT_PAGE_SIZE)
+    }
+}
+
+/// Reduces two JSON object snapshots to the keys whose values differ, so an event
+/// stores `{"name": "old"}` / `{"name": "new"}` instead of whole rows. Anything that
+/// isn't an object pair is kept as is.
+pub fn diff_snapshots(before: Option<Value>, after: Option<Value>) -> (Option<Value>, Option<Value>) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let mut changed_before = Map::new();
+            let mut changed_after = Map::new();
+
+            for (key, old) in &before {
+                let new = after.get(key).unwrap_or(&Value::Null);
+                if old != new {
+                    changed_before.insert(key.clone(), old.clone());
+                    changed_after.insert(key.clone(), new.clone());
+                }
+            }
+            for (key, new) in &after {
+                if !before.contains_key(key) && !new.is_null() {
+                    changed_before.insert(key.clone(), Value::Null);
+                    changed_after.insert(key.clone(), new.clone());
+                }
+            }
+
+            (
+                Some(Value::Object(changed_before)),
+                Some(Value::Object(changed_after)),
+            )
+        }
+        (before, after) => (before, after),
+    }
+}
@@ -1,9 +1,15 @@
-use diesel::{prelude::QueryableByName, sql_types::{BigInt, Varchar}};
+use diesel::{prelude::QueryableByName, sql_types::{BigInt, Double, Int4, Varchar}};
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::RegisterBrawlerEntity,
+    value_objects::{achievement_model::BrawlerAchievementsModel, roles::Role},
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[ts(optional_fields)]
 pub struct RegisterBrawlerModel {
     pub username: String,
     pub password: String,
@@ -25,14 +31,33 @@ impl RegisterBrawlerModel {
     }
 }
 
-#[derive(Debug, Clone, Serialize,Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize,Deserialize, QueryableByName, ToSchema, TS)]
 pub struct BrawlerModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
     #[diesel(sql_type = Varchar)]
     pub display_name: String,
     #[diesel(sql_type = Varchar)]
     pub avatar_url: String,
     #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
     pub mission_success_count: i64,
     #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
     pub mission_joined_count: i64,
-}
\ No newline at end of file
+    #[diesel(sql_type = Double)]
+    pub rating_average: f64,
+    #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
+    pub rating_count: i64,
+}
+
+/// What anyone can see of a brawler, earned badges included.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+pub struct BrawlerProfileModel {
+    pub brawler_id: i32,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub role: Role,
+    pub achievements: BrawlerAchievementsModel,
+}
@@ -0,0 +1,49 @@
+use diesel::prelude::QueryableByName;
+use diesel::sql_types::{BigInt, Nullable, Text, Varchar};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::categories::AddCategoryEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName, ToSchema)]
+pub struct CategoryModel {
+    #[diesel(sql_type = Varchar)]
+    pub slug: String,
+    #[diesel(sql_type = Varchar)]
+    pub label: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub description: Option<String>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub icon: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    pub active_mission_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddCategoryModel {
+    pub slug: Option<String>,
+    pub label: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+}
+
+impl AddCategoryModel {
+    pub fn to_entity(&self, slug: String) -> AddCategoryEntity {
+        AddCategoryEntity {
+            slug,
+            label: self.label.trim().to_string(),
+            description: self.description.clone(),
+            icon: self.icon.clone(),
+        }
+    }
+}
+
+/// Lowercases and joins alphanumeric runs with `-`, e.g. "Night Ops" -> "night-ops".
+pub fn slugify(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Fail,
+    /// Failing, but not required for readiness.
+    Degraded,
+    /// Not configured, so not required for readiness.
+    Disabled,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HealthCheckModel {
+    pub status: HealthStatus,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HealthCheckModel {
+    pub fn disabled() -> Self {
+        Self {
+            status: HealthStatus::Disabled,
+            latency_ms: 0,
+            error: None,
+        }
+    }
+}
+
+/// Ready when no check failed; optional dependencies can only degrade it.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessModel {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<String, HealthCheckModel>,
+}
+
+impl ReadinessModel {
+    pub fn new(checks: BTreeMap<String, HealthCheckModel>) -> Self {
+        let any = |status| checks.values().any(|check| check.status == status);
+        let status = if any(HealthStatus::Fail) {
+            HealthStatus::Fail
+        } else if any(HealthStatus::Degraded) {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Ok
+        };
+
+        Self { status, checks }
+    }
+
+    /// Reported instead of running the checks once shutdown has begun.
+    pub fn draining() -> Self {
+        Self::new(BTreeMap::from([(
+            "shutdown".to_string(),
+            HealthCheckModel {
+                status: HealthStatus::Fail,
+                latency_ms: 0,
+                error: Some("Draining before shutdown".to_string()),
+            },
+        )]))
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// A list query the client got wrong, answered with `400` rather than `500`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidFilter(pub String);
+
+impl InvalidFilter {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for InvalidFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidFilter {}
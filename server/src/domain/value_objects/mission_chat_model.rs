@@ -2,8 +2,10 @@ use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
 use diesel::sql_types::{Int4, Text, Timestamp, Varchar};
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema, TS)]
 pub struct MissionChatModel {
     #[diesel(sql_type = Int4)]
     pub id: i32,
@@ -19,7 +21,7 @@ pub struct MissionChatModel {
     pub created_at: NaiveDateTime,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, TS)]
 pub struct AddMissionChatModel {
     pub message: String,
 }
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_filter::{MissionSort, SortDirection};
+
+/// Position after the last mission of a page: the sort it was taken under, the
+/// finished-last bucket, the sort key rendered as text and the id tie-breaker.
+/// Clients only ever see it as an opaque string.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionCursor {
+    pub sort: MissionSort,
+    pub direction: SortDirection,
+    pub bucket: i32,
+    pub key: String,
+    pub id: i32,
+}
+
+impl MissionCursor {
+    pub fn encode(&self) -> Result<String> {
+        let json = serde_json::to_vec(self)?;
+        Ok(general_purpose::URL_SAFE_NO_PAD.encode(json))
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self> {
+        let json = general_purpose::URL_SAFE_NO_PAD
+            .decode(cursor.trim())
+            .map_err(|_| anyhow::anyhow!("Invalid cursor"))?;
+        serde_json::from_slice(&json).map_err(|_| anyhow::anyhow!("Invalid cursor"))
+    }
+}
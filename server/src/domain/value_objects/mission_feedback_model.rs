@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::QueryableByName;
+use diesel::sql_types::{BigInt, Double, Int4, Nullable, Timestamp, Varchar};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::mission_feedbacks::AddMissionFeedbackEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct MissionFeedbackModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub rater_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub rater_display_name: String,
+    #[diesel(sql_type = Int4)]
+    pub ratee_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub ratee_display_name: String,
+    #[diesel(sql_type = Int4)]
+    pub rating: i32,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub comment: Option<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddMissionFeedbackModel {
+    pub ratee_id: i32,
+    pub rating: i32,
+    pub comment: Option<String>,
+}
+
+impl AddMissionFeedbackModel {
+    pub fn to_entity(&self, mission_id: i32, rater_id: i32) -> AddMissionFeedbackEntity {
+        AddMissionFeedbackEntity {
+            mission_id,
+            rater_id,
+            ratee_id: self.ratee_id,
+            rating: self.rating,
+            comment: self.comment.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct BrawlerRatingModel {
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Double)]
+    pub rating_average: f64,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BrawlerFeedbackSummaryModel {
+    #[serde(flatten)]
+    pub rating: BrawlerRatingModel,
+    pub feedbacks: Vec<MissionFeedbackModel>,
+}
@@ -1,13 +1,138 @@
+use anyhow::Result;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::value_objects::{category_model::slugify, mission_statuses::MissionStatuses};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub const DEFAULT_MISSION_PAGE_SIZE: i64 = 20;
+pub const MAX_MISSION_PAGE_SIZE: i64 = 100;
+pub const MAX_MISSION_PAGE: i64 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, IntoParams, TS)]
+#[ts(optional_fields)]
+#[into_params(parameter_in = Query)]
 pub struct MissionFilter {
     pub name: Option<String>,
     pub status: Option<MissionStatuses>,
+    /// Comma-separated statuses, combined with `status`.
+    pub statuses: Option<String>,
     pub category: Option<String>,
+    pub chief_id: Option<i32>,
     pub exclude_chief_id: Option<i32>,
+    /// Only missions this brawler is a crew member of.
+    pub member_id: Option<i32>,
+    /// Open missions that still have room in the crew (or, when false, those that don't).
+    pub has_open_slots: Option<bool>,
+    /// Inclusive creation date range.
+    pub created_from: Option<NaiveDate>,
+    pub created_to: Option<NaiveDate>,
+    /// Full-text query over name, description and tags, tolerant of small typos.
+    pub q: Option<String>,
+    /// Comma-separated tag names; a mission must carry all of them.
+    pub tags: Option<String>,
+    pub sort: Option<MissionSort>,
+    pub direction: Option<SortDirection>,
+    /// Opaque `next_cursor` from a previous page; takes precedence over `page`.
+    pub cursor: Option<String>,
+    #[ts(as = "Option<i32>")]
     pub page: Option<i64>,
+    #[ts(as = "Option<i32>")]
     pub limit: Option<i64>,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSort {
+    Relevance,
+    #[serde(alias = "newest")]
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    #[serde(alias = "most_crew")]
+    CrewCount,
+    #[serde(alias = "ending_soonest")]
+    EndsAt,
+}
+
+impl MissionSort {
+    pub fn default_direction(&self) -> SortDirection {
+        match self {
+            MissionSort::Name | MissionSort::EndsAt => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl MissionFilter {
+    pub fn search_query(&self) -> Option<String> {
+        self.q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_string)
+    }
+
+    pub fn tag_list(&self) -> Option<Vec<String>> {
+        let mut tags = self
+            .tags
+            .as_deref()?
+            .split(',')
+            .map(slugify)
+            .filter(|tag| !tag.is_empty())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+
+        (!tags.is_empty()).then_some(tags)
+    }
+
+    pub fn status_list(&self) -> Result<Option<Vec<MissionStatuses>>> {
+        let mut statuses = self
+            .statuses
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|status| !status.is_empty())
+            .map(str::parse::<MissionStatuses>)
+            .collect::<Result<Vec<_>>>()?;
+        statuses.extend(self.status.clone());
+
+        Ok((!statuses.is_empty()).then_some(statuses))
+    }
+
+    /// Relevance only makes sense with a search query, so newest is the fallback.
+    pub fn resolved_sort(&self) -> (MissionSort, SortDirection) {
+        let sort = match (&self.sort, self.search_query()) {
+            (Some(MissionSort::Relevance), None) => MissionSort::CreatedAt,
+            (Some(sort), _) => sort.clone(),
+            (None, Some(_)) => MissionSort::Relevance,
+            (None, None) => MissionSort::CreatedAt,
+        };
+        let direction = self
+            .direction
+            .clone()
+            .unwrap_or_else(|| sort.default_direction());
+
+        (sort, direction)
+    }
+
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1)
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_MISSION_PAGE_SIZE)
+            .min(MAX_MISSION_PAGE_SIZE)
+    }
+}
@@ -1,12 +1,17 @@
 use crate::domain::{
-    entities::missions::{AddMissionEntity, EditMissionEntity},
+    entities::{
+        categories::DEFAULT_CATEGORY_SLUG,
+        missions::{AddMissionEntity, EditMissionEntity},
+    },
     value_objects::mission_statuses::MissionStatuses,
 };
 use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
-use diesel::sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar};
+use diesel::sql_types::{Array, BigInt, Int4, Nullable, Text, Timestamp, Varchar};
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+use ts_rs::TS;
+use utoipa::ToSchema;
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName, ToSchema, TS)]
 pub struct MissionModel {
     #[diesel(sql_type = Int4)]
     pub id: i32,
@@ -15,12 +20,15 @@ pub struct MissionModel {
     #[diesel(sql_type = Nullable<Text>)]
     pub description: Option<String>,
     #[diesel(sql_type = Varchar)]
+    #[schema(value_type = MissionStatuses)]
+    #[ts(as = "MissionStatuses")]
     pub status: String,
     #[diesel(sql_type = Int4)]
     pub chief_id: i32,
     #[diesel(sql_type = Varchar)]
     pub chief_display_name: String,
     #[diesel(sql_type = BigInt)]
+    #[ts(type = "number")]
     pub crew_count: i64,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
@@ -28,13 +36,25 @@ pub struct MissionModel {
     pub updated_at: NaiveDateTime,
     #[diesel(sql_type = Varchar)]
     pub category: String,
+    #[diesel(sql_type = Varchar)]
+    pub category_label: String,
+    #[diesel(sql_type = Array<Text>)]
+    pub tags: Vec<String>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub ends_at: Option<NaiveDateTime>,
+    /// When the mission was completed or failed.
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub finished_at: Option<NaiveDateTime>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, TS)]
+#[ts(optional_fields)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
     pub category: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub ends_at: Option<NaiveDateTime>,
 }
 
 impl AddMissionModel {
@@ -43,17 +63,24 @@ impl AddMissionModel {
             name: self.name.clone(),
             description: self.description.clone(),
             status: MissionStatuses::Open.to_string(),
-            category: self.category.clone().unwrap_or("General".to_string()),
+            category: self
+                .category
+                .clone()
+                .unwrap_or(DEFAULT_CATEGORY_SLUG.to_string()),
+            ends_at: self.ends_at,
             chief_id,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, TS)]
+#[ts(optional_fields)]
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
     pub category: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub ends_at: Option<NaiveDateTime>,
 }
 
 impl EditMissionModel {
@@ -62,6 +89,7 @@ impl EditMissionModel {
             name: self.name.clone(),
             description: self.description.clone(),
             category: self.category.clone(),
+            ends_at: self.ends_at,
             chief_id,
         }
     }
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::value_objects::mission_model::MissionModel;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema, TS)]
+pub struct MissionPageModel {
+    pub items: Vec<MissionModel>,
+    #[ts(type = "number")]
+    pub total: i64,
+    /// Only set for page/limit requests; cursor requests have no page number.
+    #[ts(as = "Option<i32>")]
+    pub page: Option<i64>,
+    #[ts(type = "number")]
+    pub limit: i64,
+    #[ts(type = "number")]
+    pub total_pages: i64,
+    pub next_cursor: Option<String>,
+}
+
+impl MissionPageModel {
+    pub fn new(
+        items: Vec<MissionModel>,
+        total: i64,
+        page: Option<i64>,
+        limit: i64,
+        next_cursor: Option<String>,
+    ) -> Self {
+        Self {
+            items,
+            total,
+            page,
+            limit,
+            total_pages: (total + limit - 1) / limit,
+            next_cursor,
+        }
+    }
+}
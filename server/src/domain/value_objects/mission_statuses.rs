@@ -1,8 +1,10 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, ToSchema, TS)]
 pub enum MissionStatuses {
     #[default]
     Open,
@@ -20,4 +22,17 @@ impl Display for MissionStatuses {
             MissionStatuses::Failed => write!(f, "Failed"),
         }
     }
-}
\ No newline at end of file
+}
+impl FromStr for MissionStatuses {
+    type Err = anyhow::Error;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "Open" => Ok(Self::Open),
+            "InProgress" => Ok(Self::InProgress),
+            "Completed" => Ok(Self::Completed),
+            "Failed" => Ok(Self::Failed),
+            _ => Err(anyhow::anyhow!("Invalid mission status '{}'", status)),
+        }
+    }
+}
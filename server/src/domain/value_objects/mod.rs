@@ -1,9 +1,22 @@
+pub mod achievement_model;
+pub mod achievements;
+pub mod audit_model;
 pub mod brawler_model;
+pub mod category_model;
 pub mod mission_chat_model;
+pub mod mission_cursor;
+pub mod mission_feedback_model;
 pub mod mission_filter;
 pub mod mission_model;
+pub mod mission_page_model;
 pub mod mission_statuses;
+pub mod moderation_model;
+pub mod notification_model;
+pub mod rate_limit;
 pub mod base64_image;
 pub mod uploaded_image;
 pub mod system_stats;
+pub mod health_model;
+pub mod invalid_filter;
+pub mod roles;
 
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::value_objects::roles::Role;
+
+pub const MAX_BAN_REASON_LENGTH: usize = 280;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct BanBrawlerModel {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChangeRoleModel {
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct ModerationReasonModel {
+    pub reason: Option<String>,
+}
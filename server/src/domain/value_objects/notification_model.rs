@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::domain::value_objects::achievement_model::BrawlerAchievementModel;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum NotificationModel {
+    AchievementAwarded(BrawlerAchievementModel),
+}
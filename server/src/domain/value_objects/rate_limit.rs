@@ -0,0 +1,69 @@
+use std::{str::FromStr, time::Duration};
+
+/// Token bucket allowing `capacity` requests per `period`: the bucket holds at
+/// most `capacity` tokens and refills continuously at `capacity / period`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitPolicy {
+    pub capacity: u32,
+    pub period: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Until the bucket is full again.
+    pub reset_after: Duration,
+    /// Until the next token is available, when the request was refused.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitPolicy {
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        Self { capacity, period }
+    }
+
+    fn refill_per_sec(&self) -> f64 {
+        f64::from(self.capacity) / self.period.as_secs_f64()
+    }
+
+    /// Refills a bucket holding `tokens` for `elapsed`, then tries to take one.
+    /// Returns the new token count along with the decision.
+    pub fn take(&self, tokens: f64, elapsed: Duration) -> (f64, RateLimitDecision) {
+        let capacity = f64::from(self.capacity);
+        let rate = self.refill_per_sec();
+        let refilled = (tokens + elapsed.as_secs_f64() * rate).min(capacity);
+
+        let allowed = refilled >= 1.0;
+        let tokens = if allowed { refilled - 1.0 } else { refilled };
+
+        let decision = RateLimitDecision {
+            allowed,
+            limit: self.capacity,
+            remaining: tokens.floor() as u32,
+            reset_after: Duration::from_secs_f64((capacity - tokens) / rate),
+            retry_after: (!allowed).then(|| Duration::from_secs_f64((1.0 - tokens) / rate)),
+        };
+
+        (tokens, decision)
+    }
+}
+
+/// Written as `<requests>/<seconds>`, e.g. `10/60`.
+impl FromStr for RateLimitPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid rate limit '{}', expected <requests>/<seconds>", policy);
+
+        let (capacity, seconds) = policy.trim().split_once('/').ok_or_else(invalid)?;
+        let capacity = capacity.trim().parse::<u32>().map_err(|_| invalid())?;
+        let seconds = seconds.trim().parse::<u64>().map_err(|_| invalid())?;
+        if capacity == 0 || seconds == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(capacity, Duration::from_secs(seconds)))
+    }
+}
@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, TS)]
 pub struct SystemStatsModel {
+    #[ts(type = "number")]
     pub active_members: i64,
+    #[ts(type = "number")]
     pub missions_completed: i64,
+    #[ts(type = "number")]
     pub missions_failed: i64,
     pub success_rate: f64,
 }
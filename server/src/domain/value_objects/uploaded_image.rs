@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
 pub struct UploadedImage {
     pub url: String,
     pub public_id: String,
@@ -13,7 +15,7 @@ impl UploadedImage {
 
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UploadAvatar {
 pub base64_string: String,
 }
\ No newline at end of file
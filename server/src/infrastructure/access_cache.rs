@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::domain::value_objects::roles::Role;
+
+/// How long a loaded role or ban is trusted. Changes made in this process drop
+/// the entry at once; those made by another replica or `gangbro-admin` apply
+/// within this delay.
+const ACCESS_TTL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrawlerAccess {
+    pub role: Role,
+    pub banned: bool,
+}
+
+/// Recently loaded `BrawlerAccess`, so authorizing a request rarely costs a query.
+#[derive(Default)]
+pub struct AccessCache {
+    entries: DashMap<i32, (BrawlerAccess, Instant)>,
+}
+
+impl AccessCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, brawler_id: i32) -> Option<BrawlerAccess> {
+        let now = Instant::now();
+        let access = self
+            .entries
+            .get(&brawler_id)
+            .filter(|entry| now.duration_since(entry.1) < ACCESS_TTL)
+            .map(|entry| entry.0);
+
+        if access.is_none() {
+            self.entries
+                .remove_if(&brawler_id, |_, entry| now.duration_since(entry.1) >= ACCESS_TTL);
+        }
+        access
+    }
+
+    pub fn insert(&self, brawler_id: i32, access: BrawlerAccess) {
+        self.entries.insert(brawler_id, (access, Instant::now()));
+    }
+
+    /// Call after changing a brawler's role or ban.
+    pub fn invalidate(&self, brawler_id: i32) {
+        self.entries.remove(&brawler_id);
+    }
+}
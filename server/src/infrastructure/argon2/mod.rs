@@ -1,3 +1,5 @@
+use std::sync::LazyLock;
+
 use argon2::{
     Argon2,
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
@@ -26,3 +28,18 @@ pub fn verify(password: String, hashed_password: String) -> Result<bool> {
         .verify_password(bytes_password, &parsed_hash)
         .is_ok())
 }
+
+/// Hash of a random password, checked when the username doesn't exist so that
+/// unknown users cost the same Argon2 work as a wrong password.
+static DUMMY_HASH: LazyLock<String> = LazyLock::new(|| {
+    hash(SaltString::generate(&mut OsRng).to_string()).expect("Failed to build dummy password hash")
+});
+
+pub fn verify_dummy(password: String) {
+    let _ = verify(password, DUMMY_HASH.clone());
+}
+
+/// Builds the dummy hash up front instead of on the first unknown-user login.
+pub fn warm_up() {
+    LazyLock::force(&DUMMY_HASH);
+}
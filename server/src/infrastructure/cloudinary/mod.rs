@@ -1,7 +1,10 @@
-use crate::config::{config_loader::get_cloudinary_env, config_model::CloudinaryEnv};
+use crate::config::config_model::CloudinaryEnv;
+use crate::domain::repositories::image_storage::ImageStorage;
 use crate::domain::value_objects::base64_image::Base64Image;
 use crate::domain::value_objects::uploaded_image::UploadedImage;
+use crate::infrastructure::telemetry;
 use anyhow::{Context, Ok, Result};
+use async_trait::async_trait;
 use chrono::Utc;
 use reqwest::multipart::{Form, Part};
 use sha1::{Digest, Sha1};
@@ -57,14 +60,18 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
     Ok(form)
 }
 
+#[tracing::instrument(
+    name = "cloudinary.upload",
+    skip_all,
+    fields(otel.kind = "client", http.response.status_code = tracing::field::Empty)
+)]
 pub async fn upload(
     base64_image: Base64Image,
     option: UploadImageOptions,
+    cloud_env: &CloudinaryEnv,
 ) -> Result<UploadedImage> {
-    let cloud_env = get_cloudinary_env()?;
-
     let file = Part::text(base64_image.into_inner());
-    let form = form_builder(option, &cloud_env)?;
+    let form = form_builder(option, cloud_env)?;
     let multipart = form.part("file", file);
     let client = reqwest::Client::new();
     let url = format!(
@@ -74,13 +81,64 @@ pub async fn upload(
 
     let response = client
         .post(&url)
+        .headers(telemetry::trace_context_headers())
         .multipart(multipart)
         .send()
         .await
         .context(format!("upload to {}", url))?;
+    tracing::Span::current().record("http.response.status_code", response.status().as_u16());
 
     let text = response.text().await?;
     let json: UploadedImage =
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
-}
\ No newline at end of file
+}
+
+/// Cloudinary's authenticated no-op, answering `{"status": "ok"}`.
+pub async fn ping(cloud_env: &CloudinaryEnv) -> Result<()> {
+    let url = format!("https://api.cloudinary.com/v1_1/{}/ping", cloud_env.cloud_name);
+
+    reqwest::Client::new()
+        .get(&url)
+        .basic_auth(&cloud_env.api_key, Some(&cloud_env.api_secret))
+        .send()
+        .await
+        .context(format!("ping {}", url))?
+        .error_for_status()?;
+
+    Ok(())
+}
+
+/// Image storage backed by Cloudinary. Without credentials every upload fails.
+pub struct CloudinaryStorage {
+    cloudinary: Option<CloudinaryEnv>,
+}
+
+impl CloudinaryStorage {
+    pub fn new(cloudinary: Option<CloudinaryEnv>) -> Self {
+        Self { cloudinary }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryStorage {
+    async fn upload(&self, base64img: Base64Image, opt: UploadImageOptions) -> Result<UploadedImage> {
+        let cloud_env = self
+            .cloudinary
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Image uploads are not configured"))?;
+
+        upload(base64img, opt, cloud_env).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.cloudinary.is_some()
+    }
+
+    async fn ping(&self) -> Result<()> {
+        match &self.cloudinary {
+            Some(cloud_env) => ping(cloud_env).await,
+            None => Err(anyhow::anyhow!("Image uploads are not configured")),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+CREATE TABLE mission_feedbacks (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id),
+    rater_id INTEGER NOT NULL REFERENCES brawlers(id),
+    ratee_id INTEGER NOT NULL REFERENCES brawlers(id),
+    rating INTEGER NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    comment VARCHAR(280),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT unique_mission_feedback UNIQUE (mission_id, rater_id, ratee_id),
+    CONSTRAINT no_self_feedback CHECK (rater_id <> ratee_id)
+);
+
+CREATE INDEX idx_mission_feedbacks_ratee_id ON mission_feedbacks (ratee_id);
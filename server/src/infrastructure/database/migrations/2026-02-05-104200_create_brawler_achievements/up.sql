@@ -0,0 +1,6 @@
+CREATE TABLE brawler_achievements (
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id),
+    achievement_code VARCHAR(50) NOT NULL,
+    awarded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (brawler_id, achievement_code)
+);
@@ -0,0 +1,10 @@
+ALTER TABLE missions
+DROP CONSTRAINT fk_category,
+ALTER COLUMN category SET DEFAULT 'General';
+
+UPDATE missions
+SET category = categories.label
+FROM categories
+WHERE categories.slug = missions.category;
+
+DROP TABLE categories;
@@ -0,0 +1,41 @@
+CREATE TABLE categories (
+    slug VARCHAR(50) PRIMARY KEY,
+    label VARCHAR(50) NOT NULL,
+    description TEXT,
+    icon VARCHAR(50),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    retired_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX unique_category_label ON categories (LOWER(label));
+
+INSERT INTO categories (slug, label, description, icon) VALUES
+    ('general', 'General', 'Anything that does not fit elsewhere', 'assignment'),
+    ('gaming', 'Gaming', 'Raids, ranked grinds and LAN nights', 'sports_esports'),
+    ('work', 'Work', 'Jobs that pay the bills', 'work'),
+    ('fitness', 'Fitness', 'Training, runs and sports', 'fitness_center'),
+    ('social', 'Social', 'Meetups and hangouts', 'forum'),
+    ('learning', 'Learning', 'Study groups and skill drills', 'school'),
+    ('creative', 'Creative', 'Art, music and side projects', 'palette'),
+    ('finance', 'Finance', 'Budgets, savings and investments', 'payments');
+
+-- Keep every category already used by a mission, keyed by its slugified name
+INSERT INTO categories (slug, label)
+SELECT DISTINCT ON (slug) slug, label
+FROM (
+    SELECT LOWER(TRIM(BOTH '-' FROM REGEXP_REPLACE(TRIM(category), '[^a-zA-Z0-9]+', '-', 'g'))) AS slug,
+        TRIM(category) AS label
+    FROM missions
+) used
+WHERE slug <> ''
+ON CONFLICT DO NOTHING;
+
+UPDATE missions
+SET category = COALESCE(
+    NULLIF(LOWER(TRIM(BOTH '-' FROM REGEXP_REPLACE(TRIM(category), '[^a-zA-Z0-9]+', '-', 'g'))), ''),
+    'general'
+);
+
+ALTER TABLE missions
+ALTER COLUMN category SET DEFAULT 'general',
+ADD CONSTRAINT fk_category FOREIGN KEY (category) REFERENCES categories(slug);
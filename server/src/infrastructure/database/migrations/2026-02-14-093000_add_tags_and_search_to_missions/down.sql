@@ -0,0 +1,14 @@
+DROP INDEX idx_missions_name_trgm;
+
+DROP TRIGGER refresh_search_vector ON mission_tags;
+DROP TRIGGER set_search_vector ON missions;
+DROP FUNCTION mission_tags_refresh_search_vector();
+DROP FUNCTION missions_set_search_vector();
+DROP FUNCTION mission_search_vector(VARCHAR, TEXT, INTEGER);
+
+ALTER TABLE missions
+DROP COLUMN search_vector,
+DROP COLUMN ends_at;
+
+DROP TABLE mission_tags;
+DROP TABLE tags;
@@ -0,0 +1,62 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(30) NOT NULL,
+    CONSTRAINT unique_tag_name UNIQUE (name)
+);
+
+CREATE TABLE mission_tags (
+    mission_id INTEGER NOT NULL REFERENCES missions(id),
+    tag_id INTEGER NOT NULL REFERENCES tags(id),
+    PRIMARY KEY (mission_id, tag_id)
+);
+
+CREATE INDEX idx_mission_tags_tag_id ON mission_tags (tag_id);
+
+ALTER TABLE missions ADD COLUMN ends_at TIMESTAMP;
+
+-- Maintained by triggers because tags live outside the missions row
+ALTER TABLE missions ADD COLUMN search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+
+CREATE OR REPLACE FUNCTION mission_search_vector(_name VARCHAR, _description TEXT, _mission_id INTEGER)
+RETURNS TSVECTOR AS $$
+    SELECT setweight(to_tsvector('simple', COALESCE(_name, '')), 'A')
+        || setweight(to_tsvector('simple', COALESCE((
+            SELECT STRING_AGG(t.name, ' ')
+            FROM mission_tags mt
+            INNER JOIN tags t ON t.id = mt.tag_id
+            WHERE mt.mission_id = _mission_id
+        ), '')), 'B')
+        || setweight(to_tsvector('simple', COALESCE(_description, '')), 'C');
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION missions_set_search_vector() RETURNS trigger AS $$
+BEGIN
+    NEW.search_vector := mission_search_vector(NEW.name, NEW.description, NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_search_vector BEFORE INSERT OR UPDATE OF name, description ON missions
+    FOR EACH ROW EXECUTE PROCEDURE missions_set_search_vector();
+
+CREATE OR REPLACE FUNCTION mission_tags_refresh_search_vector() RETURNS trigger AS $$
+DECLARE
+    _mission_id INTEGER := COALESCE(NEW.mission_id, OLD.mission_id);
+BEGIN
+    UPDATE missions
+    SET search_vector = mission_search_vector(name, description, id)
+    WHERE id = _mission_id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER refresh_search_vector AFTER INSERT OR DELETE ON mission_tags
+    FOR EACH ROW EXECUTE PROCEDURE mission_tags_refresh_search_vector();
+
+UPDATE missions SET search_vector = mission_search_vector(name, description, id);
+
+CREATE INDEX idx_missions_search_vector ON missions USING GIN (search_vector);
+CREATE INDEX idx_missions_name_trgm ON missions USING GIN (name gin_trgm_ops);
+CREATE INDEX idx_tags_name_trgm ON tags USING GIN (name gin_trgm_ops);
@@ -0,0 +1,7 @@
+DROP TABLE audit_events;
+
+ALTER TABLE brawlers
+DROP CONSTRAINT check_brawler_role,
+DROP COLUMN ban_reason,
+DROP COLUMN banned_at,
+DROP COLUMN role;
@@ -0,0 +1,15 @@
+ALTER TABLE brawlers
+ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'member',
+ADD COLUMN banned_at TIMESTAMP,
+ADD COLUMN ban_reason VARCHAR(280),
+ADD CONSTRAINT check_brawler_role CHECK (role IN ('member', 'moderator', 'admin'));
+
+CREATE TABLE audit_events (
+    id SERIAL PRIMARY KEY,
+    actor_id INTEGER NOT NULL REFERENCES brawlers(id),
+    action VARCHAR(50) NOT NULL,
+    target_type VARCHAR(50) NOT NULL,
+    target_id INTEGER NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
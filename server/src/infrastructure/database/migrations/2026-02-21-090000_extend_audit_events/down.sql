@@ -0,0 +1,11 @@
+DROP TRIGGER append_only ON audit_events;
+DROP FUNCTION audit_events_append_only();
+
+DROP INDEX idx_audit_events_created_at;
+DROP INDEX idx_audit_events_target;
+DROP INDEX idx_audit_events_actor;
+
+ALTER TABLE audit_events
+DROP COLUMN request_id,
+DROP COLUMN after,
+DROP COLUMN before;
@@ -0,0 +1,17 @@
+ALTER TABLE audit_events
+ADD COLUMN before JSONB,
+ADD COLUMN after JSONB,
+ADD COLUMN request_id VARCHAR(64);
+
+CREATE INDEX idx_audit_events_actor ON audit_events (actor_id, created_at);
+CREATE INDEX idx_audit_events_target ON audit_events (target_type, target_id, created_at);
+CREATE INDEX idx_audit_events_created_at ON audit_events (created_at);
+
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER append_only BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW EXECUTE PROCEDURE audit_events_append_only();
@@ -0,0 +1 @@
+ALTER TABLE missions DROP COLUMN finished_at;
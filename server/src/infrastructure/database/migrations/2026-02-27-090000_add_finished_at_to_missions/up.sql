@@ -0,0 +1,6 @@
+ALTER TABLE missions ADD COLUMN finished_at TIMESTAMP;
+
+-- Missions that finished before the column existed last changed when they did
+UPDATE missions
+SET finished_at = updated_at
+WHERE status IN ('Completed', 'Failed');
@@ -1,36 +1,137 @@
 use anyhow::{Result, Context};
 use diesel::{
+    migration::{MigrationSource, MigrationVersion},
+    pg::Pg,
     prelude::*,
     r2d2::{ConnectionManager, Pool},
+    sql_types::BigInt,
 };
+use serde::Serialize;
+use tracing::warn;
+use crate::config::config_model::Database;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/infrastructure/database/migrations");
 
 pub type PgPoolSquad = Pool<ConnectionManager<PgConnection>>;
 
-pub fn establish_connection(database_url: &str) -> Result<PgPoolSquad> {
-    let manager = ConnectionManager::<PgConnection>::new(database_url);
-    
-    // Read pool size from ENV or default to 5 (safe for small Supabase instances)
-    let pool_size: u32 = std::env::var("DATABASE_POOL_SIZE")
-        .unwrap_or_else(|_| "5".to_string())
-        .parse()
-        .unwrap_or(5);
-
-    let pool = Pool::builder()
-        .max_size(pool_size)
-        .build(manager)?;
-    
-    // Run migrations on startup
-    let mut conn = pool.get().context("Failed to get connection for migrations")?;
-    run_migrations(&mut conn)?;
-    
-    Ok(pool)
-}
-
-pub fn run_migrations(connection: &mut impl MigrationHarness<diesel::pg::Pg>) -> Result<()> {
-    connection.run_pending_migrations(MIGRATIONS)
-        .map_err(|e| anyhow::anyhow!("Failed to run migrations: {}", e))?;
-    Ok(())
-}
\ No newline at end of file
+/// Key of the Postgres advisory lock held while migrations run or revert, so
+/// replicas starting together take turns instead of racing.
+const MIGRATION_LOCK_KEY: i64 = 0x6762_6d69_6772_6174;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationState {
+    pub name: String,
+    pub applied: bool,
+}
+
+/// A pool that leaves the schema as it is.
+pub fn connection_pool(database: &Database) -> Result<PgPoolSquad> {
+    let manager = ConnectionManager::<PgConnection>::new(&database.url);
+
+    Ok(Pool::builder()
+        .max_size(database.pool_size)
+        .build(manager)?)
+}
+
+/// Runs `change` while holding the migration lock, waiting for it if another
+/// process has it. The lock goes with the connection should this one die.
+fn with_migration_lock<T>(
+    connection: &mut PgConnection,
+    change: impl FnOnce(&mut PgConnection) -> Result<T>,
+) -> Result<T> {
+    diesel::sql_query("SELECT pg_advisory_lock($1)")
+        .bind::<BigInt, _>(MIGRATION_LOCK_KEY)
+        .execute(connection)
+        .context("Failed to take the migration lock")?;
+
+    let result = change(connection);
+
+    // The outcome of `change` is what callers need; a lock we fail to release
+    // is freed anyway once this connection closes.
+    if let Err(e) = diesel::sql_query("SELECT pg_advisory_unlock($1)")
+        .bind::<BigInt, _>(MIGRATION_LOCK_KEY)
+        .execute(connection)
+    {
+        warn!(error = %e, "Failed to release the migration lock");
+    }
+
+    result
+}
+
+/// Returns the names of the migrations that ran. Pending migrations are only
+/// listed once the lock is held, so whoever waited finds nothing left to do.
+pub fn run_migrations(connection: &mut PgConnection) -> Result<Vec<String>> {
+    with_migration_lock(connection, |connection| {
+        let pending = connection
+            .pending_migrations(MIGRATIONS)
+            .map_err(|e| anyhow::anyhow!("Failed to list migrations: {}", e))?
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+
+        connection.run_pending_migrations(MIGRATIONS)
+            .map_err(|e| anyhow::anyhow!("Failed to run migrations: {}", e))?;
+        Ok(pending)
+    })
+}
+
+/// Reverts the last `steps` applied migrations, newest first, and returns their names.
+pub fn revert_migrations(connection: &mut PgConnection, steps: usize) -> Result<Vec<String>> {
+    with_migration_lock(connection, |connection| {
+        let mut reverted = Vec::new();
+        for _ in 0..steps {
+            if connection
+                .applied_migrations()
+                .map_err(|e| anyhow::anyhow!("Failed to list migrations: {}", e))?
+                .is_empty()
+            {
+                break;
+            }
+
+            let version = connection
+                .revert_last_migration(MIGRATIONS)
+                .map_err(|e| anyhow::anyhow!("Failed to revert migration: {}", e))?;
+            reverted.push(migration_name(&version)?);
+        }
+
+        Ok(reverted)
+    })
+}
+
+pub fn pending_migrations(connection: &mut impl MigrationHarness<Pg>) -> Result<usize> {
+    Ok(connection
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to list migrations: {}", e))?
+        .len())
+}
+
+/// Every embedded migration, oldest first.
+pub fn migration_states(connection: &mut impl MigrationHarness<Pg>) -> Result<Vec<MigrationState>> {
+    let applied = connection
+        .applied_migrations()
+        .map_err(|e| anyhow::anyhow!("Failed to list migrations: {}", e))?;
+
+    Ok(embedded_migrations()?
+        .iter()
+        .map(|migration| MigrationState {
+            name: migration.name().to_string(),
+            applied: applied.contains(&migration.name().version()),
+        })
+        .collect())
+}
+
+fn embedded_migrations() -> Result<Vec<Box<dyn diesel::migration::Migration<Pg>>>> {
+    let mut migrations = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to read migrations: {}", e))?;
+    migrations.sort_by_key(|migration| migration.name().version().as_owned());
+
+    Ok(migrations)
+}
+
+fn migration_name(version: &MigrationVersion) -> Result<String> {
+    Ok(embedded_migrations()?
+        .iter()
+        .find(|migration| migration.name().version() == *version)
+        .map_or_else(|| version.to_string(), |migration| migration.name().to_string()))
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::brawler_achievements::{AwardAchievementEntity, BrawlerAchievementEntity},
+        repositories::achievement::AchievementRepository,
+        value_objects::achievement_model::AchievementProgressModel,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawler_achievements, crew_memberships, missions},
+    },
+};
+
+pub struct AchievementPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AchievementPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for AchievementPostgres {
+    async fn get_progress(&self, brawler_id: i32) -> Result<AchievementProgressModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+SELECT
+    (SELECT COUNT(*)
+        FROM missions m
+        WHERE m.chief_id = $1
+            AND m.status = 'Completed'
+            AND m.deleted_at IS NULL) AS missions_led,
+    (SELECT COUNT(*)
+        FROM crew_memberships cm
+        INNER JOIN missions m ON m.id = cm.mission_id
+        WHERE cm.brawler_id = $1
+            AND m.status = 'Completed'
+            AND m.deleted_at IS NULL) AS missions_completed,
+    (SELECT COUNT(*)
+        FROM crew_memberships cm
+        INNER JOIN missions m ON m.id = cm.mission_id
+        WHERE cm.brawler_id = $1
+            AND m.deleted_at IS NULL) AS missions_joined,
+    (SELECT COUNT(DISTINCT m.category)
+        FROM crew_memberships cm
+        INNER JOIN missions m ON m.id = cm.mission_id
+        WHERE cm.brawler_id = $1
+            AND m.deleted_at IS NULL) AS categories_explored
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<AchievementProgressModel>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = brawler_achievements::table
+            .filter(brawler_achievements::brawler_id.eq(brawler_id))
+            .order(brawler_achievements::awarded_at.asc())
+            .select(BrawlerAchievementEntity::as_select())
+            .load::<BrawlerAchievementEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn award(
+        &self,
+        award_achievement_entities: Vec<AwardAchievementEntity>,
+    ) -> Result<Vec<BrawlerAchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Rows that already exist are skipped, so only fresh awards come back
+        let results = insert_into(brawler_achievements::table)
+            .values(&award_achievement_entities)
+            .on_conflict_do_nothing()
+            .returning(BrawlerAchievementEntity::as_returning())
+            .get_results::<BrawlerAchievementEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut participants = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .select(crew_memberships::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        let chief_id = missions::table
+            .filter(missions::id.eq(mission_id))
+            .select(missions::chief_id)
+            .first::<i32>(&mut conn)?;
+
+        if !participants.contains(&chief_id) {
+            participants.push(chief_id);
+        }
+
+        Ok(participants)
+    }
+}
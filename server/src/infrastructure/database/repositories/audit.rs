@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::audit_events::{AddAuditEventEntity, AuditEventEntity},
+        repositories::audit::AuditRepository,
+        value_objects::audit_model::{AuditEventFilter, AuditEventModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{audit_events, brawlers},
+    },
+};
+
+pub struct AuditPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AuditPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for AuditPostgres {
+    async fn record(&self, add_audit_event_entity: AddAuditEventEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(audit_events::table)
+            .values(add_audit_event_entity)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn query(&self, filter: &AuditEventFilter) -> Result<Vec<AuditEventModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = audit_events::table
+            .inner_join(brawlers::table)
+            .select((AuditEventEntity::as_select(), brawlers::display_name))
+            .into_boxed();
+
+        if let Some(actor_id) = filter.actor_id {
+            query = query.filter(audit_events::actor_id.eq(actor_id));
+        }
+        if let Some(action) = &filter.action {
+            query = query.filter(audit_events::action.eq(action.clone()));
+        }
+        if let Some(target_type) = &filter.target_type {
+            query = query.filter(audit_events::target_type.eq(target_type.clone()));
+        }
+        if let Some(target_id) = filter.target_id {
+            query = query.filter(audit_events::target_id.eq(target_id));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(audit_events::created_at.ge(from));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(audit_events::created_at.lt(to));
+        }
+
+        let limit = filter.limit();
+        let rows = query
+            .order((audit_events::created_at.desc(), audit_events::id.desc()))
+            .limit(limit)
+            .offset((filter.page() - 1) * limit)
+            .load::<(AuditEventEntity, String)>(&mut conn)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(entity, actor_display_name)| entity.to_model(actor_display_name))
+            .collect())
+    }
+}
@@ -1,6 +1,5 @@
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
 // use diesel::{
 //     ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into,
 //     query_dsl::methods::{FilterDsl, SelectDsl},
@@ -9,24 +8,14 @@ use diesel::{dsl::insert_into, prelude::*};
 use std::sync::Arc;
 
 use crate::{
-    config::config_loader::get_jwt_env,
     domain::{
         entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
         repositories::brawlers::BrawlerRepository,
-        value_objects::{
-            base64_image::Base64Image, mission_model::MissionModel, uploaded_image::UploadedImage,
-        },
+        value_objects::{mission_model::MissionModel, uploaded_image::UploadedImage},
     },
-    infrastructure::{
-        cloudinary::{self, UploadImageOptions},
-        database::{
-            postgresql_connection::PgPoolSquad,
-            schema::{brawlers, crew_memberships},
-        },
-        jwt::{
-            generate_token,
-            jwt_model::{Claims, Passport},
-        },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, crew_memberships},
     },
 };
 
@@ -42,64 +31,53 @@ impl BrawlerPostgres {
 
 #[async_trait]
 impl BrawlerRepository for BrawlerPostgres {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport> {
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        connection.transaction::<Passport, anyhow::Error, _>(|conn| {
-            let user_id = insert_into(brawlers::table)
-                .values(&register_brawler_entity)
-                .returning(brawlers::id)
-                .get_result::<i32>(conn)?;
-
-            let display_name = register_brawler_entity.display_name.clone();
-
-            let jwt_env = get_jwt_env().map_err(|e| anyhow::anyhow!("Config error: {}", e))?;
-            let claims = Claims {
-                sub: user_id.to_string(),
-                exp: (Utc::now() + Duration::days(jwt_env.lift_time_days)).timestamp() as usize,
-                iat: Utc::now().timestamp() as usize,
-            };
-            let token = generate_token(jwt_env.secret, &claims)?;
-            
-            Ok(Passport {
-                user_id,
-                token,
-                display_name,
-                avatar_url: None,
-            })
-        })
+        let user_id = insert_into(brawlers::table)
+            .values(&register_brawler_entity)
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut connection)?;
+
+        Ok(user_id)
     }
 
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity> {
+    async fn find_by_username(&self, username: String) -> Result<Option<BrawlerEntity>> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = brawlers::table
             .filter(brawlers::username.eq(username))
             .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+            .first::<BrawlerEntity>(&mut connection)
+            .optional()?;
 
         Ok(result)
     }
 
-    async fn upload_base64img(
-        &self,
-        user_id: i32,
-        base64img: Base64Image,
-        opt: UploadImageOptions,
-    ) -> Result<UploadedImage> {
-        let uploaded_img = cloudinary::upload(base64img, opt).await?;
+    async fn find_by_id(&self, brawler_id: i32) -> Result<Option<BrawlerEntity>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .find(brawler_id)
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)
+            .optional()?;
+
+        Ok(result)
+    }
 
+    async fn update_avatar(&self, user_id: i32, uploaded_image: UploadedImage) -> Result<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         diesel::update(brawlers::table)
             .filter(brawlers::id.eq(user_id))
             .set((
-                brawlers::avatar_url.eq(uploaded_img.url.clone()),
-                brawlers::avatar_public_id.eq(uploaded_img.public_id.clone()),
+                brawlers::avatar_url.eq(uploaded_image.url),
+                brawlers::avatar_public_id.eq(uploaded_image.public_id),
             ))
             .execute(&mut conn)?;
 
-        Ok(uploaded_img)
+        Ok(())
     }
 
     async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
@@ -118,9 +96,19 @@ SELECT DISTINCT
     (SELECT COUNT(*) FROM crew_memberships WHERE crew_memberships.mission_id = missions.id) AS crew_count,
     missions.created_at,
     missions.updated_at,
-    missions.category
+    missions.category,
+    categories.label AS category_label,
+    COALESCE((
+        SELECT ARRAY_AGG(tags.name::text ORDER BY tags.name)
+        FROM mission_tags
+        INNER JOIN tags ON tags.id = mission_tags.tag_id
+        WHERE mission_tags.mission_id = missions.id
+    ), '{}') AS tags,
+    missions.ends_at,
+    missions.finished_at
 FROM missions
 INNER JOIN brawlers ON brawlers.id = missions.chief_id
+INNER JOIN categories ON categories.slug = missions.category
 LEFT JOIN crew_memberships cm ON cm.mission_id = missions.id
 WHERE missions.deleted_at IS NULL
     AND (missions.chief_id = $1 OR cm.brawler_id = $1)
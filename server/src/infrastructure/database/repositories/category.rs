@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{dsl::now, insert_into, prelude::*, sql_types::Varchar, update};
+
+use crate::{
+    domain::{
+        entities::categories::{AddCategoryEntity, CategoryEntity},
+        repositories::category::CategoryRepository,
+        value_objects::category_model::CategoryModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::categories},
+};
+
+define_sql_function!(fn lower(value: Varchar) -> Varchar);
+
+pub struct CategoryPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl CategoryPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryPostgres {
+    async fn get_all(&self) -> Result<Vec<CategoryModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+SELECT c.slug,
+        c.label,
+        c.description,
+        c.icon,
+        COUNT(m.id) AS active_mission_count
+FROM categories c
+LEFT JOIN missions m ON m.category = c.slug
+    AND m.deleted_at IS NULL
+    AND m.status IN ('Open', 'InProgress')
+WHERE c.retired_at IS NULL
+GROUP BY c.slug, c.label, c.description, c.icon
+ORDER BY c.label ASC
+        "#;
+
+        let results = diesel::sql_query(sql).load::<CategoryModel>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_active(&self, slug_or_label: String) -> Result<Option<CategoryEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = categories::table
+            .filter(categories::retired_at.is_null())
+            .filter(
+                categories::slug
+                    .eq(slug_or_label.to_lowercase())
+                    .or(lower(categories::label).eq(slug_or_label.to_lowercase())),
+            )
+            .select(CategoryEntity::as_select())
+            .first::<CategoryEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn add(&self, add_category_entity: AddCategoryEntity) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(categories::table)
+            .values(add_category_entity)
+            .returning(categories::slug)
+            .get_result::<String>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn retire(&self, slug: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = update(categories::table)
+            .filter(categories::slug.eq(slug))
+            .filter(categories::retired_at.is_null())
+            .set(categories::retired_at.eq(now))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(anyhow::anyhow!("Category not found or already retired"));
+        }
+
+        Ok(())
+    }
+}
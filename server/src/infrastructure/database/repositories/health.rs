@@ -0,0 +1,60 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use diesel::{
+    PgConnection, RunQueryDsl,
+    r2d2::{ConnectionManager, PooledConnection},
+};
+use crate::{
+    domain::repositories::health::HealthRepository,
+    infrastructure::database::postgresql_connection::{PgPoolSquad, pending_migrations},
+};
+
+/// Probes answer well within the probe timeout instead of waiting out the pool's.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+
+type PgPooledConnection = PooledConnection<ConnectionManager<PgConnection>>;
+
+pub struct HealthPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl HealthPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+
+    /// Runs `check` on a blocking thread, so a database that hangs can't stall
+    /// the workers serving other requests.
+    async fn with_connection<T, F>(&self, check: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut PgPooledConnection) -> Result<T> + Send + 'static,
+    {
+        let db_pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = db_pool
+                .get_timeout(CONNECTION_TIMEOUT)
+                .context("Failed to get connection")?;
+            check(&mut conn)
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl HealthRepository for HealthPostgres {
+    async fn ping(&self) -> Result<()> {
+        self.with_connection(|conn| {
+            diesel::sql_query("SELECT 1").execute(conn)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn pending_migrations(&self) -> Result<usize> {
+        self.with_connection(pending_migrations).await
+    }
+}
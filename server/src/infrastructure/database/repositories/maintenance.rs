@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{Connection, delete, dsl::now, insert_into, prelude::*, update};
+
+use crate::{
+    domain::{
+        entities::brawlers::RegisterBrawlerEntity, repositories::maintenance::MaintenanceRepository,
+        value_objects::roles::Role,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, crew_memberships, mission_chats, mission_feedbacks, mission_tags, missions},
+    },
+};
+
+pub struct MaintenancePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MaintenancePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MaintenanceRepository for MaintenancePostgres {
+    async fn register_with_role(&self, register_brawler_entity: RegisterBrawlerEntity, role: Role) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let brawler_id = insert_into(brawlers::table)
+            .values((&register_brawler_entity, brawlers::role.eq(role.to_string())))
+            .returning(brawlers::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(brawler_id)
+    }
+
+    async fn set_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::password.eq(hashed_password),
+                brawlers::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(anyhow::anyhow!("Brawler not found"));
+        }
+
+        Ok(())
+    }
+
+    async fn brawler_ids(&self) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .select(brawlers::id)
+            .order(brawlers::id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn purge_deleted_missions(&self, deleted_before: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let mission_ids = missions::table
+                .filter(missions::deleted_at.lt(deleted_before))
+                .select(missions::id)
+                .order(missions::id)
+                .for_update()
+                .load::<i32>(conn)?;
+
+            delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_chats::table)
+                .filter(mission_chats::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_feedbacks::table)
+                .filter(mission_feedbacks::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(mission_tags::table)
+                .filter(mission_tags::mission_id.eq_any(&mission_ids))
+                .execute(conn)?;
+            delete(missions::table)
+                .filter(missions::id.eq_any(&mission_ids))
+                .execute(conn)?;
+
+            Ok(mission_ids)
+        })
+    }
+}
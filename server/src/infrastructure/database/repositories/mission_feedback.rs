@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use anyhow::{Ok, Result};
+use async_trait::async_trait;
+use diesel::{dsl::exists, insert_into, prelude::*, select};
+
+use crate::{
+    domain::{
+        entities::mission_feedbacks::AddMissionFeedbackEntity,
+        repositories::mission_feedback::MissionFeedbackRepository,
+        value_objects::mission_feedback_model::{BrawlerRatingModel, MissionFeedbackModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{crew_memberships, mission_feedbacks, missions},
+    },
+};
+
+pub struct MissionFeedbackPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionFeedbackPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+const FEEDBACK_SELECT: &str = r#"
+SELECT f.id,
+        f.mission_id,
+        f.rater_id,
+        rater.display_name AS rater_display_name,
+        f.ratee_id,
+        ratee.display_name AS ratee_display_name,
+        f.rating,
+        f.comment,
+        f.created_at
+FROM mission_feedbacks f
+INNER JOIN brawlers rater ON rater.id = f.rater_id
+INNER JOIN brawlers ratee ON ratee.id = f.ratee_id
+"#;
+
+#[async_trait]
+impl MissionFeedbackRepository for MissionFeedbackPostgres {
+    async fn add(&self, add_mission_feedback_entity: AddMissionFeedbackEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(mission_feedbacks::table)
+            .values(add_mission_feedback_entity)
+            .returning(mission_feedbacks::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn has_rated(&self, mission_id: i32, rater_id: i32, ratee_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = select(exists(
+            mission_feedbacks::table
+                .filter(mission_feedbacks::mission_id.eq(mission_id))
+                .filter(mission_feedbacks::rater_id.eq(rater_id))
+                .filter(mission_feedbacks::ratee_id.eq(ratee_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut participants = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .select(crew_memberships::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        // The chief always took part, even if the automatic crew join failed
+        let chief_id = missions::table
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .select(missions::chief_id)
+            .first::<i32>(&mut conn)?;
+
+        if !participants.contains(&chief_id) {
+            participants.push(chief_id);
+        }
+
+        Ok(participants)
+    }
+
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionFeedbackModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            "{} WHERE f.mission_id = $1 ORDER BY f.created_at ASC",
+            FEEDBACK_SELECT
+        );
+
+        let results = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(mission_id)
+            .load::<MissionFeedbackModel>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn get_by_ratee(&self, brawler_id: i32) -> Result<Vec<MissionFeedbackModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            "{} WHERE f.ratee_id = $1 ORDER BY f.created_at DESC",
+            FEEDBACK_SELECT
+        );
+
+        let results = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .load::<MissionFeedbackModel>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn get_rating(&self, brawler_id: i32) -> Result<BrawlerRatingModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = r#"
+SELECT $1 AS brawler_id,
+        COALESCE(AVG(f.rating), 0)::float8 AS rating_average,
+        COUNT(f.id) AS rating_count
+FROM mission_feedbacks f
+WHERE f.ratee_id = $1
+        "#;
+
+        let result = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+            .get_result::<BrawlerRatingModel>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -1,14 +1,20 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
+        entities::{
+            missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+            tags::{AddTagEntity, MissionTagEntity},
+        },
         repositories::mission_management::MissionManagementRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{mission_tags, missions, tags}},
 };
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, delete, dsl::now,
+    dsl::update, insert_into,
+};
 use std::sync::Arc;
 
 pub struct MissionManagementPostgres {
@@ -59,4 +65,64 @@ impl MissionManagementRepository for MissionManagementPostgres {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn set_tags(&self, mission_id: i32, tag_names: Vec<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            delete(mission_tags::table)
+                .filter(mission_tags::mission_id.eq(mission_id))
+                .execute(conn)?;
+
+            if tag_names.is_empty() {
+                return Ok(());
+            }
+
+            let new_tags = tag_names
+                .iter()
+                .map(|name| AddTagEntity { name: name.clone() })
+                .collect::<Vec<_>>();
+
+            insert_into(tags::table)
+                .values(&new_tags)
+                .on_conflict(tags::name)
+                .do_nothing()
+                .execute(conn)?;
+
+            let mission_tag_entities = tags::table
+                .filter(tags::name.eq_any(&tag_names))
+                .select(tags::id)
+                .load::<i32>(conn)?
+                .into_iter()
+                .map(|tag_id| MissionTagEntity { mission_id, tag_id })
+                .collect::<Vec<_>>();
+
+            insert_into(mission_tags::table)
+                .values(&mission_tag_entities)
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn find(&self, mission_id: i32) -> Result<MissionEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = missions::table
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .select(MissionEntity::as_select())
+            .first::<MissionEntity>(&mut conn)?;
+        Ok(result)
+    }
+
+    async fn get_tags(&self, mission_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = mission_tags::table
+            .inner_join(tags::table)
+            .filter(mission_tags::mission_id.eq(mission_id))
+            .select(tags::name)
+            .order(tags::name.asc())
+            .load::<String>(&mut conn)?;
+        Ok(result)
+    }
+}
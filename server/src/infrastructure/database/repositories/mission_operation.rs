@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use anyhow::{Context, Ok, Result};
 use async_trait::async_trait;
+use chrono::Utc;
 use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
 
 use crate::{
@@ -28,6 +29,9 @@ impl MissionOperationPostgres {
     ) -> Result<i32> {
         let db_pool = Arc::clone(&self.db_pool);
         let status_string = status.to_string();
+        // Only a terminal status starts the feedback window
+        let finished_at = matches!(status, MissionStatuses::Completed | MissionStatuses::Failed)
+            .then(|| Utc::now().naive_utc());
         let id = tokio::task::spawn_blocking(move || -> Result<i32> {
             let mut conn = db_pool.get().context("Failed to get DB connection")?;
 
@@ -35,7 +39,10 @@ impl MissionOperationPostgres {
                 .filter(missions::id.eq(mission_id))
                 .filter(missions::chief_id.eq(chief_id))
                 .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_string),))
+                .set((
+                    missions::status.eq(status_string),
+                    missions::finished_at.eq(finished_at),
+                ))
                 .returning(missions::id)
                 .get_result::<i32>(&mut conn)
                 .context("Failed to execute mission update query")
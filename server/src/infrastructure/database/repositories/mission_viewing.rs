@@ -2,17 +2,134 @@ use std::sync::Arc;
 
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::prelude::*;
+use diesel::{
+    pg::Pg,
+    prelude::*,
+    query_builder::{BoxedSqlQuery, SqlQuery},
+    sql_types::{Array, BigInt, Bool, Date, Int4, Int8, Nullable, Text, Varchar},
+};
 
 use crate::{
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::{
-            brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+            brawler_model::BrawlerModel,
+            mission_cursor::MissionCursor,
+            mission_filter::{MissionFilter, MissionSort, SortDirection},
+            mission_model::MissionModel,
         },
     },
     infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
+
+/// Trigram similarity a query needs against a name or tag to count as a typo match.
+const TYPO_SIMILARITY_THRESHOLD: f64 = 0.3;
+
+/// Shared by the listing and count queries; binds are applied by `bind_filter`.
+const MISSION_FILTER_CONDITIONS: &str = r#"
+WHERE m.deleted_at IS NULL
+    AND ($1::varchar[] IS NULL OR m.status = ANY($1))
+    AND ($2::varchar IS NULL OR m.name ILIKE $2)
+    AND ($3::int4 IS NULL OR m.chief_id != $3)
+    AND ($4::varchar IS NULL OR c.slug = LOWER($4) OR LOWER(c.label) = LOWER($4))
+    AND ($5::text IS NULL
+        OR m.search_vector @@ websearch_to_tsquery('simple', $5)
+        OR word_similarity($5, m.name) >= {threshold}
+        OR EXISTS (
+            SELECT 1
+            FROM mission_tags mt
+            INNER JOIN tags t ON t.id = mt.tag_id
+            WHERE mt.mission_id = m.id
+                AND similarity($5, t.name) >= {threshold}
+        ))
+    AND ($6::text[] IS NULL OR $6 <@ ARRAY(
+        SELECT t.name::text
+        FROM mission_tags mt
+        INNER JOIN tags t ON t.id = mt.tag_id
+        WHERE mt.mission_id = m.id
+    ))
+    AND ($7::int4 IS NULL OR m.chief_id = $7)
+    AND ($8::int4 IS NULL OR EXISTS (
+        SELECT 1
+        FROM crew_memberships member
+        WHERE member.mission_id = m.id
+            AND member.brawler_id = $8
+    ))
+    AND ($9::bool IS NULL OR (
+        m.status = 'Open'
+        AND (SELECT COUNT(*) FROM crew_memberships slot WHERE slot.mission_id = m.id) < $10
+    ) = $9)
+    AND ($11::date IS NULL OR m.created_at >= $11)
+    AND ($12::date IS NULL OR m.created_at < $12 + 1)
+"#;
+
+fn filter_conditions() -> String {
+    MISSION_FILTER_CONDITIONS.replace("{threshold}", &TYPO_SIMILARITY_THRESHOLD.to_string())
+}
+
+fn bind_filter<'f>(
+    query: BoxedSqlQuery<'f, Pg, SqlQuery>,
+    mission_filter: &MissionFilter,
+    max_crew_per_mission: i64,
+) -> Result<BoxedSqlQuery<'f, Pg, SqlQuery>> {
+    let statuses_bind: Option<Vec<String>> = mission_filter
+        .status_list()?
+        .map(|statuses| statuses.iter().map(|s| s.to_string()).collect());
+    let name_bind: Option<String> = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
+
+    Ok(query
+        .bind::<Nullable<Array<Varchar>>, _>(statuses_bind)
+        .bind::<Nullable<Varchar>, _>(name_bind)
+        .bind::<Nullable<Int4>, _>(mission_filter.exclude_chief_id)
+        .bind::<Nullable<Varchar>, _>(mission_filter.category.clone())
+        .bind::<Nullable<Text>, _>(mission_filter.search_query())
+        .bind::<Nullable<Array<Text>>, _>(mission_filter.tag_list())
+        .bind::<Nullable<Int4>, _>(mission_filter.chief_id)
+        .bind::<Nullable<Int4>, _>(mission_filter.member_id)
+        .bind::<Nullable<Bool>, _>(mission_filter.has_open_slots)
+        .bind::<Int8, _>(max_crew_per_mission)
+        .bind::<Nullable<Date>, _>(mission_filter.created_from)
+        .bind::<Nullable<Date>, _>(mission_filter.created_to))
+}
+
+/// Expression a listing is ordered by and the SQL type it compares as. Sort keys
+/// come from closed enums, so they are safe to splice into the query.
+fn sort_key(sort: &MissionSort, direction: &SortDirection) -> (&'static str, &'static str) {
+    match (sort, direction) {
+        (MissionSort::Relevance, _) => (
+            "(ts_rank(m.search_vector, websearch_to_tsquery('simple', $5)) + word_similarity($5, m.name))::float8",
+            "float8",
+        ),
+        (MissionSort::CreatedAt, _) => ("m.created_at", "timestamp"),
+        (MissionSort::UpdatedAt, _) => ("m.updated_at", "timestamp"),
+        (MissionSort::Name, _) => ("LOWER(m.name)", "text"),
+        (MissionSort::CrewCount, _) => ("COUNT(cm.brawler_id)", "int8"),
+        // Missions without an end time always go last
+        (MissionSort::EndsAt, SortDirection::Asc) => {
+            ("COALESCE(m.ends_at, 'infinity'::timestamp)", "timestamp")
+        }
+        (MissionSort::EndsAt, SortDirection::Desc) => {
+            ("COALESCE(m.ends_at, '-infinity'::timestamp)", "timestamp")
+        }
+    }
+}
+
+#[derive(QueryableByName)]
+struct MissionRow {
+    #[diesel(embed)]
+    mission: MissionModel,
+    #[diesel(sql_type = Int4)]
+    sort_bucket: i32,
+    #[diesel(sql_type = Text)]
+    cursor_key: String,
+}
+
+#[derive(QueryableByName)]
+struct TotalCount {
+    #[diesel(sql_type = BigInt)]
+    total: i64,
+}
+
 pub struct MissionViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -33,8 +150,7 @@ impl MissionViewingRepository for MissionViewingPostgres {
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = i64::try_from(value)?;
-        Ok(count)
+        Ok(value)
     }
 
     async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
@@ -50,13 +166,23 @@ SELECT m.id,
         COUNT(cm.brawler_id) AS crew_count,
         m.created_at,
         m.updated_at,
-        m.category
+        m.category,
+        c.label AS category_label,
+        COALESCE((
+            SELECT ARRAY_AGG(t.name::text ORDER BY t.name)
+            FROM mission_tags mt
+            INNER JOIN tags t ON t.id = mt.tag_id
+            WHERE mt.mission_id = m.id
+        ), '{}') AS tags,
+        m.ends_at,
+        m.finished_at
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
+INNER JOIN categories c ON c.slug = m.category
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
 WHERE m.deleted_at IS NULL
     AND m.id = $1
-GROUP BY m.id, b.display_name, m.name, m.description, m.status, m.chief_id, m.created_at, m.updated_at, m.category
+GROUP BY m.id, b.display_name, m.name, m.description, m.status, m.chief_id, m.created_at, m.updated_at, m.category, c.label
 LIMIT 1
         "#;
 
@@ -67,65 +193,136 @@ LIMIT 1
         Ok(result)
     }
 
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        use diesel::sql_types::{Nullable, Varchar};
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        cursor: Option<MissionCursor>,
+        max_crew_per_mission: i64,
+    ) -> Result<(Vec<MissionModel>, Option<MissionCursor>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (sort, direction) = mission_filter.resolved_sort();
+        let (key_expression, key_type) = sort_key(&sort, &direction);
+        let (order, comparison) = match direction {
+            SortDirection::Asc => ("ASC", ">"),
+            SortDirection::Desc => ("DESC", "<"),
+        };
+
+        // Finished missions sit in a later bucket so they always trail active ones
+        let sql = format!(
+            r#"
+SELECT listing.*, listing.sort_key::text AS cursor_key
+FROM (
+    SELECT m.id,
+            m.name,
+            m.description,
+            m.status,
+            m.chief_id,
+            COALESCE(b.display_name, '') AS chief_display_name,
+            COUNT(cm.brawler_id) AS crew_count,
+            m.created_at,
+            m.updated_at,
+            m.category,
+            c.label AS category_label,
+            COALESCE((
+                SELECT ARRAY_AGG(t.name::text ORDER BY t.name)
+                FROM mission_tags mt
+                INNER JOIN tags t ON t.id = mt.tag_id
+                WHERE mt.mission_id = m.id
+            ), '{{}}') AS tags,
+            m.ends_at,
+            m.finished_at,
+            CASE WHEN m.status IN ('Completed', 'Failed') THEN 1 ELSE 0 END AS sort_bucket,
+            {key_expression} AS sort_key
+    FROM missions m
+    LEFT JOIN brawlers b ON b.id = m.chief_id
+    INNER JOIN categories c ON c.slug = m.category
+    LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
+    {conditions}
+    GROUP BY m.id, b.display_name, m.name, m.description, m.status, m.chief_id, m.created_at, m.updated_at, m.category, c.label
+) listing
+WHERE $15::int4 IS NULL
+    OR listing.sort_bucket > $15
+    OR (listing.sort_bucket = $15 AND (listing.sort_key, listing.id) {comparison} ($16::{key_type}, $17))
+ORDER BY listing.sort_bucket ASC, listing.sort_key {order}, listing.id {order}
+LIMIT $13 OFFSET $14
+        "#,
+            conditions = filter_conditions(),
+        );
+
+        let limit = mission_filter.limit();
+        // Keyset requests continue after the cursor instead of skipping rows
+        let offset = match cursor {
+            Some(_) => 0,
+            None => (mission_filter.page() - 1) * limit,
+        };
 
+        let mut rows = bind_filter(
+            diesel::sql_query(sql).into_boxed(),
+            mission_filter,
+            max_crew_per_mission,
+        )?
+        // One extra row tells us whether another page exists
+        .bind::<Int8, _>(limit + 1)
+        .bind::<Int8, _>(offset)
+        .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|cursor| cursor.bucket))
+        .bind::<Nullable<Text>, _>(cursor.as_ref().map(|cursor| cursor.key.clone()))
+        .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|cursor| cursor.id))
+        .load::<MissionRow>(&mut conn)?;
+
+        let has_more = rows.len() as i64 > limit;
+        rows.truncate(limit as usize);
+
+        let next_cursor = match rows.last() {
+            Some(last) if has_more => Some(MissionCursor {
+                sort,
+                direction,
+                bucket: last.sort_bucket,
+                key: last.cursor_key.clone(),
+                id: last.mission.id,
+            }),
+            _ => None,
+        };
+
+        Ok((rows.into_iter().map(|row| row.mission).collect(), next_cursor))
+    }
+
+    async fn count_all(
+        &self,
+        mission_filter: &MissionFilter,
+        max_crew_per_mission: i64,
+    ) -> Result<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let sql = r#"
-SELECT m.id,
-        m.name,
-        m.description,
-        m.status,
-        m.chief_id,
-        COALESCE(b.display_name, '') AS chief_display_name,
-        COUNT(cm.brawler_id) AS crew_count,
-        m.created_at,
-        m.updated_at,
-        m.category
+        let sql = format!(
+            r#"
+SELECT COUNT(*) AS total
 FROM missions m
-LEFT JOIN brawlers b ON b.id = m.chief_id
-LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
-WHERE m.deleted_at IS NULL
-    AND ($1::varchar IS NULL OR m.status = $1)
-    AND ($2::varchar IS NULL OR m.name ILIKE $2)
-    AND ($3::int4 IS NULL OR m.chief_id != $3)
-    AND ($4::varchar IS NULL OR m.category = $4)
-GROUP BY m.id, b.display_name, m.name, m.description, m.status, m.chief_id, m.created_at, m.updated_at, m.category
-ORDER BY 
-    CASE WHEN m.status IN ('Completed', 'Failed') THEN 1 ELSE 0 END ASC,
-    m.created_at DESC
-LIMIT $5 OFFSET $6
-        "#;
+INNER JOIN categories c ON c.slug = m.category
+{conditions}
+        "#,
+            conditions = filter_conditions(),
+        );
 
-        // Prepare optional bind values
-        let status_bind: Option<String> = mission_filter.status.as_ref().map(|s| s.to_string());
-        let name_bind: Option<String> = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
-        let exclude_chief_id_bind: Option<i32> = mission_filter.exclude_chief_id;
-        let category_bind: Option<String> = mission_filter.category.clone();
-        
-        let limit = mission_filter.limit.unwrap_or(20);
-        let page = mission_filter.page.unwrap_or(1);
-        let offset = (page - 1) * limit;
-
-        let rows = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Varchar>, _>(status_bind)
-            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Varchar>, _>(name_bind)
-            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Int4>, _>(exclude_chief_id_bind)
-            .bind::<diesel::sql_types::Nullable<diesel::sql_types::Varchar>, _>(category_bind)
-            .bind::<diesel::sql_types::Int8, _>(limit)
-            .bind::<diesel::sql_types::Int8, _>(offset)
-            .load::<MissionModel>(&mut conn)?;
-
-        Ok(rows)
+        let result = bind_filter(
+            diesel::sql_query(sql).into_boxed(),
+            mission_filter,
+            max_crew_per_mission,
+        )?
+        .get_result::<TotalCount>(&mut conn)?;
+
+        Ok(result.total)
     }
 
     async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
         let sql = r#"
-            SELECT b.display_name,
+            SELECT b.id AS brawler_id,
+                    b.display_name,
                     COALESCE(b.avatar_url, '') AS avatar_url,
                     COALESCE(s.success_count, 0) AS mission_success_count,
-                    COALESCE(j.joined_count, 0) AS mission_joined_count
+                    COALESCE(j.joined_count, 0) AS mission_joined_count,
+                    COALESCE(r.rating_average, 0)::float8 AS rating_average,
+                    COALESCE(r.rating_count, 0) AS rating_count
             FROM crew_memberships cm
             INNER JOIN brawlers b ON b.id = cm.brawler_id
             LEFT JOIN (
@@ -140,6 +337,11 @@ LIMIT $5 OFFSET $6
                 FROM crew_memberships cm3
                 GROUP BY cm3.brawler_id
             ) j ON j.brawler_id = b.id
+            LEFT JOIN (
+                SELECT f.ratee_id, AVG(f.rating) AS rating_average, COUNT(*) AS rating_count
+                FROM mission_feedbacks f
+                GROUP BY f.ratee_id
+            ) r ON r.ratee_id = b.id
             WHERE cm.mission_id = $1
         "#;
 
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{Connection, delete, dsl::now, prelude::*, update};
+
+use crate::{
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        repositories::moderation::ModerationRepository,
+        value_objects::{mission_statuses::MissionStatuses, roles::Role},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, mission_chats, missions},
+    },
+};
+
+pub struct ModerationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ModerationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ModerationRepository for ModerationPostgres {
+    async fn find_brawler(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .find(brawler_id)
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn ban(&self, brawler_id: i32, reason: Option<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::banned_at.eq(now),
+                brawlers::ban_reason.eq(reason),
+                brawlers::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn unban(&self, brawler_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::banned_at.eq(None::<chrono::NaiveDateTime>),
+                brawlers::ban_reason.eq(None::<String>),
+                brawlers::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn set_role(&self, brawler_id: i32, role: Role) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set((
+                brawlers::role.eq(role.to_string()),
+                brawlers::updated_at.eq(now),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn force_fail_mission(&self, mission_id: i32) -> Result<String> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let previous_status = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select(missions::status)
+                .for_update()
+                .first::<String>(conn)?;
+
+            if previous_status != MissionStatuses::Open.to_string()
+                && previous_status != MissionStatuses::InProgress.to_string()
+            {
+                return Err(anyhow::anyhow!("Mission is not open or in progress"));
+            }
+
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set((
+                    missions::status.eq(MissionStatuses::Failed.to_string()),
+                    missions::updated_at.eq(now),
+                    missions::finished_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            Ok(previous_status)
+        })
+    }
+
+    async fn delete_mission(&self, mission_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let updated = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .set(missions::deleted_at.eq(now))
+                .execute(conn)?;
+
+            if updated == 0 {
+                return Err(anyhow::anyhow!("Mission not found"));
+            }
+
+            delete(mission_chats::table)
+                .filter(mission_chats::mission_id.eq(mission_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn delete_chat_message(&self, message_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mission_id = delete(mission_chats::table)
+            .filter(mission_chats::id.eq(message_id))
+            .returning(mission_chats::mission_id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(mission_id)
+    }
+}
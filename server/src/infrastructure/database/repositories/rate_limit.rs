@@ -0,0 +1,84 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use diesel::{dsl::{delete, insert_into, update}, prelude::*};
+
+use crate::{
+    domain::{
+        entities::rate_limit_buckets::RateLimitBucketEntity,
+        repositories::rate_limit::RateLimitRepository,
+        value_objects::rate_limit::{RateLimitDecision, RateLimitPolicy},
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::rate_limit_buckets},
+};
+
+/// Buckets untouched for this long are full again and can be dropped.
+const STALE_BUCKET_AGE: TimeDelta = TimeDelta::days(1);
+
+/// Stale buckets are swept once every this many requests.
+const SWEEP_EVERY: u64 = 1_000;
+
+/// Buckets shared by every server instance through the database.
+pub struct RateLimitPostgres {
+    db_pool: Arc<PgPoolSquad>,
+    requests: AtomicU64,
+}
+
+impl RateLimitPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self {
+            db_pool,
+            requests: AtomicU64::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RateLimitPostgres {
+    async fn take(&self, key: String, policy: RateLimitPolicy) -> Result<RateLimitDecision> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+
+        if self.requests.fetch_add(1, Ordering::Relaxed).is_multiple_of(SWEEP_EVERY) {
+            delete(rate_limit_buckets::table)
+                .filter(rate_limit_buckets::updated_at.lt(now - STALE_BUCKET_AGE))
+                .execute(&mut conn)?;
+        }
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_into(rate_limit_buckets::table)
+                .values(RateLimitBucketEntity {
+                    key: key.clone(),
+                    tokens: f64::from(policy.capacity),
+                    updated_at: now,
+                })
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            let bucket = rate_limit_buckets::table
+                .find(&key)
+                .select(RateLimitBucketEntity::as_select())
+                .for_update()
+                .first::<RateLimitBucketEntity>(conn)?;
+
+            // Instance clocks can drift apart, so never move a bucket back in time.
+            let updated_at = now.max(bucket.updated_at);
+            let elapsed = (updated_at - bucket.updated_at).to_std().unwrap_or_default();
+            let (tokens, decision) = policy.take(bucket.tokens, elapsed);
+
+            update(rate_limit_buckets::table.find(&key))
+                .set((
+                    rate_limit_buckets::tokens.eq(tokens),
+                    rate_limit_buckets::updated_at.eq(updated_at),
+                ))
+                .execute(conn)?;
+
+            Ok(decision)
+        })
+    }
+}
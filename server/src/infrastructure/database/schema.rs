@@ -1,5 +1,32 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    audit_events (id) {
+        id -> Int4,
+        actor_id -> Int4,
+        #[max_length = 50]
+        action -> Varchar,
+        #[max_length = 50]
+        target_type -> Varchar,
+        target_id -> Int4,
+        details -> Jsonb,
+        created_at -> Timestamp,
+        before -> Nullable<Jsonb>,
+        after -> Nullable<Jsonb>,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    brawler_achievements (brawler_id, achievement_code) {
+        brawler_id -> Int4,
+        #[max_length = 50]
+        achievement_code -> Varchar,
+        awarded_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -15,6 +42,25 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        #[max_length = 20]
+        role -> Varchar,
+        banned_at -> Nullable<Timestamp>,
+        #[max_length = 280]
+        ban_reason -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    categories (slug) {
+        #[max_length = 50]
+        slug -> Varchar,
+        #[max_length = 50]
+        label -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 50]
+        icon -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        retired_at -> Nullable<Timestamp>,
     }
 }
 
@@ -36,6 +82,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mission_feedbacks (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        rating -> Int4,
+        #[max_length = 280]
+        comment -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     missions (id) {
         id -> Int4,
@@ -50,13 +109,57 @@ diesel::table! {
         deleted_at -> Nullable<Timestamp>,
         #[max_length = 50]
         category -> Varchar,
+        ends_at -> Nullable<Timestamp>,
+        finished_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    mission_tags (mission_id, tag_id) {
+        mission_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
+diesel::table! {
+    rate_limit_buckets (key) {
+        #[max_length = 255]
+        key -> Varchar,
+        tokens -> Float8,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 30]
+        name -> Varchar,
     }
 }
 
+diesel::joinable!(audit_events -> brawlers (actor_id));
+diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
 diesel::joinable!(mission_chats -> brawlers (brawler_id));
 diesel::joinable!(mission_chats -> missions (mission_id));
+diesel::joinable!(mission_feedbacks -> missions (mission_id));
+diesel::joinable!(mission_tags -> missions (mission_id));
+diesel::joinable!(mission_tags -> tags (tag_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> categories (category));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, mission_chats, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    audit_events,
+    brawler_achievements,
+    brawlers,
+    categories,
+    crew_memberships,
+    mission_chats,
+    mission_feedbacks,
+    mission_tags,
+    missions,
+    rate_limit_buckets,
+    tags,
+);
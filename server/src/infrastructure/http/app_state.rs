@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use crate::{
+    application::use_cases::{
+        achievement::AchievementUseCase, audit::AuditUseCase,
+        authentication::AuthenticationUseCase, brawlers::BrawlersUseCase,
+        category::CategoryUseCase, crew_operation::CrewOperationUseCase, health::HealthUseCase,
+        mission_chat::MissionChatUseCase, mission_feedback::MissionFeedbackUseCase,
+        mission_management::MissionManagementUseCase, mission_operation::MissionOperationUseCase,
+        mission_viewing::MissionViewingUseCase, moderation::ModerationUseCase,
+        system::SystemUseCase,
+    },
+    config::config_model::{DotEnvyConfig, RateLimitStore},
+    domain::repositories::{
+        achievement::AchievementRepository, audit::AuditRepository, brawlers::BrawlerRepository,
+        category::CategoryRepository, crew_operation::CrewOperationRepository,
+        health::HealthRepository, image_storage::ImageStorage, maintenance::MaintenanceRepository, mission_chat::MissionChatRepository,
+        mission_feedback::MissionFeedbackRepository,
+        mission_management::MissionManagementRepository,
+        mission_metrics::MissionMetrics,
+        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+        moderation::ModerationRepository, notification_publisher::NotificationPublisher,
+        rate_limit::RateLimitRepository,
+        system::SystemRepository,
+    },
+    infrastructure::{
+        access_cache::AccessCache,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievement::AchievementPostgres, audit::AuditPostgres, brawlers::BrawlerPostgres,
+                category::CategoryPostgres, crew_operation::CrewOperationPostgres,
+                health::HealthPostgres, maintenance::MaintenancePostgres, mission_chat::MissionChatPostgres, mission_feedback::MissionFeedbackPostgres,
+                mission_management::MissionManagementPostgres,
+                mission_operation::MissionOperationPostgres,
+                mission_viewing::MissionViewingPostgres, moderation::ModerationPostgres,
+                rate_limit::RateLimitPostgres, system::SystemPostgres,
+            },
+        },
+        http::middleware::rate_limit::RateLimiter,
+        login_throttle::LoginThrottle,
+        metrics::PrometheusMissionMetrics,
+        notifications::NotificationHub,
+        rate_limit::RateLimitMemory,
+        shutdown::Shutdown,
+    },
+};
+
+pub type DynAchievementRepository = dyn AchievementRepository + Send + Sync;
+pub type DynAuditRepository = dyn AuditRepository + Send + Sync;
+pub type DynBrawlerRepository = dyn BrawlerRepository + Send + Sync;
+pub type DynCategoryRepository = dyn CategoryRepository + Send + Sync;
+pub type DynCrewOperationRepository = dyn CrewOperationRepository + Send + Sync;
+pub type DynHealthRepository = dyn HealthRepository + Send + Sync;
+pub type DynMaintenanceRepository = dyn MaintenanceRepository + Send + Sync;
+pub type DynMissionChatRepository = dyn MissionChatRepository + Send + Sync;
+pub type DynMissionFeedbackRepository = dyn MissionFeedbackRepository + Send + Sync;
+pub type DynMissionManagementRepository = dyn MissionManagementRepository + Send + Sync;
+pub type DynMissionOperationRepository = dyn MissionOperationRepository + Send + Sync;
+pub type DynMissionViewingRepository = dyn MissionViewingRepository + Send + Sync;
+pub type DynModerationRepository = dyn ModerationRepository + Send + Sync;
+pub type DynRateLimitRepository = dyn RateLimitRepository + Send + Sync;
+pub type DynSystemRepository = dyn SystemRepository + Send + Sync;
+pub type DynImageStorage = dyn ImageStorage + Send + Sync;
+pub type DynNotificationPublisher = dyn NotificationPublisher + Send + Sync;
+pub type DynMissionMetrics = dyn MissionMetrics + Send + Sync;
+
+pub type AppAchievementUseCase =
+    AchievementUseCase<DynAchievementRepository, DynNotificationPublisher>;
+pub type AppAuditUseCase = AuditUseCase<DynAuditRepository>;
+pub type AppAuthenticationUseCase = AuthenticationUseCase<DynBrawlerRepository>;
+pub type AppBrawlersUseCase = BrawlersUseCase<
+    DynBrawlerRepository,
+    DynImageStorage,
+    DynAchievementRepository,
+    DynNotificationPublisher,
+>;
+pub type AppCategoryUseCase = CategoryUseCase<DynCategoryRepository>;
+pub type AppCrewOperationUseCase = CrewOperationUseCase<
+    DynCrewOperationRepository,
+    DynMissionViewingRepository,
+    DynAchievementRepository,
+    DynAuditRepository,
+    DynNotificationPublisher,
+    DynMissionMetrics,
+>;
+pub type AppHealthUseCase = HealthUseCase<DynHealthRepository, DynImageStorage>;
+pub type AppMissionChatUseCase = MissionChatUseCase<DynMissionChatRepository>;
+pub type AppMissionFeedbackUseCase =
+    MissionFeedbackUseCase<DynMissionFeedbackRepository, DynMissionViewingRepository>;
+pub type AppMissionManagementUseCase = MissionManagementUseCase<
+    DynMissionManagementRepository,
+    DynMissionChatRepository,
+    DynCrewOperationRepository,
+    DynAchievementRepository,
+    DynCategoryRepository,
+    DynAuditRepository,
+    DynNotificationPublisher,
+    DynMissionMetrics,
+>;
+pub type AppMissionOperationUseCase = MissionOperationUseCase<
+    DynMissionOperationRepository,
+    DynMissionViewingRepository,
+    DynMissionChatRepository,
+    DynAchievementRepository,
+    DynAuditRepository,
+    DynNotificationPublisher,
+    DynMissionMetrics,
+>;
+pub type AppMissionViewingUseCase = MissionViewingUseCase<DynMissionViewingRepository>;
+pub type AppModerationUseCase =
+    ModerationUseCase<DynModerationRepository, DynAuditRepository, DynMissionMetrics>;
+pub type AppSystemUseCase = SystemUseCase<DynSystemRepository>;
+
+/// Every repository the app talks to. `Repositories::new` wires the Postgres
+/// implementations; tests fill the fields with `Mock*` repositories instead.
+pub struct Repositories {
+    pub achievement: Arc<DynAchievementRepository>,
+    pub audit: Arc<DynAuditRepository>,
+    pub brawler: Arc<DynBrawlerRepository>,
+    pub category: Arc<DynCategoryRepository>,
+    pub crew_operation: Arc<DynCrewOperationRepository>,
+    pub health: Arc<DynHealthRepository>,
+    pub maintenance: Arc<DynMaintenanceRepository>,
+    pub mission_chat: Arc<DynMissionChatRepository>,
+    pub mission_feedback: Arc<DynMissionFeedbackRepository>,
+    pub mission_management: Arc<DynMissionManagementRepository>,
+    pub mission_operation: Arc<DynMissionOperationRepository>,
+    pub mission_viewing: Arc<DynMissionViewingRepository>,
+    pub moderation: Arc<DynModerationRepository>,
+    pub rate_limit: Arc<DynRateLimitRepository>,
+    pub system: Arc<DynSystemRepository>,
+}
+
+impl Repositories {
+    pub fn new(db_pool: &Arc<PgPoolSquad>, config: &DotEnvyConfig) -> Self {
+        let rate_limit: Arc<DynRateLimitRepository> = match config.rate_limit.store {
+            RateLimitStore::Memory => Arc::new(RateLimitMemory::new()),
+            RateLimitStore::Postgres => Arc::new(RateLimitPostgres::new(Arc::clone(db_pool))),
+        };
+
+        Self {
+            achievement: Arc::new(AchievementPostgres::new(Arc::clone(db_pool))),
+            audit: Arc::new(AuditPostgres::new(Arc::clone(db_pool))),
+            brawler: Arc::new(BrawlerPostgres::new(Arc::clone(db_pool))),
+            category: Arc::new(CategoryPostgres::new(Arc::clone(db_pool))),
+            crew_operation: Arc::new(CrewOperationPostgres::new(Arc::clone(db_pool))),
+            health: Arc::new(HealthPostgres::new(Arc::clone(db_pool))),
+            maintenance: Arc::new(MaintenancePostgres::new(Arc::clone(db_pool))),
+            mission_chat: Arc::new(MissionChatPostgres::new(Arc::clone(db_pool))),
+            mission_feedback: Arc::new(MissionFeedbackPostgres::new(Arc::clone(db_pool))),
+            mission_management: Arc::new(MissionManagementPostgres::new(Arc::clone(db_pool))),
+            mission_operation: Arc::new(MissionOperationPostgres::new(Arc::clone(db_pool))),
+            mission_viewing: Arc::new(MissionViewingPostgres::new(Arc::clone(db_pool))),
+            moderation: Arc::new(ModerationPostgres::new(Arc::clone(db_pool))),
+            rate_limit,
+            system: Arc::new(SystemPostgres::new(Arc::clone(db_pool))),
+        }
+    }
+}
+
+/// Everything the HTTP layer needs, built once in `main` and handed to every
+/// router. Use cases are shared, so there is a single notification hub, login
+/// throttle and audit trail per process.
+pub struct AppState {
+    pub config: Arc<DotEnvyConfig>,
+    pub db_pool: Arc<PgPoolSquad>,
+    pub image_storage: Arc<DynImageStorage>,
+    pub notification_hub: Arc<DynNotificationPublisher>,
+    pub login_throttle: Arc<LoginThrottle>,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub shutdown: Arc<Shutdown>,
+    pub achievement_use_case: Arc<AppAchievementUseCase>,
+    pub audit_use_case: Arc<AppAuditUseCase>,
+    pub authentication_use_case: Arc<AppAuthenticationUseCase>,
+    pub brawlers_use_case: Arc<AppBrawlersUseCase>,
+    pub category_use_case: Arc<AppCategoryUseCase>,
+    pub crew_operation_use_case: Arc<AppCrewOperationUseCase>,
+    pub health_use_case: Arc<AppHealthUseCase>,
+    pub mission_chat_use_case: Arc<AppMissionChatUseCase>,
+    pub mission_feedback_use_case: Arc<AppMissionFeedbackUseCase>,
+    pub mission_management_use_case: Arc<AppMissionManagementUseCase>,
+    pub mission_operation_use_case: Arc<AppMissionOperationUseCase>,
+    pub mission_viewing_use_case: Arc<AppMissionViewingUseCase>,
+    pub moderation_use_case: Arc<AppModerationUseCase>,
+    pub system_use_case: Arc<AppSystemUseCase>,
+}
+
+impl AppState {
+    pub fn new(
+        config: Arc<DotEnvyConfig>,
+        db_pool: Arc<PgPoolSquad>,
+        repositories: Repositories,
+        image_storage: Arc<DynImageStorage>,
+    ) -> Self {
+        let notification_hub: Arc<DynNotificationPublisher> = Arc::new(NotificationHub::new());
+        let mission_metrics: Arc<DynMissionMetrics> = Arc::new(PrometheusMissionMetrics);
+        let login_throttle = Arc::new(LoginThrottle::new());
+        let access_cache = Arc::new(AccessCache::new());
+        let rate_limiter = Arc::new(RateLimiter::new(
+            Arc::clone(&repositories.rate_limit),
+            config.rate_limit.clone(),
+            config.jwt.secret.clone(),
+        ));
+        let max_crew_per_mission = config.missions.max_crew_per_mission;
+
+        let achievement_use_case = Arc::new(AchievementUseCase::new(
+            Arc::clone(&repositories.achievement),
+            Arc::clone(&notification_hub),
+        ));
+        let audit_use_case = Arc::new(AuditUseCase::new(Arc::clone(&repositories.audit)));
+
+        let authentication_use_case = Arc::new(AuthenticationUseCase::new(
+            Arc::clone(&repositories.brawler),
+            Arc::clone(&login_throttle),
+            Arc::clone(&access_cache),
+            config.jwt.clone(),
+        ));
+        let brawlers_use_case = Arc::new(BrawlersUseCase::new(
+            Arc::clone(&repositories.brawler),
+            Arc::clone(&image_storage),
+            Arc::clone(&achievement_use_case),
+            config.jwt.clone(),
+        ));
+        let category_use_case = Arc::new(CategoryUseCase::new(Arc::clone(&repositories.category)));
+        let crew_operation_use_case = Arc::new(CrewOperationUseCase::new(
+            Arc::clone(&repositories.crew_operation),
+            Arc::clone(&repositories.mission_viewing),
+            Arc::clone(&achievement_use_case),
+            Arc::clone(&audit_use_case),
+            Arc::clone(&mission_metrics),
+            max_crew_per_mission,
+        ));
+        let health_use_case = Arc::new(HealthUseCase::new(
+            Arc::clone(&repositories.health),
+            Arc::clone(&image_storage),
+        ));
+        let mission_chat_use_case =
+            Arc::new(MissionChatUseCase::new(Arc::clone(&repositories.mission_chat)));
+        let mission_feedback_use_case = Arc::new(MissionFeedbackUseCase::new(
+            Arc::clone(&repositories.mission_feedback),
+            Arc::clone(&repositories.mission_viewing),
+        ));
+        let mission_management_use_case = Arc::new(MissionManagementUseCase::new(
+            Arc::clone(&repositories.mission_management),
+            Arc::clone(&repositories.mission_chat),
+            Arc::clone(&repositories.crew_operation),
+            Arc::clone(&achievement_use_case),
+            Arc::clone(&repositories.category),
+            Arc::clone(&audit_use_case),
+            Arc::clone(&mission_metrics),
+        ));
+        let mission_operation_use_case = Arc::new(MissionOperationUseCase::new(
+            Arc::clone(&repositories.mission_operation),
+            Arc::clone(&repositories.mission_viewing),
+            Arc::clone(&repositories.mission_chat),
+            Arc::clone(&achievement_use_case),
+            Arc::clone(&audit_use_case),
+            Arc::clone(&mission_metrics),
+            max_crew_per_mission,
+        ));
+        let mission_viewing_use_case = Arc::new(MissionViewingUseCase::new(
+            Arc::clone(&repositories.mission_viewing),
+            max_crew_per_mission,
+        ));
+        let moderation_use_case = Arc::new(ModerationUseCase::new(
+            Arc::clone(&repositories.moderation),
+            Arc::clone(&audit_use_case),
+            mission_metrics,
+            access_cache,
+        ));
+        let system_use_case = Arc::new(SystemUseCase::new(Arc::clone(&repositories.system)));
+
+        Self {
+            config,
+            db_pool,
+            image_storage,
+            notification_hub,
+            login_throttle,
+            rate_limiter,
+            shutdown: Arc::new(Shutdown::new()),
+            achievement_use_case,
+            audit_use_case,
+            authentication_use_case,
+            brawlers_use_case,
+            category_use_case,
+            crew_operation_use_case,
+            health_use_case,
+            mission_chat_use_case,
+            mission_feedback_use_case,
+            mission_management_use_case,
+            mission_operation_use_case,
+            mission_viewing_use_case,
+            moderation_use_case,
+            system_use_case,
+        }
+    }
+}
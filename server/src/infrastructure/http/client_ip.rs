@@ -0,0 +1,58 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{Extensions, HeaderMap, request::Parts},
+};
+
+use crate::config::config_model::DotEnvyConfig;
+
+/// Address of the client that sent the request.
+///
+/// Behind a reverse proxy every connection comes from the proxy, so with
+/// `TRUST_PROXY_HEADERS=true` the last `X-Forwarded-For` hop is used instead:
+/// that is the address the proxy itself saw, unlike earlier hops which the
+/// client can forge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+impl ClientIp {
+    pub fn resolve(headers: &HeaderMap, extensions: &Extensions) -> Self {
+        let trust_proxy_headers = extensions
+            .get::<Arc<DotEnvyConfig>>()
+            .is_some_and(|config| config.server.trust_proxy_headers);
+        if trust_proxy_headers {
+            let forwarded = headers
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .filter_map(|hop| hop.trim().parse::<IpAddr>().ok())
+                .next_back();
+            if let Some(ip) = forwarded {
+                return ClientIp(ip);
+            }
+        }
+
+        let ip = extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip())
+            .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+
+        ClientIp(ip)
+    }
+}
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(ClientIp::resolve(&parts.headers, &parts.extensions))
+    }
+}
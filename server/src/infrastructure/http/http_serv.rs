@@ -2,71 +2,139 @@ use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::{Ok, Result};
 use axum::{
-    Router,
+    Extension, Router,
+    middleware,
     http::{
-        Method, StatusCode,
-        header::{AUTHORIZATION, CONTENT_TYPE},
+        HeaderName, StatusCode,
+        header::{AUTHORIZATION, CONTENT_TYPE, LINK, RETRY_AFTER},
     },
 };
-use tokio::net::TcpListener;
+use tokio::{net::TcpListener, task::JoinHandle};
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowOrigin, Any, CorsLayer},
     limit::RequestBodyLimitLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     services::{ServeDir, ServeFile},
-    trace::TraceLayer,
+    trace::{DefaultOnResponse, TraceLayer},
 };
-use tracing::info;
+use tracing::{Level, error, info};
 
 use crate::{
-    config::config_model::DotEnvyConfig,
+    config::config_model::Cors,
     infrastructure::{
-        database::postgresql_connection::PgPoolSquad,
-        http::routers::{self},
+        http::{
+            app_state::AppState,
+            middleware::{
+                deprecation::{self, DEPRECATION, SUNSET},
+                metrics::track_http,
+                rate_limit::{
+                    RATE_LIMIT_LIMIT, RATE_LIMIT_POLICY, RATE_LIMIT_REMAINING, RATE_LIMIT_RESET,
+                    rate_limit,
+                },
+                security_headers::security_headers,
+                request_id::{request_context, request_id_in_errors},
+                request_span::{make_request_span, record_mission_id},
+            },
+            routers::{self},
+        },
+        metrics,
+        shutdown::Shutdown,
     },
 };
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_v1(state: &AppState) -> Router {
     Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
-        // ... (rest of api_serve)
-        .nest(
-            "/view",
-            routers::missions_viewing::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/mission",
-            routers::missions_operations::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/crew",
-            routers::crew_operations::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/mission-management",
-            routers::missions_management::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/mission-chats",
-            routers::mission_chats::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
-        )
-        .nest(
-            "/system",
-            routers::system::routes(crate::infrastructure::database::repositories::system::SystemPostgres::new(Arc::clone(&db_pool))),
-        )
-        .nest("/util", routers::default_routers::routes())
+        .merge(routers::authentication::v1_routes(state))
+        .merge(routers::brawlers::v1_routes(state))
+        .merge(routers::categories::v1_routes(state))
+        .merge(routers::missions_viewing::v1_routes(state))
+        .merge(routers::missions_management::v1_routes(state))
+        .merge(routers::missions_operations::v1_routes(state))
+        .merge(routers::crew_operations::v1_routes(state))
+        .merge(routers::mission_chats::v1_routes(state))
+        .merge(routers::mission_feedbacks::v1_routes(state))
+        .merge(routers::achievements::v1_routes(state))
+        .merge(routers::admin::v1_routes(state))
+        .merge(routers::system::v1_routes(state))
+}
+
+/// The unversioned routes the deployed client still calls, kept as aliases of
+/// `/api/v1` until their sunset. Only routes that predate `/api/v1` belong here;
+/// everything added since is served from `/api/v1` alone.
+fn legacy_api(state: &AppState) -> Router {
+    Router::new()
+        .nest("/brawler", routers::brawlers::routes(state))
+        .nest("/view", routers::missions_viewing::routes(state))
+        .nest("/mission", routers::missions_operations::routes(state))
+        .nest("/crew", routers::crew_operations::routes(state))
+        .nest("/mission-management", routers::missions_management::routes(state))
+        .nest("/mission-chats", routers::mission_chats::routes(state))
+        .nest("/authentication", routers::authentication::routes(state))
+        .nest("/system", routers::system::routes(state))
+        .nest("/util", routers::default_routers::routes(state))
+        .layer(middleware::from_fn(deprecation::legacy_api))
+}
+
+fn api_serve(state: &AppState) -> Router {
+    Router::new()
+        .nest("/v1", api_v1(state))
+        .merge(legacy_api(state))
+        .merge(routers::api_docs::routes(state))
         .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
 }
 
-pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+const TRACEPARENT: HeaderName = HeaderName::from_static("traceparent");
+const TRACESTATE: HeaderName = HeaderName::from_static("tracestate");
+
+fn cors_layer(cors: &Cors) -> CorsLayer {
+    let allow_origin = match &cors.allowed_origins {
+        Some(origins) => AllowOrigin::list(origins.clone()),
+        None => AllowOrigin::from(Any),
+    };
+
+    CorsLayer::new()
+        .allow_methods(cors.allowed_methods.clone())
+        .allow_origin(allow_origin)
+        .allow_headers([
+            AUTHORIZATION,
+            CONTENT_TYPE,
+            X_REQUEST_ID,
+            TRACEPARENT,
+            TRACESTATE,
+        ])
+        .allow_credentials(cors.allow_credentials)
+        .expose_headers([
+            X_REQUEST_ID,
+            DEPRECATION,
+            SUNSET,
+            LINK,
+            RETRY_AFTER,
+            RATE_LIMIT_LIMIT,
+            RATE_LIMIT_REMAINING,
+            RATE_LIMIT_RESET,
+            RATE_LIMIT_POLICY,
+        ])
+}
+
+/// The whole application, API and static client, with every middleware layer.
+/// Tests can build it from an `AppState` wired with mock repositories.
+pub fn app(state: Arc<AppState>) -> Result<Router> {
+    let config = Arc::clone(&state.config);
     let static_service = ServeDir::new("statics")
         .fallback(ServeFile::new("statics/index.html"));
 
-    let app = Router::new()
-        .nest("/api", api_serve(db_pool))
+    let mut app = Router::new()
+        .nest("/api", api_serve(&state))
+        .merge(routers::health::routes(&state));
+    if config.metrics.enabled {
+        metrics::handle();
+        if config.metrics.port.is_none() {
+            app = app.merge(routers::metrics::routes(&state));
+        }
+    }
+
+    let app = app
         .fallback_service(static_service)
         .layer(tower_http::timeout::TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
@@ -75,35 +143,103 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
         .layer(RequestBodyLimitLayer::new(
             (config.server.body_limit * 1024 * 1024).try_into()?,
         ))
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state.rate_limiter),
+            rate_limit,
+        ))
+        .layer(cors_layer(&config.cors))
+        .layer(middleware::from_fn_with_state(
+            Arc::new(config.security_headers.clone()),
+            security_headers,
+        ))
+        .layer(middleware::from_fn(request_id_in_errors))
+        .layer(middleware::from_fn(request_context))
+        .layer(middleware::from_fn(record_mission_id))
+        .layer(middleware::from_fn(track_http))
         .layer(
-            CorsLayer::new()
-                .allow_methods([
-                    Method::GET,
-                    Method::POST,
-                    Method::PUT,
-                    Method::PATCH,
-                    Method::DELETE,
-                    Method::OPTIONS,
-                ])
-                .allow_origin(Any)
-                .allow_headers([AUTHORIZATION, CONTENT_TYPE]),
+            TraceLayer::new_for_http()
+                .make_span_with(make_request_span)
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
         )
-        .layer(TraceLayer::new_for_http());
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .layer(Extension(Arc::clone(&state.shutdown)))
+        .layer(Extension(Arc::clone(&state.authentication_use_case)))
+        // Outermost, so every middleware below can read the config
+        .layer(Extension(Arc::clone(&config)));
+
+    Ok(app)
+}
+
+/// Serves `/metrics` alone, for when it must stay off the public port. It
+/// keeps answering scrapes through the drain and is cancelled after the API.
+async fn serve_metrics(state: &AppState, port: u16) -> Result<JoinHandle<()>> {
+    let router = routers::metrics::routes(state);
+    let listener = TcpListener::bind(SocketAddr::from(([0, 0, 0, 0], port))).await?;
+
+    info!("Metrics served on port {}", port);
+    Ok(tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, router).await {
+            error!(error = %e, "Metrics listener stopped");
+        }
+    }))
+}
+
+/// Runs until SIGTERM or SIGINT, then drains: readiness fails and WebSockets
+/// are closed while requests are still accepted for `SERVER_SHUTDOWN_DRAIN`
+/// seconds. After that the listener closes and in-flight requests finish.
+pub async fn start(state: Arc<AppState>) -> Result<()> {
+    let port = state.config.server.port;
+    let metrics_listener = match state.config.metrics.port.filter(|_| state.config.metrics.enabled) {
+        Some(metrics_port) => Some(serve_metrics(&state, metrics_port).await?),
+        None => None,
+    };
+    let shutdown = Arc::clone(&state.shutdown);
+    let drain_period = Duration::from_secs(state.config.server.shutdown_drain);
+    let app = app(state)?;
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
     let listener = TcpListener::bind(addr).await?;
 
-    info!("Server start on port {}", config.server.port);
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+    info!("Server start on port {}", port);
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(drain(shutdown, drain_period))
         .await?;
 
+    if let Some(metrics_listener) = metrics_listener {
+        metrics_listener.abort();
+        let _ = metrics_listener.await;
+    }
+    info!("Server stopped");
+
     Ok(())
 }
 
+async fn drain(shutdown: Arc<Shutdown>, drain_period: Duration) {
+    shutdown_signal().await;
+    shutdown.begin_draining();
+    info!(seconds = drain_period.as_secs(), "Draining before shutdown");
+
+    // A second signal skips the rest of the drain
+    tokio::select! {
+        _ = tokio::time::sleep(drain_period) => {}
+        _ = shutdown_signal() => info!("Drain cut short"),
+    }
+    info!("Waiting for in-flight requests");
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async { tokio::signal::ctrl_c().await.expect("Fail ctrl + c") };
 
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Fail to install the SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
     let terminate = std::future::pending::<()>();
 
     tokio::select! {
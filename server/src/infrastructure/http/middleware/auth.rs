@@ -1,6 +1,10 @@
 use crate::infrastructure;
-use crate::config::config_loader::get_jwt_env;
+use crate::application::use_cases::authentication::AccessDenied;
+use crate::config::config_model::DotEnvyConfig;
+use crate::infrastructure::http::app_state::AppAuthenticationUseCase;
+use crate::domain::value_objects::roles::Role;
 use axum::{Json, http::{Request, StatusCode, header}, middleware::Next, body::Body, response::{Response, IntoResponse}};
+use std::sync::Arc;
 use serde_json::json;
 
 pub async fn authorization(mut req: Request<Body>, next: Next) -> Response {
@@ -23,15 +27,15 @@ pub async fn authorization(mut req: Request<Body>, next: Next) -> Response {
         ).into_response(),
     };
 
-    let jwt_env = match get_jwt_env() {
-        Ok(env) => env,
-        Err(_) => return (
+    let config = match req.extensions().get::<Arc<DotEnvyConfig>>() {
+        Some(config) => Arc::clone(config),
+        None => return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({ "error": "Server configuration error" }))
         ).into_response(),
     };
 
-    let claims = match infrastructure::jwt::verify_token(jwt_env.secret, token.to_string()) {
+    let claims = match infrastructure::jwt::verify_token(config.jwt.secret.clone(), token.to_string()) {
         Ok(claims) => claims,
         Err(_) => return (
             StatusCode::UNAUTHORIZED,
@@ -47,7 +51,78 @@ pub async fn authorization(mut req: Request<Body>, next: Next) -> Response {
         ).into_response(),
     };
 
+    let authentication_use_case = match req.extensions().get::<Arc<AppAuthenticationUseCase>>() {
+        Some(use_case) => Arc::clone(use_case),
+        None => return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Server configuration error" }))
+        ).into_response(),
+    };
+
+    // The token's role may be stale, so the current one comes from the database
+    let role = match authentication_use_case.authorize(brawler_id).await {
+        Ok(role) => role,
+        Err(e) => {
+            let (status, error) = match e.downcast_ref::<AccessDenied>() {
+                Some(AccessDenied::Banned) => (StatusCode::FORBIDDEN, e.to_string()),
+                Some(AccessDenied::UnknownBrawler) => (StatusCode::UNAUTHORIZED, e.to_string()),
+                None => {
+                    tracing::error!("Failed to authorize brawler {}: {:#}", brawler_id, e);
+                    (StatusCode::INTERNAL_SERVER_ERROR, "Failed to authorize".to_string())
+                }
+            };
+            return (status, Json(json!({ "error": error }))).into_response();
+        }
+    };
+
+    tracing::Span::current().record("brawler_id", brawler_id);
     req.extensions_mut().insert::<i32>(brawler_id);
+    req.extensions_mut().insert::<Role>(role);
+    next.run(req).await
+}
+
+/// Must run after `authorization`, which puts the caller's role into the request extensions.
+async fn require_role(required: Role, req: Request<Body>, next: Next) -> Response {
+    let role = match req.extensions().get::<Role>() {
+        Some(role) => *role,
+        None => return (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "error": "Missing brawler identity" }))
+        ).into_response(),
+    };
+
+    if role < required {
+        let error = match required {
+            Role::Member => "Member access required",
+            Role::Moderator => "Moderator access required",
+            Role::Admin => "Admin access required",
+        };
+        return (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": error }))
+        ).into_response();
+    }
+
     next.run(req).await
 }
 
+pub async fn moderator_authorization(req: Request<Body>, next: Next) -> Response {
+    require_role(Role::Moderator, req, next).await
+}
+
+pub async fn admin_authorization(req: Request<Body>, next: Next) -> Response {
+    require_role(Role::Admin, req, next).await
+}
+
+/// The brawler behind a valid bearer token, if any. Unlike `authorization` this
+/// never rejects; it is for middleware that only treats signed-in callers differently.
+pub fn bearer_brawler_id(headers: &header::HeaderMap, jwt_secret: &str) -> Option<i32> {
+    let token = headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")?;
+    let claims = infrastructure::jwt::verify_token(jwt_secret.to_string(), token.to_string()).ok()?;
+
+    claims.sub.parse::<i32>().ok()
+}
@@ -0,0 +1,28 @@
+use axum::{
+    body::Body,
+    http::{HeaderName, HeaderValue, Request, header::LINK},
+    middleware::Next,
+    response::Response,
+};
+
+pub const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+pub const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// When the unversioned routes were superseded by `/api/v1` (RFC 9745: `@` and
+/// Unix seconds), here 2026-10-19.
+const LEGACY_DEPRECATED_AT: &str = "@1792368000";
+/// After this the unversioned routes may be removed (RFC 8594).
+const LEGACY_SUNSET: &str = "Mon, 19 Apr 2027 00:00:00 GMT";
+const LEGACY_SUCCESSOR: &str = "</api/v1>; rel=\"successor-version\"";
+
+/// Marks responses of the unversioned `/api` aliases as deprecated in favour of `/api/v1`.
+pub async fn legacy_api(req: Request<Body>, next: Next) -> Response {
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+
+    headers.insert(DEPRECATION, HeaderValue::from_static(LEGACY_DEPRECATED_AT));
+    headers.insert(SUNSET, HeaderValue::from_static(LEGACY_SUNSET));
+    headers.append(LINK, HeaderValue::from_static(LEGACY_SUCCESSOR));
+
+    response
+}
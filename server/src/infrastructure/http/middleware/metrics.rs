@@ -0,0 +1,25 @@
+use std::time::Instant;
+
+use axum::{body::Body, extract::MatchedPath, http::Request, middleware::Next, response::Response};
+
+use crate::infrastructure::metrics;
+
+/// Requests no route matched, such as static files and 404s, share one label.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Counts and times every request by method, matched route and status.
+pub async fn track_http(req: Request<Body>, next: Next) -> Response {
+    let started_at = Instant::now();
+    let method = req.method().to_string();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let response = next.run(req).await;
+
+    metrics::record_http_request(&method, &route, response.status().as_u16(), started_at.elapsed());
+
+    response
+}
@@ -1 +1,7 @@
-pub mod auth ;
\ No newline at end of file
+pub mod auth ;
+pub mod deprecation;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
+pub mod request_span;
+pub mod security_headers;
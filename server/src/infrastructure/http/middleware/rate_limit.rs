@@ -0,0 +1,166 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use tracing::warn;
+
+use crate::{
+    config::config_model::RateLimit,
+    domain::{
+        repositories::rate_limit::RateLimitRepository,
+        value_objects::rate_limit::{RateLimitDecision, RateLimitPolicy},
+    },
+    infrastructure::http::{client_ip::ClientIp, middleware::auth::bearer_brawler_id},
+};
+
+pub const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+pub const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+pub const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+pub const RATE_LIMIT_POLICY: HeaderName = HeaderName::from_static("ratelimit-policy");
+
+/// Requests are limited per group, each with its own bucket per caller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RouteGroup {
+    Login,
+    Register,
+    Upload,
+    Chat,
+    Write,
+    Read,
+}
+
+impl RouteGroup {
+    fn of(method: &Method, api_path: &str) -> Self {
+        let segments = api_path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            (&Method::POST, ["v1", "auth", "login"] | ["authentication", "login"]) => {
+                RouteGroup::Login
+            }
+            (&Method::POST, ["v1", "brawlers"] | ["brawler", "register"]) => RouteGroup::Register,
+            (&Method::PUT, ["v1", "brawlers", "me", "avatar"])
+            | (&Method::POST, ["brawler", "avatar"]) => RouteGroup::Upload,
+            (&Method::POST, ["v1", "missions", _, "messages"] | ["mission-chats", _]) => {
+                RouteGroup::Chat
+            }
+            (&Method::GET | &Method::HEAD, _) => RouteGroup::Read,
+            _ => RouteGroup::Write,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            RouteGroup::Login => "login",
+            RouteGroup::Register => "register",
+            RouteGroup::Upload => "upload",
+            RouteGroup::Chat => "chat",
+            RouteGroup::Write => "write",
+            RouteGroup::Read => "read",
+        }
+    }
+
+    fn policy(&self, config: &RateLimit) -> RateLimitPolicy {
+        match self {
+            RouteGroup::Login => config.login,
+            RouteGroup::Register => config.register,
+            RouteGroup::Upload => config.upload,
+            RouteGroup::Chat => config.chat,
+            RouteGroup::Write => config.write,
+            RouteGroup::Read => config.read,
+        }
+    }
+}
+
+pub struct RateLimiter {
+    store: Arc<dyn RateLimitRepository + Send + Sync>,
+    config: RateLimit,
+    jwt_secret: String,
+}
+
+impl RateLimiter {
+    pub fn new(
+        store: Arc<dyn RateLimitRepository + Send + Sync>,
+        config: RateLimit,
+        jwt_secret: String,
+    ) -> Self {
+        Self {
+            store,
+            config,
+            jwt_secret,
+        }
+    }
+}
+
+/// Token-bucket limits on `/api`, keyed by the signed-in brawler or else the
+/// client IP. Every API response carries `RateLimit-*` headers; refused ones
+/// get `429` with `Retry-After`.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let api_path = match req.uri().path().strip_prefix("/api/") {
+        Some(api_path) if limiter.config.enabled => api_path.to_string(),
+        _ => return next.run(req).await,
+    };
+
+    let group = RouteGroup::of(req.method(), &api_path);
+    let policy = group.policy(&limiter.config);
+    let caller = match bearer_brawler_id(req.headers(), &limiter.jwt_secret) {
+        Some(brawler_id) => format!("brawler:{}", brawler_id),
+        None => format!("ip:{}", ClientIp::resolve(req.headers(), req.extensions()).0),
+    };
+
+    let decision = match limiter
+        .store
+        .take(format!("{}:{}", group.name(), caller), policy)
+        .await
+    {
+        Ok(decision) => decision,
+        Err(e) => {
+            // Better to serve unlimited than to take the API down with the store
+            warn!(error = %e, "Rate limit store failed, letting request through");
+            return next.run(req).await;
+        }
+    };
+
+    let mut response = if decision.allowed {
+        next.run(req).await
+    } else {
+        let retry_after = ceil_secs(decision.retry_after.unwrap_or_default());
+        let mut response = (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(json!({
+                "error": format!("Too many requests, try again in {} seconds", retry_after)
+            })),
+        )
+            .into_response();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        response
+    };
+
+    insert_rate_limit_headers(response.headers_mut(), &policy, &decision);
+    response
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, policy: &RateLimitPolicy, decision: &RateLimitDecision) {
+    headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(decision.limit));
+    headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(RATE_LIMIT_RESET, HeaderValue::from(ceil_secs(decision.reset_after)));
+    if let Ok(value) = HeaderValue::from_str(&format!("{};w={}", policy.capacity, policy.period.as_secs())) {
+        headers.insert(RATE_LIMIT_POLICY, value);
+    }
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
@@ -0,0 +1,66 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{HeaderValue, Request, header::{CONTENT_LENGTH, CONTENT_TYPE}},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use tower_http::request_id::RequestId;
+use tracing::warn;
+
+use crate::infrastructure::request_context::{self, current_request_id};
+
+/// Error bodies are a short message; anything larger is passed through untouched.
+const MAX_ERROR_BODY: usize = 64 * 1024;
+
+/// The id assigned by `SetRequestIdLayer`, or the one the client sent.
+pub fn request_id(req: &Request<Body>) -> Option<&str> {
+    req.extensions()
+        .get::<RequestId>()
+        .and_then(|request_id| request_id.header_value().to_str().ok())
+}
+
+/// Exposes the id assigned by `SetRequestIdLayer` to the rest of the request's task.
+pub async fn request_context(req: Request<Body>, next: Next) -> Response {
+    match request_id(&req).map(str::to_string) {
+        Some(request_id) => request_context::scope(request_id, next.run(req)).await,
+        None => next.run(req).await,
+    }
+}
+
+/// Adds `request_id` to JSON error bodies, so a user reporting an error can quote
+/// the id support searches the logs for. Must run inside `request_context`.
+pub async fn request_id_in_errors(req: Request<Body>, next: Next) -> Response {
+    let response = next.run(req).await;
+
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .is_some_and(|content_type| content_type.as_bytes().starts_with(b"application/json"));
+    let is_error = response.status().is_client_error() || response.status().is_server_error();
+    let Some(request_id) = current_request_id().filter(|_| is_json && is_error) else {
+        return response;
+    };
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ERROR_BODY).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!(error = %e, "Cannot read error body");
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let mut error = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(Value::Object(error)) => error,
+        _ => return Response::from_parts(parts, Body::from(bytes)),
+    };
+    error
+        .entry("request_id")
+        .or_insert_with(|| Value::String(request_id));
+
+    let body = Value::Object(error).to_string();
+    parts.headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+
+    Response::from_parts(parts, Body::from(body))
+}
@@ -0,0 +1,53 @@
+use axum::{
+    body::Body,
+    extract::{MatchedPath, RawPathParams, rejection::RawPathParamsRejection},
+    http::Request,
+    middleware::Next,
+    response::Response,
+};
+use tracing::{Span, field::Empty};
+
+use crate::infrastructure::{http::middleware::request_id::request_id, telemetry};
+
+/// The span every log line of a request nests under, continuing the caller's
+/// trace when it sent a `traceparent`. `authorization` fills in `brawler_id` and
+/// `record_mission_id` the `mission_id` once they are known.
+pub fn make_request_span(req: &Request<Body>) -> Span {
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(MatchedPath::as_str);
+
+    let span = tracing::info_span!(
+        "request",
+        otel.name = format!("{} {}", req.method(), route.unwrap_or("unmatched")),
+        otel.kind = "server",
+        method = %req.method(),
+        path = req.uri().path(),
+        route,
+        request_id = request_id(req),
+        brawler_id = Empty,
+        mission_id = Empty,
+    );
+    telemetry::set_remote_parent(&span, req.headers());
+
+    span
+}
+
+/// Puts the `mission_id` path parameter on the request span for routes that have one.
+pub async fn record_mission_id(
+    params: Result<RawPathParams, RawPathParamsRejection>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let mission_id = params
+        .iter()
+        .flat_map(|params| params.iter())
+        .find(|(name, _)| *name == "mission_id")
+        .and_then(|(_, mission_id)| mission_id.parse::<i32>().ok());
+    if let Some(mission_id) = mission_id {
+        Span::current().record("mission_id", mission_id);
+    }
+
+    next.run(req).await
+}
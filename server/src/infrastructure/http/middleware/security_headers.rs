@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{
+        HeaderValue, Request,
+        header::{
+            CONTENT_SECURITY_POLICY, REFERRER_POLICY, STRICT_TRANSPORT_SECURITY,
+            X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
+        },
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use crate::config::config_model::SecurityHeaders;
+
+/// JSON responses never need to load anything.
+const API_CONTENT_SECURITY_POLICY: &str = "default-src 'none'; frame-ancestors 'none'";
+const HSTS: &str = "max-age=31536000; includeSubDomains";
+
+/// Hardening headers for both the API and the SPA; handlers that set one of
+/// these themselves keep their own value.
+pub async fn security_headers(
+    State(config): State<Arc<SecurityHeaders>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let is_api = req.uri().path().starts_with("/api/");
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+
+    let content_security_policy = if is_api {
+        HeaderValue::from_static(API_CONTENT_SECURITY_POLICY)
+    } else {
+        config.content_security_policy.clone()
+    };
+    headers
+        .entry(CONTENT_SECURITY_POLICY)
+        .or_insert(content_security_policy);
+    headers
+        .entry(X_CONTENT_TYPE_OPTIONS)
+        .or_insert(HeaderValue::from_static("nosniff"));
+    headers
+        .entry(X_FRAME_OPTIONS)
+        .or_insert(HeaderValue::from_static("DENY"));
+    headers
+        .entry(REFERRER_POLICY)
+        .or_insert(HeaderValue::from_static("strict-origin-when-cross-origin"));
+    if config.hsts {
+        headers
+            .entry(STRICT_TRANSPORT_SECURITY)
+            .or_insert(HeaderValue::from_static(HSTS));
+    }
+
+    response
+}
@@ -1,3 +1,6 @@
+pub mod app_state;
 pub mod routers;
 pub mod http_serv;
-pub mod middleware;
\ No newline at end of file
+pub mod middleware;
+pub mod openapi;
+pub mod client_ip;
@@ -0,0 +1,171 @@
+use serde::Serialize;
+use utoipa::{
+    Modify, OpenApi, ToSchema,
+    openapi::{
+        OpenApi as OpenApiDocument,
+        security::{Http, HttpAuthScheme, SecurityScheme},
+    },
+};
+
+use crate::{
+    domain::value_objects::{
+        mission_filter::{MissionSort, SortDirection},
+        mission_statuses::MissionStatuses,
+        notification_model::NotificationModel,
+        roles::Role,
+    },
+    infrastructure::http::routers,
+};
+
+/// Name of the security scheme paths refer to in `security(...)`.
+pub const BEARER_AUTH: &str = "bearer_auth";
+
+/// Body of every non-2xx JSON response, from handlers and middleware alike.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    #[schema(example = "Invalid or expired token")]
+    pub error: String,
+    /// Same as the `X-Request-Id` response header; quote it when reporting a problem.
+    #[schema(example = "0490a7d5-e78c-46ed-8753-e42e0295ed5c")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageResponse {
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MissionCreatedResponse {
+    pub mission_id: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryCreatedResponse {
+    pub slug: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeedbackCreatedResponse {
+    pub feedback_id: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChatMessageSentResponse {
+    #[schema(example = "sent")]
+    pub status: String,
+}
+
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                BEARER_AUTH,
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            );
+        }
+    }
+}
+
+/// The API as clients see it, relative to `/api/v1`. A handler only shows up
+/// here once it is annotated with `#[utoipa::path]` and listed in `paths`.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "GangBro API",
+        description = "Mission management for brawlers. Protected operations expect \
+            `Authorization: Bearer <token>` with the token from login or register; \
+            every error body is `{ \"error\": \"...\" }`. The unversioned `/api` routes \
+            are deprecated aliases of these."
+    ),
+    servers((url = "/api/v1")),
+    paths(
+        routers::authentication::login,
+        routers::brawlers::register,
+        routers::brawlers::upload_avatar,
+        routers::brawlers::get_missions,
+        routers::brawlers::get_profile,
+        routers::categories::get_all,
+        routers::categories::add,
+        routers::categories::retire,
+        routers::missions_viewing::gets,
+        routers::missions_viewing::view_details,
+        routers::missions_viewing::get_crew,
+        routers::missions_management::add,
+        routers::missions_management::edit,
+        routers::missions_management::remove,
+        routers::missions_operations::in_progress,
+        routers::missions_operations::to_completed,
+        routers::missions_operations::to_failed,
+        routers::crew_operations::join,
+        routers::crew_operations::leave,
+        routers::mission_chats::get_messages,
+        routers::mission_chats::add_message,
+        routers::mission_chats::ws_handler,
+        routers::mission_feedbacks::get_mission_feedbacks,
+        routers::mission_feedbacks::add,
+        routers::mission_feedbacks::get_brawler_summary,
+        routers::achievements::get_definitions,
+        routers::achievements::get_brawler_achievements,
+        routers::achievements::ws_handler,
+        routers::admin::force_fail_mission,
+        routers::admin::delete_mission,
+        routers::admin::delete_chat_message,
+        routers::admin::ban,
+        routers::admin::unban,
+        routers::admin::change_role,
+        routers::admin::get_audit_events,
+        routers::system::get_stats,
+    ),
+    // Only reachable through query parameters or WebSocket messages
+    components(schemas(MissionStatuses, MissionSort, SortDirection, Role, NotificationModel)),
+    modifiers(&BearerAuth),
+    tags(
+        (name = "authentication", description = "Logging in"),
+        (name = "brawlers", description = "Registration and the caller's own profile"),
+        (name = "categories", description = "Mission categories"),
+        (name = "missions", description = "Browsing missions and their crews"),
+        (name = "mission-management", description = "Creating, editing and removing missions as their chief"),
+        (name = "mission-operations", description = "Moving a mission through its statuses as its chief"),
+        (name = "crew", description = "Joining and leaving mission crews"),
+        (name = "mission-chats", description = "Mission intel chat"),
+        (name = "mission-feedbacks", description = "Ratings brawlers give each other after a mission"),
+        (name = "achievements", description = "Badges and their live notifications"),
+        (name = "admin", description = "Moderation and audit, for moderators and admins"),
+        (name = "system", description = "Public statistics"),
+    )
+)]
+pub struct ApiDoc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_paths_use_the_bearer_scheme() {
+        let openapi = ApiDoc::openapi();
+        let components = openapi.components.as_ref().expect("components");
+        assert!(components.security_schemes.contains_key(BEARER_AUTH));
+
+        let operation = openapi.paths.paths["/missions"]
+            .post
+            .as_ref()
+            .expect("create mission");
+        let security = serde_json::to_value(&operation.security).expect("serializes");
+        assert_eq!(security, serde_json::json!([{ BEARER_AUTH: [] }]));
+    }
+
+    #[test]
+    fn referenced_schemas_are_all_registered() {
+        let openapi = ApiDoc::openapi();
+        let json = openapi.to_json().expect("serializes");
+        let schemas = &openapi.components.as_ref().expect("components").schemas;
+
+        for reference in json.split("\"$ref\":\"#/components/schemas/").skip(1) {
+            let name = &reference[..reference.find('"').expect("closing quote")];
+            assert!(schemas.contains_key(name), "{} is referenced but not registered", name);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{
+        Path, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use serde_json::json;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    application::use_cases::achievement::AchievementUseCase,
+    domain::{
+        repositories::{
+            achievement::AchievementRepository, notification_publisher::NotificationPublisher,
+        },
+        value_objects::{
+            achievement_model::BrawlerAchievementsModel, achievements::AchievementDefinition,
+        },
+    },
+    infrastructure::{
+        http::{app_state::AppState, middleware::auth::authorization, openapi::ErrorResponse},
+        metrics::WebSocketConnection,
+        shutdown::{Shutdown, restart_close_frame},
+    },
+};
+
+pub fn v1_routes(state: &AppState) -> Router {
+    let protected_routes = Router::new()
+        .route("/notifications/ws", get(ws_handler))
+        .route_layer(middleware::from_fn(authorization));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/achievements", get(get_definitions))
+        .route("/brawlers/{brawler_id}/achievements", get(get_brawler_achievements))
+        .with_state(Arc::clone(&state.achievement_use_case))
+}
+
+#[utoipa::path(
+    get,
+    path = "/achievements",
+    tag = "achievements",
+    summary = "Every badge and how to earn it",
+    responses(
+        (status = 200, description = "Badge definitions", body = [AchievementDefinition]),
+    ),
+)]
+pub async fn get_definitions<T1, T2>(
+    State(achievement_use_case): State<Arc<AchievementUseCase<T1, T2>>>,
+) -> impl IntoResponse
+where
+    T1: AchievementRepository + Send + Sync + ?Sized,
+    T2: NotificationPublisher + Send + Sync + ?Sized,
+{
+    (StatusCode::OK, Json(achievement_use_case.get_definitions())).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/brawlers/{brawler_id}/achievements",
+    tag = "achievements",
+    summary = "Badges and progress of a brawler",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    responses(
+        (status = 200, description = "Progress and awarded badges", body = BrawlerAchievementsModel),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn get_brawler_achievements<T1, T2>(
+    State(achievement_use_case): State<Arc<AchievementUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AchievementRepository + Send + Sync + ?Sized,
+    T2: NotificationPublisher + Send + Sync + ?Sized,
+{
+    match achievement_use_case
+        .get_brawler_achievements(brawler_id)
+        .await
+    {
+        Ok(achievements) => (StatusCode::OK, Json(achievements)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/notifications/ws",
+    tag = "achievements",
+    summary = "WebSocket of the caller's notifications",
+    responses(
+        (status = 101, description = "Switches protocols; each message is a `NotificationModel`"),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn ws_handler<T1, T2>(
+    ws: WebSocketUpgrade,
+    Extension(brawler_id): Extension<i32>,
+    Extension(shutdown): Extension<Arc<Shutdown>>,
+    State(achievement_use_case): State<Arc<AchievementUseCase<T1, T2>>>,
+) -> impl IntoResponse
+where
+    T1: AchievementRepository + Send + Sync + 'static + ?Sized,
+    T2: NotificationPublisher + Send + Sync + 'static + ?Sized,
+{
+    ws.on_upgrade(move |socket| handle_socket(socket, brawler_id, achievement_use_case, shutdown))
+}
+
+async fn handle_socket<T1, T2>(
+    mut socket: WebSocket,
+    brawler_id: i32,
+    achievement_use_case: Arc<AchievementUseCase<T1, T2>>,
+    shutdown: Arc<Shutdown>,
+) where
+    T1: AchievementRepository + Send + Sync + ?Sized,
+    T2: NotificationPublisher + Send + Sync + ?Sized,
+{
+    tracing::info!("[WS] New notification connection for brawler {}", brawler_id);
+    let _connection = WebSocketConnection::notifications();
+
+    let mut notifications = achievement_use_case.subscribe(brawler_id);
+    let draining = shutdown.draining();
+    tokio::pin!(draining);
+
+    loop {
+        tokio::select! {
+            _ = &mut draining => {
+                let _ = socket.send(restart_close_frame()).await;
+                break;
+            }
+            notification = notifications.recv() => match notification {
+                Ok(notification) => {
+                    let Ok(text) = serde_json::to_string(&notification) else {
+                        continue;
+                    };
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(brawler_id, skipped, "[WS] Notifications missed");
+                }
+                Err(RecvError::Closed) => break,
+            },
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
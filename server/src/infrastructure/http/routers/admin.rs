@@ -0,0 +1,364 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+use serde_json::json;
+
+use crate::{
+    application::use_cases::{audit::AuditUseCase, moderation::ModerationUseCase},
+    domain::{
+        repositories::{
+            audit::AuditRepository, mission_metrics::MissionMetrics,
+            moderation::ModerationRepository,
+        },
+        value_objects::{
+            audit_model::{AuditEventFilter, AuditEventModel},
+            invalid_filter::InvalidFilter,
+            moderation_model::{BanBrawlerModel, ChangeRoleModel, ModerationReasonModel},
+        },
+    },
+    infrastructure::http::{
+        app_state::AppState,
+        middleware::auth::{admin_authorization, authorization, moderator_authorization},
+        openapi::{ErrorResponse, MessageResponse},
+    },
+};
+
+pub fn routes(state: &AppState) -> Router {
+    // Layers run outside-in, so `authorization` resolves the role first
+    let moderator_routes = Router::new()
+        .route("/missions/{mission_id}/fail", patch(force_fail_mission))
+        .route("/missions/{mission_id}", delete(delete_mission))
+        .route("/chats/{message_id}", delete(delete_chat_message))
+        .route_layer(middleware::from_fn(moderator_authorization))
+        .route_layer(middleware::from_fn(authorization));
+
+    let admin_routes = Router::new()
+        .route("/brawlers/{brawler_id}/ban", post(ban).delete(unban))
+        .route("/brawlers/{brawler_id}/role", patch(change_role))
+        .route_layer(middleware::from_fn(admin_authorization))
+        .route_layer(middleware::from_fn(authorization));
+
+    let audit_routes = Router::new()
+        .route("/audit-events", get(get_audit_events))
+        .route_layer(middleware::from_fn(admin_authorization))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::clone(&state.audit_use_case));
+
+    Router::new()
+        .merge(moderator_routes)
+        .merge(admin_routes)
+        .with_state(Arc::clone(&state.moderation_use_case))
+        .merge(audit_routes)
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new().nest("/admin", routes(state))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/audit-events",
+    tag = "admin",
+    summary = "Query the audit trail (admin)",
+    params(
+        AuditEventFilter,
+    ),
+    responses(
+        (status = 200, description = "Matching events, newest first", body = [AuditEventModel]),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn get_audit_events<T>(
+    State(audit_use_case): State<Arc<AuditUseCase<T>>>,
+    filter: Query<AuditEventFilter>,
+) -> impl IntoResponse
+where
+    T: AuditRepository + Send + Sync + ?Sized,
+{
+    match audit_use_case.query(&filter).await {
+        Ok(events) => (StatusCode::OK, Json(events)).into_response(),
+        Err(e) => {
+            let status = if e.downcast_ref::<InvalidFilter>().is_some() {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (status, Json(json!({ "error": e.to_string() }))).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/brawlers/{brawler_id}/ban",
+    tag = "admin",
+    summary = "Ban a brawler (admin)",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    request_body = Option<BanBrawlerModel>,
+    responses(
+        (status = 200, description = "Banned", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn ban<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+    model: Option<Json<BanBrawlerModel>>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    let model = model.map(|Json(model)| model).unwrap_or_default();
+    match moderation_use_case.ban(actor_id, brawler_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Brawler({}) has been banned", brawler_id) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/brawlers/{brawler_id}/ban",
+    tag = "admin",
+    summary = "Lift a ban (admin)",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    responses(
+        (status = 200, description = "Unbanned", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn unban<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    match moderation_use_case.unban(actor_id, brawler_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Brawler({}) has been unbanned", brawler_id) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/brawlers/{brawler_id}/role",
+    tag = "admin",
+    summary = "Change a brawler's role (admin)",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    request_body = ChangeRoleModel,
+    responses(
+        (status = 200, description = "Role changed", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn change_role<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(brawler_id): Path<i32>,
+    Json(model): Json<ChangeRoleModel>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    let role = model.role;
+    match moderation_use_case
+        .change_role(actor_id, brawler_id, model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Brawler({}) is now {}", brawler_id, role) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/admin/missions/{mission_id}/fail",
+    tag = "admin",
+    summary = "Force a mission to fail (moderator)",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    request_body = Option<ModerationReasonModel>,
+    responses(
+        (status = 200, description = "Failed", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn force_fail_mission<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    model: Option<Json<ModerationReasonModel>>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    let model = model.map(|Json(model)| model).unwrap_or_default();
+    match moderation_use_case
+        .force_fail_mission(actor_id, mission_id, model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Mission({}) has been failed", mission_id) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/missions/{mission_id}",
+    tag = "admin",
+    summary = "Delete any mission (moderator)",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    request_body = Option<ModerationReasonModel>,
+    responses(
+        (status = 200, description = "Deleted", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn delete_mission<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    model: Option<Json<ModerationReasonModel>>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    let model = model.map(|Json(model)| model).unwrap_or_default();
+    match moderation_use_case
+        .delete_mission(actor_id, mission_id, model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Mission({}) has been deleted", mission_id) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/chats/{message_id}",
+    tag = "admin",
+    summary = "Delete a chat message (moderator)",
+    params(
+        ("message_id" = i32, Path, description = "Chat message id"),
+    ),
+    responses(
+        (status = 200, description = "Deleted", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn delete_chat_message<T1, T2, T3>(
+    State(moderation_use_case): State<Arc<ModerationUseCase<T1, T2, T3>>>,
+    Extension(actor_id): Extension<i32>,
+    Path(message_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: ModerationRepository + Send + Sync + ?Sized,
+    T2: AuditRepository + Send + Sync + ?Sized,
+    T3: MissionMetrics + Send + Sync + ?Sized,
+{
+    match moderation_use_case
+        .delete_chat_message(actor_id, message_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Chat message({}) has been deleted", message_id) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,61 @@
+use axum::{
+    Json, Router,
+    http::{HeaderValue, header::CONTENT_SECURITY_POLICY},
+    response::{Html, IntoResponse},
+    routing::get,
+};
+use utoipa::{OpenApi, openapi::OpenApi as OpenApiDocument};
+
+use crate::{
+    config::stage::Stage,
+    infrastructure::http::{app_state::AppState, openapi::ApiDoc},
+};
+
+/// Redoc renders in a web worker and pulls its script and fonts from CDNs.
+const DOCS_CONTENT_SECURITY_POLICY: &str = "default-src 'none'; \
+    script-src https://cdn.redoc.ly; \
+    style-src 'unsafe-inline' https://fonts.googleapis.com; \
+    font-src https://fonts.gstatic.com; \
+    img-src 'self' data: https://cdn.redoc.ly; \
+    connect-src 'self'; \
+    worker-src blob:; \
+    frame-ancestors 'none'";
+
+const DOCS_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>GangBro API</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <redoc spec-url="/api/openapi.json"></redoc>
+    <script src="https://cdn.redoc.ly/redoc/v2.5.0/bundles/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
+
+/// The spec is public in every stage; the browsable docs stay out of production.
+pub fn routes(state: &AppState) -> Router {
+    let router = Router::new().route("/openapi.json", get(openapi_json));
+
+    if state.config.stage == Stage::Production {
+        router
+    } else {
+        router.route("/docs", get(docs))
+    }
+}
+
+pub async fn openapi_json() -> Json<OpenApiDocument> {
+    Json(ApiDoc::openapi())
+}
+
+pub async fn docs() -> impl IntoResponse {
+    (
+        [(
+            CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static(DOCS_CONTENT_SECURITY_POLICY),
+        )],
+        Html(DOCS_PAGE),
+    )
+}
@@ -1,41 +1,72 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{StatusCode, header::RETRY_AFTER},
+    response::IntoResponse,
+    routing::post,
+};
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
     domain::repositories::brawlers::BrawlerRepository,
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        jwt::authentication_model::LoginModel,
+        http::{app_state::AppState, client_ip::ClientIp, openapi::ErrorResponse},
+        jwt::{authentication_model::LoginModel, jwt_model::Passport},
+        login_throttle::TooManyAttempts,
     },
 };
 
 use serde_json::json;
 
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "authentication",
+    summary = "Log in with username and password",
+    request_body = LoginModel,
+    responses(
+        (status = 200, description = "Logged in", body = Passport),
+        (status = 400, description = "Wrong credentials or a banned brawler", body = ErrorResponse),
+        (status = 429, description = "Too many failed attempts; see `Retry-After`", body = ErrorResponse),
+    ),
+)]
 pub async fn login<T>(
     State(user_case): State<Arc<AuthenticationUseCase<T>>>,
+    ClientIp(client_ip): ClientIp,
     Json(model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T: BrawlerRepository + Send + Sync + ?Sized,
 {
-    match user_case.login(model).await {
+    match user_case.login(model, client_ip).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
 
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            Json(json!({ "error": e.to_string() })),
-        )
-            .into_response(),
+        Err(e) => match e.downcast_ref::<TooManyAttempts>() {
+            Some(too_many) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, too_many.retry_after_secs().to_string())],
+                Json(json!({ "error": e.to_string() })),
+            )
+                .into_response(),
+            None => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            )
+                .into_response(),
+        },
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = AuthenticationUseCase::new(Arc::new(repository));
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/login", post(login))
-        .with_state(Arc::new(user_case))
-}
\ No newline at end of file
+        .with_state(Arc::clone(&state.authentication_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/auth/login", post(login))
+        .with_state(Arc::clone(&state.authentication_use_case))
+}
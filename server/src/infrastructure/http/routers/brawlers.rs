@@ -2,30 +2,34 @@ use std::sync::Arc;
 
 use axum::{
     Extension, Json, Router,
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_image::UploadAvatar},
+        repositories::{
+            achievement::AchievementRepository, brawlers::BrawlerRepository,
+            image_storage::ImageStorage, notification_publisher::NotificationPublisher,
+        },
+        value_objects::{
+            brawler_model::{BrawlerProfileModel, RegisterBrawlerModel},
+            mission_model::MissionModel,
+            uploaded_image::{UploadAvatar, UploadedImage},
+        },
     },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        http::middleware::auth::authorization,
+        http::{app_state::AppState, middleware::auth::authorization, openapi::ErrorResponse},
+        jwt::jwt_model::Passport,
     },
 };
 
 use serde_json::json;
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = BrawlersUseCase::new(Arc::new(repository));
-
+pub fn routes(state: &AppState) -> Router {
     let protected_routes = Router::new()
         .route("/avatar", post(upload_avatar))
         .route("/my-missions", get(get_missions))
@@ -34,15 +38,43 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     Router::new()
         .merge(protected_routes)
         .route("/register", post(register))
-        .with_state(Arc::new(user_case))
+        .with_state(Arc::clone(&state.brawlers_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    let protected_routes = Router::new()
+        .route("/brawlers/me/avatar", put(upload_avatar))
+        .route("/brawlers/me/missions", get(get_missions))
+        .route_layer(axum::middleware::from_fn(authorization));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/brawlers", post(register))
+        .route("/brawlers/{brawler_id}", get(get_profile))
+        .with_state(Arc::clone(&state.brawlers_use_case))
 }
 
-pub async fn get_missions<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+#[utoipa::path(
+    get,
+    path = "/brawlers/me/missions",
+    tag = "brawlers",
+    summary = "Missions the caller chiefs or crews",
+    responses(
+        (status = 200, description = "The caller's missions", body = [MissionModel]),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn get_missions<T1, T2, T3, T4>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2, T3, T4>>>,
     Extension(brawler_id): Extension<i32>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
 {
     match brawlers_use_case.get_missions(brawler_id).await {
         Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
@@ -54,12 +86,26 @@ where
     }
 }
 
-pub async fn register<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+#[utoipa::path(
+    post,
+    path = "/brawlers",
+    tag = "brawlers",
+    summary = "Register a brawler and log in",
+    request_body = RegisterBrawlerModel,
+    responses(
+        (status = 201, description = "Registered", body = Passport),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn register<T1, T2, T3, T4>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3, T4>>>,
     Json(model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
 {
     match user_case.register(model).await {
         Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
@@ -72,13 +118,29 @@ where
     }
 }
 
-pub async fn upload_avatar<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+#[utoipa::path(
+    put,
+    path = "/brawlers/me/avatar",
+    tag = "brawlers",
+    summary = "Replace the caller's avatar with a base64 PNG or JPEG",
+    request_body = UploadAvatar,
+    responses(
+        (status = 200, description = "Uploaded", body = UploadedImage),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn upload_avatar<T1, T2, T3, T4>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3, T4>>>,
     Extension(user_id): Extension<i32>,
     Json(model): Json<UploadAvatar>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
 {
     match user_case
         .upload_base64img(user_id, model.base64_string)
@@ -92,4 +154,37 @@ where
         )
             .into_response(),
     }
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    get,
+    path = "/brawlers/{brawler_id}",
+    tag = "brawlers",
+    summary = "Public profile of a brawler with the badges they earned",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    responses(
+        (status = 200, description = "The brawler's profile", body = BrawlerProfileModel),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn get_profile<T1, T2, T3, T4>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2, T3, T4>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: NotificationPublisher + Send + Sync + ?Sized,
+{
+    match user_case.get_profile(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
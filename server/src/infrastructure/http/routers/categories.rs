@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use serde_json::json;
+
+use crate::{
+    application::use_cases::category::CategoryUseCase,
+    domain::{
+        repositories::category::CategoryRepository,
+        value_objects::category_model::{AddCategoryModel, CategoryModel},
+    },
+    infrastructure::http::{
+        app_state::AppState,
+        middleware::auth::{admin_authorization, authorization},
+        openapi::{CategoryCreatedResponse, ErrorResponse, MessageResponse},
+    },
+};
+
+pub fn routes(state: &AppState) -> Router {
+    // Layers run outside-in, so `authorization` resolves the brawler id first
+    let admin_routes = Router::new()
+        .route("/", post(add))
+        .route("/{slug}", delete(retire))
+        .route_layer(middleware::from_fn(admin_authorization))
+        .route_layer(middleware::from_fn(authorization));
+
+    Router::new()
+        .merge(admin_routes)
+        .route("/", get(get_all))
+        .with_state(Arc::clone(&state.category_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new().nest("/categories", routes(state))
+}
+
+#[utoipa::path(
+    get,
+    path = "/categories",
+    tag = "categories",
+    summary = "Active categories",
+    responses(
+        (status = 200, description = "Active categories", body = [CategoryModel]),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn get_all<T>(
+    State(category_use_case): State<Arc<CategoryUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: CategoryRepository + Send + Sync + ?Sized,
+{
+    match category_use_case.get_all().await {
+        Ok(categories) => (StatusCode::OK, Json(categories)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/categories",
+    tag = "categories",
+    summary = "Add a category (admin)",
+    request_body = AddCategoryModel,
+    responses(
+        (status = 201, description = "Added", body = CategoryCreatedResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn add<T>(
+    State(category_use_case): State<Arc<CategoryUseCase<T>>>,
+    Json(model): Json<AddCategoryModel>,
+) -> impl IntoResponse
+where
+    T: CategoryRepository + Send + Sync + ?Sized,
+{
+    match category_use_case.add(model).await {
+        Ok(slug) => (StatusCode::CREATED, Json(json!({ "slug": slug }))).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/categories/{slug}",
+    tag = "categories",
+    summary = "Retire a category (admin)",
+    params(
+        ("slug" = String, Path, description = "Category slug"),
+    ),
+    responses(
+        (status = 200, description = "Retired", body = MessageResponse),
+        (status = 403, description = "The caller's role is too low", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn retire<T>(
+    State(category_use_case): State<Arc<CategoryUseCase<T>>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse
+where
+    T: CategoryRepository + Send + Sync + ?Sized,
+{
+    match category_use_case.retire(slug.clone()).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Category({}) has been retired", slug) })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
@@ -3,16 +3,37 @@ use std::sync::Arc;
 use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, post}};
 use serde_json::json;
 
-use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::{crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::{achievement::AchievementRepository, audit::AuditRepository, crew_operation::CrewOperationRepository, mission_metrics::MissionMetrics, mission_viewing::MissionViewingRepository, notification_publisher::NotificationPublisher}, infrastructure::http::{app_state::AppState, middleware::auth::authorization, openapi::{ErrorResponse, MessageResponse}}};
 
-pub async fn join<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+type SharedUseCase<T1, T2, T3, T4, T5, T6> = Arc<CrewOperationUseCase<T1, T2, T3, T4, T5, T6>>;
+
+#[utoipa::path(
+    post,
+    path = "/missions/{mission_id}/crew",
+    tag = "crew",
+    summary = "Join the crew of an open mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Joined", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn join<T1, T2, T3, T4, T5, T6>(
+    State(crew_operation_use_case): State<SharedUseCase<T1, T2, T3, T4, T5, T6>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T1: CrewOperationRepository + Send + Sync + 'static + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: AuditRepository + Send + Sync + ?Sized,
+    T5: NotificationPublisher + Send + Sync + ?Sized,
+    T6: MissionMetrics + Send + Sync + ?Sized,
 {
     match crew_operation_use_case.join(mission_id, brawler_id, false).await {
         Ok(_) => (
@@ -30,14 +51,33 @@ where
     }
 }
 
-pub async fn leave<T1, T2>( 
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+#[utoipa::path(
+    delete,
+    path = "/missions/{mission_id}/crew",
+    tag = "crew",
+    summary = "Leave the crew of an open mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Left", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn leave<T1, T2, T3, T4, T5, T6>( 
+    State(crew_operation_use_case): State<SharedUseCase<T1, T2, T3, T4, T5, T6>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T1: CrewOperationRepository + Send + Sync + 'static + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: AchievementRepository + Send + Sync + ?Sized,
+    T4: AuditRepository + Send + Sync + ?Sized,
+    T5: NotificationPublisher + Send + Sync + ?Sized,
+    T6: MissionMetrics + Send + Sync + ?Sized,
 {
 
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
@@ -57,18 +97,17 @@ where
 
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-
-    let use_case = CrewOperationUseCase::new(
-        Arc::new(crew_operation_repository),
-        Arc::new(mission_viewing_repository),
-    );
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
         .route_layer(axum::middleware::from_fn(authorization))
-        .with_state(Arc::new(use_case))
+        .with_state(Arc::clone(&state.crew_operation_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/missions/{mission_id}/crew", post(join).delete(leave))
+        .route_layer(axum::middleware::from_fn(authorization))
+        .with_state(Arc::clone(&state.crew_operation_use_case))
 }
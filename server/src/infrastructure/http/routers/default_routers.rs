@@ -1,17 +1,32 @@
 use axum::{Json, Router, extract::Path, http::StatusCode, response::IntoResponse, routing::get};
 use serde_json::json;
 
+use crate::{config::stage::Stage, infrastructure::http::app_state::AppState};
+
+/// Kept for existing monitors; same as `/health/live`.
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, Json(json!({ "status": "ok", "message": "All Right, I'm Good" }))).into_response()
 }
 
 pub async fn make_error(Path(code): Path<u16>) -> impl IntoResponse {
-    let status_code = StatusCode::from_u16(code).unwrap();
-    (status_code, Json(json!({ "error": format!("Error code: {}", code) }))).into_response()
+    match StatusCode::from_u16(code) {
+        Ok(status_code) => {
+            (status_code, Json(json!({ "error": format!("Error code: {}", code) }))).into_response()
+        }
+        Err(_) => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": format!("Invalid status code: {}", code) })),
+        ).into_response(),
+    }
 }
 
-pub fn routes() -> Router {
-    Router::new()
-        .route("/make-error/{code}", get(make_error))
-        .route("/health-check", get(health_check))
-}
\ No newline at end of file
+/// `/make-error` only exists to exercise error handling, so production leaves it out.
+pub fn routes(state: &AppState) -> Router {
+    let router = Router::new().route("/health-check", get(health_check));
+
+    if state.config.stage == Stage::Production {
+        router
+    } else {
+        router.route("/make-error/{code}", get(make_error))
+    }
+}
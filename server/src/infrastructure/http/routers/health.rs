@@ -0,0 +1,47 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::get, Extension, Json, Router};
+use serde_json::json;
+use std::sync::Arc;
+use crate::{
+    application::use_cases::health::HealthUseCase,
+    domain::{
+        repositories::{health::HealthRepository, image_storage::ImageStorage},
+        value_objects::health_model::{HealthStatus, ReadinessModel},
+    },
+    infrastructure::{http::app_state::AppState, shutdown::Shutdown},
+};
+
+/// Probes for orchestrators, outside `/api` so they are never rate limited.
+pub fn routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/health/live", get(live))
+        .route("/health/ready", get(ready))
+        .with_state(Arc::clone(&state.health_use_case))
+}
+
+/// The process is up and serving requests; dependencies are not consulted.
+pub async fn live() -> impl IntoResponse {
+    (StatusCode::OK, Json(json!({ "status": "ok" }))).into_response()
+}
+
+/// Fails as soon as shutdown begins, so traffic moves elsewhere while this
+/// instance finishes what it has.
+pub async fn ready<T1, T2>(
+    State(health_use_case): State<Arc<HealthUseCase<T1, T2>>>,
+    Extension(shutdown): Extension<Arc<Shutdown>>,
+) -> impl IntoResponse
+where
+    T1: HealthRepository + Send + Sync + ?Sized,
+    T2: ImageStorage + Send + Sync + ?Sized,
+{
+    let readiness = if shutdown.is_draining() {
+        ReadinessModel::draining()
+    } else {
+        health_use_case.readiness().await
+    };
+    let status = match readiness.status {
+        HealthStatus::Fail => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::OK,
+    };
+
+    (status, Json(readiness)).into_response()
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde_json::json;
+
+use crate::infrastructure::{
+    database::postgresql_connection::PgPoolSquad, http::app_state::AppState, metrics,
+};
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+struct MetricsEndpoint {
+    db_pool: Arc<PgPoolSquad>,
+    token: Option<String>,
+}
+
+/// `GET /metrics` in the Prometheus text format, for the public app or the
+/// separate metrics listener.
+pub fn routes(state: &AppState) -> Router {
+    let endpoint = MetricsEndpoint {
+        db_pool: Arc::clone(&state.db_pool),
+        token: state.config.metrics.token.clone(),
+    };
+
+    Router::new()
+        .route("/metrics", get(scrape))
+        .with_state(Arc::new(endpoint))
+}
+
+async fn scrape(State(endpoint): State<Arc<MetricsEndpoint>>, headers: HeaderMap) -> Response {
+    if let Some(token) = &endpoint.token {
+        let presented = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        if !presented.is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes())) {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({ "error": "Invalid metrics token" })),
+            )
+                .into_response();
+        }
+    }
+
+    (
+        [(header::CONTENT_TYPE, HeaderValue::from_static(PROMETHEUS_CONTENT_TYPE))],
+        metrics::render(&endpoint.db_pool),
+    )
+        .into_response()
+}
+
+/// Compares without returning early, so response times don't leak the token.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -12,31 +12,20 @@ use crate::{
     application::use_cases::mission_chat::MissionChatUseCase,
     domain::{
         repositories::mission_chat::MissionChatRepository,
-        value_objects::mission_chat_model::{AddMissionChatModel},
+        value_objects::mission_chat_model::{AddMissionChatModel, MissionChatModel},
     },
     infrastructure::{
-        database::{
-            postgresql_connection::PgPoolSquad, repositories::mission_chat::MissionChatPostgres,
+        http::{
+            app_state::AppState,
+            middleware::auth::authorization,
+            openapi::{ChatMessageSentResponse, ErrorResponse},
         },
-        http::middleware::auth::authorization,
+        metrics::WebSocketConnection,
+        shutdown::{Shutdown, restart_close_frame},
     },
 };
 
-pub struct MissionChatState<T>
-where
-    T: MissionChatRepository + Send + Sync,
-{
-    pub use_case: MissionChatUseCase<T>,
-}
-
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repo = MissionChatPostgres::new(db_pool);
-    let use_case = MissionChatUseCase::new(Arc::new(repo));
-
-    let state = Arc::new(MissionChatState {
-        use_case,
-    });
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/{mission_id}", 
             get(get_messages)
@@ -44,55 +33,106 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
             .layer(axum::middleware::from_fn(authorization))
         )
         .route("/ws/{mission_id}", get(ws_handler))
-        .with_state(state)
+        .with_state(Arc::clone(&state.mission_chat_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route(
+            "/missions/{mission_id}/messages",
+            get(get_messages)
+                .post(add_message)
+                .layer(axum::middleware::from_fn(authorization)),
+        )
+        .route("/missions/{mission_id}/messages/ws", get(ws_handler))
+        .with_state(Arc::clone(&state.mission_chat_use_case))
 }
 
-async fn ws_handler<T>(
+#[utoipa::path(
+    get,
+    path = "/missions/{mission_id}/messages/ws",
+    tag = "mission-chats",
+    summary = "WebSocket that sends the chat history on connect",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 101, description = "Switches protocols; the first message is the history as `[MissionChatModel]`"),
+    ),
+)]
+pub async fn ws_handler<T>(
     ws: WebSocketUpgrade,
     Path(mission_id): Path<i32>,
-    State(state): State<Arc<MissionChatState<T>>>,
+    Extension(shutdown): Extension<Arc<Shutdown>>,
+    State(mission_chat_use_case): State<Arc<MissionChatUseCase<T>>>,
 ) -> impl IntoResponse
 where
-    T: MissionChatRepository + Send + Sync + 'static,
+    T: MissionChatRepository + Send + Sync + 'static + ?Sized,
 {
-    ws.on_upgrade(move |socket| handle_socket(socket, mission_id, state))
+    ws.on_upgrade(move |socket| handle_socket(socket, mission_id, mission_chat_use_case, shutdown))
 }
 
 async fn handle_socket<T>(
     mut _socket: WebSocket,
     mission_id: i32,
-    state: Arc<MissionChatState<T>>,
+    mission_chat_use_case: Arc<MissionChatUseCase<T>>,
+    shutdown: Arc<Shutdown>,
 ) where
-    T: MissionChatRepository + Send + Sync,
+    T: MissionChatRepository + Send + Sync + ?Sized,
 {
     tracing::info!("[WS] New connection for mission {}", mission_id);
+    let _connection = WebSocketConnection::mission_chat(mission_id);
     
     // Initial data: send current messages right away
-    if let Ok(messages) = state.use_case.get_messages(mission_id).await {
+    if let Ok(messages) = mission_chat_use_case.get_messages(mission_id).await {
         if let Ok(msg_text) = serde_json::to_string(&messages) {
             let _ = _socket.send(axum::extract::ws::Message::Text(msg_text.into())).await;
         }
     }
 
-    // Listener loop to keep connection alive
-    while let Some(Ok(msg)) = _socket.recv().await {
-        if let axum::extract::ws::Message::Close(_) = msg {
-            break;
+    // Listener loop to keep connection alive, until the client or a shutdown closes it
+    let draining = shutdown.draining();
+    tokio::pin!(draining);
+    loop {
+        tokio::select! {
+            _ = &mut draining => {
+                let _ = _socket.send(restart_close_frame()).await;
+                break;
+            }
+            msg = _socket.recv() => match msg {
+                Some(Ok(axum::extract::ws::Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
         }
     }
 }
 
-async fn get_messages<T>(
-    State(state): State<Arc<MissionChatState<T>>>,
+#[utoipa::path(
+    get,
+    path = "/missions/{mission_id}/messages",
+    tag = "mission-chats",
+    summary = "Chat history of a mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Messages, oldest first", body = [MissionChatModel]),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn get_messages<T>(
+    State(mission_chat_use_case): State<Arc<MissionChatUseCase<T>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionChatRepository + Send + Sync,
+    T: MissionChatRepository + Send + Sync + ?Sized,
 {
-    match state.use_case.get_messages(mission_id).await {
+    match mission_chat_use_case.get_messages(mission_id).await {
         Ok(messages) => (StatusCode::OK, Json(messages)).into_response(),
         Err(e) => {
-            tracing::error!("Failed to get messages: {}", e);
+            tracing::error!(error = %e, "Failed to get messages");
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "error": e.to_string() })),
@@ -101,21 +141,37 @@ where
     }
 }
 
-async fn add_message<T>(
-    State(state): State<Arc<MissionChatState<T>>>,
+#[utoipa::path(
+    post,
+    path = "/missions/{mission_id}/messages",
+    tag = "mission-chats",
+    summary = "Post to a mission's chat",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    request_body = AddMissionChatModel,
+    responses(
+        (status = 201, description = "Sent", body = ChatMessageSentResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn add_message<T>(
+    State(mission_chat_use_case): State<Arc<MissionChatUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
     Json(payload): Json<AddMissionChatModel>,
 ) -> impl IntoResponse
 where
-    T: MissionChatRepository + Send + Sync,
+    T: MissionChatRepository + Send + Sync + ?Sized,
 {
-    match state.use_case.add_message(mission_id, brawler_id, &payload.message).await {
+    match mission_chat_use_case.add_message(mission_id, brawler_id, &payload.message).await {
         Ok(_) => {
             (StatusCode::CREATED, Json(json!({ "status": "sent" }))).into_response()
         },
         Err(e) => {
-            tracing::error!("Failed to add message: {}", e);
+            tracing::error!(error = %e, "Failed to add message");
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "error": e.to_string() })),
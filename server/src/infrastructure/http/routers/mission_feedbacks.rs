@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use serde_json::json;
+
+use crate::{
+    application::use_cases::mission_feedback::MissionFeedbackUseCase,
+    domain::{
+        repositories::{
+            mission_feedback::MissionFeedbackRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_feedback_model::{
+            AddMissionFeedbackModel, BrawlerFeedbackSummaryModel, MissionFeedbackModel,
+        },
+    },
+    infrastructure::http::{
+        app_state::AppState,
+        middleware::auth::authorization,
+        openapi::{ErrorResponse, FeedbackCreatedResponse},
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/missions/{mission_id}/feedbacks",
+    tag = "mission-feedbacks",
+    summary = "Rate a crewmate of a finished mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    request_body = AddMissionFeedbackModel,
+    responses(
+        (status = 201, description = "Rated", body = FeedbackCreatedResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn add<T1, T2>(
+    State(mission_feedback_use_case): State<Arc<MissionFeedbackUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionFeedbackModel>,
+) -> impl IntoResponse
+where
+    T1: MissionFeedbackRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+{
+    match mission_feedback_use_case
+        .add(mission_id, brawler_id, model)
+        .await
+    {
+        Ok(feedback_id) => (
+            StatusCode::CREATED,
+            Json(json!({ "feedback_id": feedback_id })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/missions/{mission_id}/feedbacks",
+    tag = "mission-feedbacks",
+    summary = "Feedback left on a mission the caller took part in",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Feedback on the mission", body = [MissionFeedbackModel]),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn get_mission_feedbacks<T1, T2>(
+    State(mission_feedback_use_case): State<Arc<MissionFeedbackUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionFeedbackRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+{
+    match mission_feedback_use_case
+        .get_mission_feedbacks(mission_id, brawler_id)
+        .await
+    {
+        Ok(feedbacks) => (StatusCode::OK, Json(feedbacks)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/brawlers/{brawler_id}/feedbacks",
+    tag = "mission-feedbacks",
+    summary = "Rating summary of a brawler",
+    params(
+        ("brawler_id" = i32, Path, description = "Brawler id"),
+    ),
+    responses(
+        (status = 200, description = "Average rating and the feedback behind it", body = BrawlerFeedbackSummaryModel),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn get_brawler_summary<T1, T2>(
+    State(mission_feedback_use_case): State<Arc<MissionFeedbackUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionFeedbackRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+{
+    match mission_feedback_use_case.get_brawler_summary(brawler_id).await {
+        Ok(summary) => (StatusCode::OK, Json(summary)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/missions/{mission_id}/feedbacks", get(get_mission_feedbacks).post(add))
+        .route_layer(middleware::from_fn(authorization))
+        .route("/brawlers/{brawler_id}/feedbacks", get(get_brawler_summary))
+        .with_state(Arc::clone(&state.mission_feedback_use_case))
+}
@@ -14,36 +14,54 @@ use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
     domain::{
         repositories::{
+            achievement::AchievementRepository,
+            audit::AuditRepository,
+            category::CategoryRepository,
             crew_operation::CrewOperationRepository,
             mission_chat::MissionChatRepository,
             mission_management::MissionManagementRepository,
-            mission_viewing::MissionViewingRepository,
+            mission_metrics::MissionMetrics,
+            notification_publisher::NotificationPublisher,
         },
         value_objects::mission_model::{AddMissionModel, EditMissionModel},
     },
-    infrastructure::{
-        database::{
-            postgresql_connection::PgPoolSquad,
-            repositories::{
-                crew_operation::CrewOperationPostgres,
-                mission_chat::MissionChatPostgres,
-                mission_management::MissionManagementPostgres,
-                mission_viewing::MissionViewingPostgres,
-            },
-        },
-        http::middleware::auth::authorization,
+    infrastructure::http::{
+        app_state::AppState,
+        middleware::auth::authorization,
+        openapi::{ErrorResponse, MessageResponse, MissionCreatedResponse},
     },
 };
 
-pub async fn add<T1, T2, T3>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+type UseCaseState<T1, T2, T3, T4, T5, T6, T7, T8> =
+    State<Arc<MissionManagementUseCase<T1, T2, T3, T4, T5, T6, T7, T8>>>;
+
+#[utoipa::path(
+    post,
+    path = "/missions",
+    tag = "mission-management",
+    summary = "Create a mission chiefed by the caller",
+    request_body = AddMissionModel,
+    responses(
+        (status = 201, description = "Created", body = MissionCreatedResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn add<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): UseCaseState<T1, T2, T3, T4, T5, T6, T7, T8>,
     Extension(user_id): Extension<i32>,
     Json(model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionChatRepository + Send + Sync,
-    T3: CrewOperationRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + ?Sized,
+    T2: MissionChatRepository + Send + Sync + ?Sized,
+    T3: CrewOperationRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: CategoryRepository + Send + Sync + ?Sized,
+    T6: AuditRepository + Send + Sync + ?Sized,
+    T7: NotificationPublisher + Send + Sync + ?Sized,
+    T8: MissionMetrics + Send + Sync + ?Sized,
 {
     match user_case.add(user_id, model).await {
         Ok(mission_id) => {
@@ -61,16 +79,37 @@ where
     }
 }
 
-pub async fn edit<T1, T2, T3>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+#[utoipa::path(
+    patch,
+    path = "/missions/{mission_id}",
+    tag = "mission-management",
+    summary = "Edit an open mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    request_body = EditMissionModel,
+    responses(
+        (status = 200, description = "Edited", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn edit<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): UseCaseState<T1, T2, T3, T4, T5, T6, T7, T8>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
     Json(model): Json<EditMissionModel>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionChatRepository + Send + Sync,
-    T3: CrewOperationRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + ?Sized,
+    T2: MissionChatRepository + Send + Sync + ?Sized,
+    T3: CrewOperationRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: CategoryRepository + Send + Sync + ?Sized,
+    T6: AuditRepository + Send + Sync + ?Sized,
+    T7: NotificationPublisher + Send + Sync + ?Sized,
+    T8: MissionMetrics + Send + Sync + ?Sized,
 {
     match user_case.edit(mission_id, user_id, model).await {
         Ok(mission_id) => (
@@ -87,15 +126,35 @@ where
     }
 }
 
-pub async fn remove<T1, T2, T3>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+#[utoipa::path(
+    delete,
+    path = "/missions/{mission_id}",
+    tag = "mission-management",
+    summary = "Remove an open mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Removed", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn remove<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): UseCaseState<T1, T2, T3, T4, T5, T6, T7, T8>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionManagementRepository + Send + Sync,
-    T2: MissionChatRepository + Send + Sync,
-    T3: CrewOperationRepository + Send + Sync,
+    T1: MissionManagementRepository + Send + Sync + ?Sized,
+    T2: MissionChatRepository + Send + Sync + ?Sized,
+    T3: CrewOperationRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: CategoryRepository + Send + Sync + ?Sized,
+    T6: AuditRepository + Send + Sync + ?Sized,
+    T7: NotificationPublisher + Send + Sync + ?Sized,
+    T8: MissionMetrics + Send + Sync + ?Sized,
 {
     match user_case.remove(mission_id, user_id).await {
         Ok(_) => (
@@ -112,22 +171,19 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
-    let mission_chat_repository = MissionChatPostgres::new(Arc::clone(&db_pool));
-    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
-    
-    let user_case =
-        MissionManagementUseCase::new(
-            Arc::new(mission_repository), 
-            Arc::new(mission_chat_repository),
-            Arc::new(crew_operation_repository)
-        );
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
         .route_layer(middleware::from_fn(authorization))
-        .with_state(Arc::new(user_case))
-}
\ No newline at end of file
+        .with_state(Arc::clone(&state.mission_management_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/missions", post(add))
+        .route("/missions/{mission_id}", patch(edit).delete(remove))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::clone(&state.mission_management_use_case))
+}
@@ -3,17 +3,38 @@ use std::sync::Arc;
 use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, middleware, response::IntoResponse, routing::patch};
 use serde_json::json;
 
-use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::{mission_chat::MissionChatRepository, mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository}, value_objects::mission_statuses::MissionStatuses}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_chat::MissionChatPostgres, mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::{achievement::AchievementRepository, audit::AuditRepository, mission_chat::MissionChatRepository, mission_metrics::MissionMetrics, mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository, notification_publisher::NotificationPublisher}, value_objects::mission_statuses::MissionStatuses}, infrastructure::http::{app_state::AppState, middleware::auth::authorization, openapi::{ErrorResponse, MessageResponse}}};
 
-pub async fn in_progress<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+type SharedUseCase<T1, T2, T3, T4, T5, T6, T7> = Arc<MissionOperationUseCase<T1, T2, T3, T4, T5, T6, T7>>;
+
+#[utoipa::path(
+    patch,
+    path = "/missions/{mission_id}/start",
+    tag = "mission-operations",
+    summary = "Start an open mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "The mission is in progress", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn in_progress<T1, T2, T3, T4, T5, T6, T7>(
+    State(mission_operation_use_case): State<SharedUseCase<T1, T2, T3, T4, T5, T6, T7>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: MissionChatRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: MissionChatRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: AuditRepository + Send + Sync + ?Sized,
+    T6: NotificationPublisher + Send + Sync + ?Sized,
+    T7: MissionMetrics + Send + Sync + ?Sized,
 {
     match mission_operation_use_case
         .in_progress(mission_id, chief_id)
@@ -34,15 +55,34 @@ where
     }
 }
 
-pub async fn to_completed<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+#[utoipa::path(
+    patch,
+    path = "/missions/{mission_id}/complete",
+    tag = "mission-operations",
+    summary = "Complete a mission in progress",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "The mission is completed", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn to_completed<T1, T2, T3, T4, T5, T6, T7>(
+    State(mission_operation_use_case): State<SharedUseCase<T1, T2, T3, T4, T5, T6, T7>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: MissionChatRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: MissionChatRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: AuditRepository + Send + Sync + ?Sized,
+    T6: NotificationPublisher + Send + Sync + ?Sized,
+    T7: MissionMetrics + Send + Sync + ?Sized,
 {
     match mission_operation_use_case
         .to_completed(mission_id, chief_id)
@@ -63,15 +103,34 @@ where
     }
 }
 
-pub async fn to_failed<T1, T2, T3>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2, T3>>>,
+#[utoipa::path(
+    patch,
+    path = "/missions/{mission_id}/fail",
+    tag = "mission-operations",
+    summary = "Fail a mission in progress",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "The mission has failed", body = MessageResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid token", body = ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+pub async fn to_failed<T1, T2, T3, T4, T5, T6, T7>(
+    State(mission_operation_use_case): State<SharedUseCase<T1, T2, T3, T4, T5, T6, T7>>,
     Extension(chief_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: MissionChatRepository + Send + Sync,
+    T1: MissionOperationRepository + Send + Sync + ?Sized,
+    T2: MissionViewingRepository + Send + Sync + ?Sized,
+    T3: MissionChatRepository + Send + Sync + ?Sized,
+    T4: AchievementRepository + Send + Sync + ?Sized,
+    T5: AuditRepository + Send + Sync + ?Sized,
+    T6: NotificationPublisher + Send + Sync + ?Sized,
+    T7: MissionMetrics + Send + Sync + ?Sized,
 {
     match mission_operation_use_case
         .to_failed(mission_id, chief_id)
@@ -92,20 +151,20 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_operation_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let mission_chat_repository = MissionChatPostgres::new(Arc::clone(&db_pool));
-    let use_case = MissionOperationUseCase::new(
-        Arc::new(mission_operation_repository),
-        Arc::new(mission_viewing_repository),
-        Arc::new(mission_chat_repository),
-    );
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
         .route_layer(middleware::from_fn(authorization))
-        .with_state(Arc::new(use_case))
-}
\ No newline at end of file
+        .with_state(Arc::clone(&state.mission_operation_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/missions/{mission_id}/start", patch(in_progress))
+        .route("/missions/{mission_id}/complete", patch(to_completed))
+        .route("/missions/{mission_id}/fail", patch(to_failed))
+        .route_layer(middleware::from_fn(authorization))
+        .with_state(Arc::clone(&state.mission_operation_use_case))
+}
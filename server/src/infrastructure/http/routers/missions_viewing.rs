@@ -12,31 +12,51 @@ use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
-    },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+        value_objects::{
+            brawler_model::BrawlerModel, invalid_filter::InvalidFilter,
+            mission_filter::MissionFilter, mission_model::MissionModel,
+            mission_page_model::MissionPageModel,
+        },
     },
+    infrastructure::http::{app_state::AppState, openapi::ErrorResponse},
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_viewing_repository = MissionViewingPostgres::new(db_pool);
-    let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository));
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/gets", get(gets))
         .route("/{mission_id}", get(view_details))
         .route("/crew/{mission_id}", get(get_mission_count))
         .route("/roster/{mission_id}", get(get_crew))
-        .with_state(Arc::new(use_case))
+        .with_state(Arc::clone(&state.mission_viewing_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new()
+        .route("/missions", get(gets))
+        .route("/missions/{mission_id}", get(view_details))
+        .route("/missions/{mission_id}/crew", get(get_crew))
+        .with_state(Arc::clone(&state.mission_viewing_use_case))
 }
 
+#[utoipa::path(
+    get,
+    path = "/missions/{mission_id}/crew",
+    tag = "missions",
+    summary = "Crew of a mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "Crew members", body = [BrawlerModel]),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
 pub async fn get_crew<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
     match mission_viewing_use_case.get_crew(mission_id).await {
         Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
@@ -50,12 +70,25 @@ where
 
 use serde_json::json;
 
+#[utoipa::path(
+    get,
+    path = "/missions/{mission_id}",
+    tag = "missions",
+    summary = "One mission",
+    params(
+        ("mission_id" = i32, Path, description = "Mission id"),
+    ),
+    responses(
+        (status = 200, description = "The mission", body = MissionModel),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
 pub async fn view_details<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
     match mission_viewing_use_case.get_one(mission_id).await {
         Ok(mission_model) => (StatusCode::OK, Json(mission_model)).into_response(),
@@ -67,20 +100,37 @@ where
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/missions",
+    tag = "missions",
+    summary = "Search, filter and page through missions",
+    params(
+        MissionFilter,
+    ),
+    responses(
+        (status = 200, description = "One page of missions", body = MissionPageModel),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
 pub async fn gets<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     filter: Query<MissionFilter>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
     match mission_viewing_use_case.get_all(&filter).await {
         Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({ "error": e.to_string() })),
-        )
-            .into_response(),
+        Err(e) => {
+            let status = if e.downcast_ref::<InvalidFilter>().is_some() {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (status, Json(json!({ "error": e.to_string() }))).into_response()
+        }
     }
 }
 
@@ -89,7 +139,7 @@ pub async fn get_mission_count<T>(
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T: MissionViewingRepository + Send + Sync + ?Sized,
 {
     match mission_viewing_use_case.get_mission_count(mission_id).await {
         Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
@@ -1,7 +1,14 @@
 pub mod default_routers;
+pub mod health;
+pub mod metrics;
+pub mod api_docs;
+pub mod achievements;
+pub mod admin;
 pub mod authentication;
 pub mod brawlers;
+pub mod categories;
 pub mod mission_chats;
+pub mod mission_feedbacks;
 pub mod crew_operations;
 pub mod missions_management;
 pub mod missions_operations;
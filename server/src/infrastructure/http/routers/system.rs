@@ -2,33 +2,37 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, routing::ge
 use std::sync::Arc;
 use crate::{
     application::use_cases::system::SystemUseCase,
-    domain::repositories::system::SystemRepository,
+    domain::{repositories::system::SystemRepository, value_objects::system_stats::SystemStatsModel},
+    infrastructure::http::{app_state::AppState, openapi::ErrorResponse},
 };
 
-pub struct SystemState<T: SystemRepository> {
-    pub use_case: SystemUseCase<T>,
-}
-
-pub fn routes<T>(repo: T) -> Router 
-where 
-    T: SystemRepository + Send + Sync + 'static,
-{
-    let state = Arc::new(SystemState {
-        use_case: SystemUseCase::new(repo),
-    });
-
+pub fn routes(state: &AppState) -> Router {
     Router::new()
         .route("/stats", get(get_stats))
-        .with_state(state)
+        .with_state(Arc::clone(&state.system_use_case))
+}
+
+pub fn v1_routes(state: &AppState) -> Router {
+    Router::new().nest("/system", routes(state))
 }
 
-async fn get_stats<T>(
-    State(state): State<Arc<SystemState<T>>>,
+#[utoipa::path(
+    get,
+    path = "/system/stats",
+    tag = "system",
+    summary = "Member and mission totals",
+    responses(
+        (status = 200, description = "Current totals", body = SystemStatsModel),
+        (status = 500, description = "The operation failed", body = ErrorResponse),
+    ),
+)]
+pub async fn get_stats<T>(
+    State(system_use_case): State<Arc<SystemUseCase<T>>>,
 ) -> impl IntoResponse
 where
-    T: SystemRepository + Send + Sync,
+    T: SystemRepository + Send + Sync + ?Sized,
 {
-    match state.use_case.get_stats().await {
+    match system_use_case.get_stats().await {
         Ok(stats) => (StatusCode::OK, Json(stats)).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -3,11 +3,14 @@ use anyhow::Result;
 use chrono::Duration;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-use crate::config::config_loader::get_jwt_env;
+use crate::config::config_model::JwtEnv;
+use crate::domain::value_objects::roles::Role;
 use crate::infrastructure::jwt::generate_token;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
 pub struct Passport {
     // pub token_type: String,
     pub user_id: i32,
@@ -15,22 +18,30 @@ pub struct Passport {
     // pub expires_in: usize,
     pub display_name: String,
     pub avatar_url: Option<String>,
+    pub role: Role,
 }
 
 impl Passport {
-    pub fn new(user_id: i32, display_name: String, avatar_url: Option<String>) -> Result<Self> {
-        let jwt_env = get_jwt_env()?;
+    pub fn new(
+        user_id: i32,
+        display_name: String,
+        avatar_url: Option<String>,
+        role: Role,
+        jwt_env: &JwtEnv,
+    ) -> Result<Self> {
         let claims = Claims {
             sub: user_id.to_string(),
             exp: (Utc::now() + Duration::days(jwt_env.lift_time_days)).timestamp() as usize,
             iat: Utc::now().timestamp() as usize,
+            role,
         };
-        let token = generate_token(jwt_env.secret, &claims)?;
+        let token = generate_token(jwt_env.secret.clone(), &claims)?;
         Ok(Self {
             user_id,
             token,
             display_name,
             avatar_url,
+            role,
         })
     }
 }
@@ -40,4 +51,7 @@ pub struct Claims {
     pub sub: String,
     pub exp: usize,
     pub iat: usize,
+    /// Tokens issued before roles existed carry none and act as members.
+    #[serde(default)]
+    pub role: Role,
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+use crate::config::config_model::{LogFormat, Logging};
+
+/// Installs the global subscriber, exporting spans too when a tracer provider is
+/// given. JSON lines list the spans they happened in, so every line carries the
+/// request span's fields (request id, route, brawler and mission ids).
+pub fn init(logging: &Logging, tracer_provider: Option<&SdkTracerProvider>) {
+    let filter = EnvFilter::try_new(&logging.level).unwrap_or_else(|_| EnvFilter::new("info"));
+    let fmt_layer = match logging.format {
+        LogFormat::Pretty => tracing_subscriber::fmt::layer().boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(false)
+            .with_span_list(true)
+            .boxed(),
+    };
+    let otel_layer = tracer_provider
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer("gangbro-server")));
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt_layer)
+        .with(otel_layer)
+        .init();
+}
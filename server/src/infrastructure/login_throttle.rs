@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    hash::Hash,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+
+/// How many failures a key gets before it has to slow down or stop.
+struct AttemptPolicy {
+    /// Failures allowed back to back before backoff kicks in.
+    free_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    /// Failures after which the key is locked out for `lockout`.
+    lockout_after: u32,
+    lockout: Duration,
+}
+
+// Several users can share an IP behind NAT, so it gets more room than a username.
+const USERNAME_POLICY: AttemptPolicy = AttemptPolicy {
+    free_attempts: 3,
+    base_backoff: Duration::from_secs(1),
+    max_backoff: Duration::from_secs(60),
+    lockout_after: 10,
+    lockout: Duration::from_secs(15 * 60),
+};
+
+const IP_POLICY: AttemptPolicy = AttemptPolicy {
+    free_attempts: 10,
+    base_backoff: Duration::from_secs(1),
+    max_backoff: Duration::from_secs(60),
+    lockout_after: 50,
+    lockout: Duration::from_secs(15 * 60),
+};
+
+/// Failures older than this no longer count.
+const ATTEMPT_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// Stale entries are swept once a map grows past this many keys.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Attempts {
+    failures: u32,
+    last_failure: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl Attempts {
+    fn is_stale(&self, now: Instant) -> bool {
+        now.duration_since(self.last_failure) > ATTEMPT_WINDOW
+            && self.blocked_until.is_none_or(|until| until <= now)
+    }
+
+    fn retry_after(&self, now: Instant) -> Option<Duration> {
+        self.blocked_until
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyAttempts {
+    pub retry_after: Duration,
+}
+
+impl fmt::Display for TooManyAttempts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Too many login attempts, try again in {} seconds",
+            self.retry_after_secs()
+        )
+    }
+}
+
+impl std::error::Error for TooManyAttempts {}
+
+impl TooManyAttempts {
+    pub fn retry_after_secs(&self) -> u64 {
+        self.retry_after.as_secs() + u64::from(self.retry_after.subsec_nanos() > 0)
+    }
+}
+
+/// In-process tracker of failed logins per username and per client IP, with
+/// exponential backoff and a temporary lockout. State is per server instance
+/// and resets on restart.
+#[derive(Default)]
+pub struct LoginThrottle {
+    by_username: DashMap<String, Attempts>,
+    by_ip: DashMap<IpAddr, Attempts>,
+}
+
+impl LoginThrottle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails while either the username or the IP is backing off or locked out.
+    pub fn check(&self, username: &str, ip: IpAddr) -> Result<(), TooManyAttempts> {
+        let now = Instant::now();
+        let retry_after = [
+            retry_after(&self.by_username, &username_key(username), now),
+            retry_after(&self.by_ip, &ip, now),
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+
+        match retry_after {
+            Some(retry_after) => Err(TooManyAttempts { retry_after }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn record_failure(&self, username: &str, ip: IpAddr) {
+        let now = Instant::now();
+        record_failure(&self.by_username, username_key(username), &USERNAME_POLICY, now);
+        record_failure(&self.by_ip, ip, &IP_POLICY, now);
+    }
+
+    /// A correct password clears the username; the IP keeps its count so one
+    /// valid account can't be used to reset a spraying client.
+    pub fn record_success(&self, username: &str) {
+        self.by_username.remove(&username_key(username));
+    }
+}
+
+fn username_key(username: &str) -> String {
+    username.trim().to_lowercase()
+}
+
+fn retry_after<K: Eq + Hash>(attempts: &DashMap<K, Attempts>, key: &K, now: Instant) -> Option<Duration> {
+    attempts.get(key)?.retry_after(now)
+}
+
+fn record_failure<K: Eq + Hash>(attempts: &DashMap<K, Attempts>, key: K, policy: &AttemptPolicy, now: Instant) {
+    if attempts.len() > PRUNE_THRESHOLD {
+        attempts.retain(|_, entry| !entry.is_stale(now));
+    }
+
+    let mut entry = attempts.entry(key).or_insert(Attempts {
+        failures: 0,
+        last_failure: now,
+        blocked_until: None,
+    });
+    if entry.is_stale(now) {
+        entry.failures = 0;
+    }
+
+    entry.failures += 1;
+    entry.last_failure = now;
+    entry.blocked_until = if entry.failures >= policy.lockout_after {
+        Some(now + policy.lockout)
+    } else if entry.failures > policy.free_attempts {
+        let exponent = (entry.failures - policy.free_attempts - 1).min(16);
+        let backoff = policy.base_backoff.saturating_mul(1 << exponent);
+        Some(now + backoff.min(policy.max_backoff))
+    } else {
+        None
+    };
+}
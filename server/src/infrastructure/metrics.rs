@@ -0,0 +1,124 @@
+use std::{sync::OnceLock, time::Duration};
+
+use metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use tracing::warn;
+
+use crate::{
+    domain::repositories::mission_metrics::MissionMetrics,
+    infrastructure::database::postgresql_connection::PgPoolSquad,
+};
+
+const HTTP_REQUESTS: &str = "gangbro_http_requests_total";
+const HTTP_REQUEST_DURATION: &str = "gangbro_http_request_duration_seconds";
+const DB_POOL_CONNECTIONS: &str = "gangbro_db_pool_connections";
+const DB_POOL_IDLE_CONNECTIONS: &str = "gangbro_db_pool_idle_connections";
+const DB_POOL_MAX_CONNECTIONS: &str = "gangbro_db_pool_max_connections";
+const WEBSOCKET_CONNECTIONS: &str = "gangbro_websocket_connections";
+const MISSIONS_CREATED: &str = "gangbro_missions_created_total";
+const CREW_JOINS: &str = "gangbro_crew_joins_total";
+const CREW_LEAVES: &str = "gangbro_crew_leaves_total";
+const MISSION_STATUS_TRANSITIONS: &str = "gangbro_mission_status_transitions_total";
+
+/// From a quick cached read up to a request about to hit the server timeout.
+const HTTP_DURATION_BUCKETS: [f64; 12] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+static HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Installs the process-wide Prometheus recorder on first use. Until then, and in
+/// unit tests that never call it, the recording functions below are no-ops.
+pub fn handle() -> &'static PrometheusHandle {
+    HANDLE.get_or_init(|| {
+        let recorder = PrometheusBuilder::new()
+            .set_buckets_for_metric(Matcher::Full(HTTP_REQUEST_DURATION.to_string()), &HTTP_DURATION_BUCKETS)
+            .expect("valid histogram buckets")
+            .build_recorder();
+        let handle = recorder.handle();
+
+        if let Err(e) = metrics::set_global_recorder(recorder) {
+            warn!(error = %e, "Metrics recorder not installed");
+        }
+
+        handle
+    })
+}
+
+/// The Prometheus text exposition of every metric, with the pool gauges sampled now.
+pub fn render(db_pool: &PgPoolSquad) -> String {
+    let handle = handle();
+
+    let state = db_pool.state();
+    gauge!(DB_POOL_CONNECTIONS).set(state.connections);
+    gauge!(DB_POOL_IDLE_CONNECTIONS).set(state.idle_connections);
+    gauge!(DB_POOL_MAX_CONNECTIONS).set(db_pool.max_size());
+
+    handle.run_upkeep();
+    handle.render()
+}
+
+/// `route` is the matched route template, never the raw path, to keep the
+/// number of series bounded.
+pub fn record_http_request(method: &str, route: &str, status: u16, elapsed: Duration) {
+    let labels = [
+        ("method", method.to_string()),
+        ("route", route.to_string()),
+        ("status", status.to_string()),
+    ];
+
+    counter!(HTTP_REQUESTS, &labels).increment(1);
+    histogram!(HTTP_REQUEST_DURATION, &labels).record(elapsed.as_secs_f64());
+}
+
+/// The domain counters, recorded by the use cases through `MissionMetrics`.
+pub struct PrometheusMissionMetrics;
+
+impl MissionMetrics for PrometheusMissionMetrics {
+    fn mission_created(&self) {
+        counter!(MISSIONS_CREATED).increment(1);
+    }
+
+    fn crew_joined(&self) {
+        counter!(CREW_JOINS).increment(1);
+    }
+
+    fn crew_left(&self) {
+        counter!(CREW_LEAVES).increment(1);
+    }
+
+    fn status_changed(&self, from: &str, to: &str) {
+        counter!(MISSION_STATUS_TRANSITIONS, "from" => from.to_string(), "to" => to.to_string()).increment(1);
+    }
+}
+
+/// Counts an open WebSocket for as long as it is alive. Mission chats are
+/// labelled with their mission; a closed mission's series stays behind at 0.
+pub struct WebSocketConnection {
+    gauge: metrics::Gauge,
+}
+
+impl WebSocketConnection {
+    pub fn mission_chat(mission_id: i32) -> Self {
+        Self::open(gauge!(
+            WEBSOCKET_CONNECTIONS,
+            "channel" => "mission_chat",
+            "mission_id" => mission_id.to_string()
+        ))
+    }
+
+    pub fn notifications() -> Self {
+        Self::open(gauge!(WEBSOCKET_CONNECTIONS, "channel" => "notifications"))
+    }
+
+    fn open(gauge: metrics::Gauge) -> Self {
+        gauge.increment(1);
+        Self { gauge }
+    }
+}
+
+impl Drop for WebSocketConnection {
+    fn drop(&mut self) {
+        self.gauge.decrement(1);
+    }
+}
@@ -2,4 +2,14 @@ pub mod database;
 pub mod http;
 pub mod argon2;
 pub mod jwt;
-pub mod cloudinary;
\ No newline at end of file
+pub mod cloudinary;
+pub mod notifications;
+pub mod request_context;
+pub mod telemetry;
+pub mod login_throttle;
+pub mod access_cache;
+pub mod logging;
+pub mod metrics;
+pub mod rate_limit;
+pub mod shutdown;
+
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use tokio::sync::broadcast;
+
+use crate::domain::{
+    repositories::notification_publisher::{NotificationPublisher, NotificationSubscription},
+    value_objects::notification_model::NotificationModel,
+};
+
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 32;
+
+/// In-process fan-out of notifications to every open connection of a brawler.
+#[derive(Default)]
+pub struct NotificationHub {
+    channels: Arc<DashMap<i32, broadcast::Sender<NotificationModel>>>,
+}
+
+impl NotificationHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl NotificationPublisher for NotificationHub {
+    fn publish(&self, brawler_id: i32, notification: NotificationModel) {
+        if let Some(sender) = self.channels.get(&brawler_id) {
+            let _ = sender.send(notification);
+        }
+    }
+
+    fn subscribe(&self, brawler_id: i32) -> NotificationSubscription {
+        let receiver = self
+            .channels
+            .entry(brawler_id)
+            .or_insert_with(|| broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY).0)
+            .subscribe();
+
+        // The last connection to close drops the channel
+        let channels = Arc::clone(&self.channels);
+        NotificationSubscription::new(receiver, move || {
+            channels.remove_if(&brawler_id, |_, sender| sender.receiver_count() == 0);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_is_dropped_with_the_last_subscription() {
+        let hub = NotificationHub::new();
+        let first = hub.subscribe(7);
+        let second = hub.subscribe(7);
+
+        drop(first);
+        assert!(hub.channels.contains_key(&7));
+
+        drop(second);
+        assert!(!hub.channels.contains_key(&7));
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use dashmap::DashMap;
+
+use crate::domain::{
+    repositories::rate_limit::RateLimitRepository,
+    value_objects::rate_limit::{RateLimitDecision, RateLimitPolicy},
+};
+
+/// Full buckets are swept once the map grows past this many keys.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    period: Duration,
+}
+
+/// Buckets kept in this process only; each server instance limits on its own.
+#[derive(Default)]
+pub struct RateLimitMemory {
+    buckets: DashMap<String, Bucket>,
+}
+
+impl RateLimitMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn take_at(&self, key: String, policy: RateLimitPolicy, now: Instant) -> RateLimitDecision {
+        // A bucket left alone for a whole period is full, same as a missing one
+        if self.buckets.len() > PRUNE_THRESHOLD {
+            self.buckets
+                .retain(|_, bucket| now.duration_since(bucket.updated_at) < bucket.period);
+        }
+
+        let mut bucket = self.buckets.entry(key).or_insert(Bucket {
+            tokens: f64::from(policy.capacity),
+            updated_at: now,
+            period: policy.period,
+        });
+
+        let (tokens, decision) = policy.take(bucket.tokens, now.duration_since(bucket.updated_at));
+        *bucket = Bucket {
+            tokens,
+            updated_at: now,
+            period: policy.period,
+        };
+
+        decision
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RateLimitMemory {
+    async fn take(&self, key: String, policy: RateLimitPolicy) -> Result<RateLimitDecision> {
+        Ok(self.take_at(key, policy, Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One token every 10 seconds, up to 3
+    const POLICY: RateLimitPolicy = RateLimitPolicy {
+        capacity: 3,
+        period: Duration::from_secs(30),
+    };
+
+    fn drain(store: &RateLimitMemory, now: Instant) {
+        for _ in 0..POLICY.capacity {
+            assert!(store.take_at("key".to_string(), POLICY, now).allowed);
+        }
+    }
+
+    #[test]
+    fn empty_bucket_is_rejected() {
+        let store = RateLimitMemory::new();
+        let now = Instant::now();
+        drain(&store, now);
+
+        let decision = store.take_at("key".to_string(), POLICY, now);
+
+        assert!(!decision.allowed);
+        assert_eq!(decision.remaining, 0);
+        assert_eq!(decision.retry_after, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn bucket_refills_over_elapsed_time() {
+        let store = RateLimitMemory::new();
+        let now = Instant::now();
+        drain(&store, now);
+
+        let later = now + Duration::from_secs(10);
+        assert!(store.take_at("key".to_string(), POLICY, later).allowed);
+        assert!(!store.take_at("key".to_string(), POLICY, later).allowed);
+    }
+
+    #[test]
+    fn refill_is_capped_at_burst() {
+        let store = RateLimitMemory::new();
+        let now = Instant::now();
+        drain(&store, now);
+
+        let much_later = now + Duration::from_secs(3_600);
+        drain(&store, much_later);
+        assert!(!store.take_at("key".to_string(), POLICY, much_later).allowed);
+    }
+
+    #[test]
+    fn keys_have_their_own_buckets() {
+        let store = RateLimitMemory::new();
+        let now = Instant::now();
+        drain(&store, now);
+
+        assert!(store.take_at("other".to_string(), POLICY, now).allowed);
+    }
+}
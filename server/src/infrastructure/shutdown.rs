@@ -0,0 +1,67 @@
+use std::future::Future;
+
+use axum::extract::ws::{CloseFrame, Message, close_code};
+use tokio::sync::watch;
+
+/// Set once a stop signal arrives. The server keeps answering during the drain
+/// period, but readiness fails and long-lived connections are told to go away.
+pub struct Shutdown {
+    draining: watch::Sender<bool>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self {
+            draining: watch::Sender::new(false),
+        }
+    }
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn begin_draining(&self) {
+        self.draining.send_replace(true);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        *self.draining.borrow()
+    }
+
+    /// Resolves once draining has begun, immediately if it already has.
+    pub fn draining(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.draining.subscribe();
+        async move {
+            // Only fails if `self` is gone, and then so is the server
+            let _ = receiver.wait_for(|draining| *draining).await;
+        }
+    }
+}
+
+/// Sent to open WebSockets when draining begins. 1012 tells clients the server
+/// is restarting, so they should reconnect after a short delay.
+pub fn restart_close_frame() -> Message {
+    Message::Close(Some(CloseFrame {
+        code: close_code::RESTART,
+        reason: "Server restarting, reconnect shortly".into(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn draining_resolves_for_earlier_and_later_waiters() {
+        let shutdown = Shutdown::new();
+        let earlier = shutdown.draining();
+        assert!(!shutdown.is_draining());
+
+        shutdown.begin_draining();
+        assert!(shutdown.is_draining());
+        earlier.await;
+        shutdown.draining().await;
+    }
+}
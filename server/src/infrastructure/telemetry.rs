@@ -0,0 +1,126 @@
+use anyhow::Result;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use diesel::connection::{Instrumentation, InstrumentationEvent, set_default_instrumentation};
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, propagation::TraceContextPropagator, trace::SdkTracerProvider};
+use tracing::{Span, field::Empty};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::config::config_model::Telemetry;
+
+const TRACES_PATH: &str = "/v1/traces";
+
+/// Sets up OTLP/HTTP span export, W3C trace context propagation and a span per
+/// Diesel query. `None` when no endpoint is configured, leaving all of it off.
+/// Must run before the database pool opens its connections.
+pub fn tracer_provider(telemetry: &Telemetry) -> Result<Option<SdkTracerProvider>> {
+    let Some(endpoint) = &telemetry.otlp_endpoint else {
+        return Ok(None);
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(traces_url(endpoint))
+        .build()?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(telemetry.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    set_default_instrumentation(|| Some(Box::new(QueryTracing::default())))?;
+
+    Ok(Some(provider))
+}
+
+/// The collector's base URL gets the traces path, like the exporter does for
+/// `OTEL_EXPORTER_OTLP_ENDPOINT` itself.
+fn traces_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    if endpoint.ends_with(TRACES_PATH) {
+        endpoint.to_string()
+    } else {
+        format!("{}{}", endpoint, TRACES_PATH)
+    }
+}
+
+/// Continues the trace of an incoming `traceparent` header, if there is one.
+pub fn set_remote_parent(span: &Span, headers: &HeaderMap) {
+    let parent = global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)));
+    // Fails only when export is off, in which case there is nothing to continue
+    let _ = span.set_parent(parent);
+}
+
+/// `traceparent` for an outgoing request made within the current span.
+pub fn trace_context_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(&mut headers))
+    });
+
+    headers
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(key), HeaderValue::try_from(value)) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+/// Opens a span when a connection starts a query and closes it when the query
+/// finishes. Bind values are left out, they may hold password hashes.
+#[derive(Default)]
+struct QueryTracing {
+    query: Option<Span>,
+}
+
+impl Instrumentation for QueryTracing {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartQuery { query, .. } => {
+                let statement = query.to_string();
+                let statement = statement.split(" -- binds:").next().unwrap_or_default();
+                self.query = Some(tracing::info_span!(
+                    "db.query",
+                    otel.kind = "client",
+                    otel.status_code = Empty,
+                    db.system.name = "postgresql",
+                    db.query.text = statement,
+                    error.message = Empty,
+                ));
+            }
+            InstrumentationEvent::FinishQuery { error, .. } => {
+                if let (Some(span), Some(error)) = (self.query.take(), error) {
+                    span.record("otel.status_code", "ERROR");
+                    span.record("error.message", tracing::field::display(error));
+                }
+            }
+            _ => {}
+        }
+    }
+}
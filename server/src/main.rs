@@ -1,42 +1,121 @@
 use std::sync::Arc;
 
-use server::{config::config_loader::load, infrastructure::{database::postgresql_connection, http::http_serv::start}};
-use tracing::{Level, info, error};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use server::{
+    config::{config_loader::load, config_model::DotEnvyConfig},
+    infrastructure::{
+        cloudinary::CloudinaryStorage,
+        database::postgresql_connection::{self, PgPoolSquad},
+        logging,
+        telemetry,
+        http::{
+            app_state::{AppState, Repositories},
+            http_serv::start,
+        },
+    },
+};
+use tracing::{error, info, warn};
 
+#[derive(Parser)]
+#[command(name = "server", version, about = "The GangBro API server")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Serve the API; what runs without a command
+    Serve,
+    /// Apply pending migrations and exit, for deployment pipelines
+    Migrate,
+}
 
 #[tokio::main]
 async fn main() {
-   
-    tracing_subscriber::fmt()
-        .with_max_level(Level::DEBUG)
-        .init();
+    let cli = Cli::parse();
 
     let dotenvy_env = match load() {
         Ok(env) => env,
         Err(e) => {
-            tracing::error!("Failed to load .env file: {}", e);
+            // Logging is configured by what failed to load
+            tracing_subscriber::fmt().init();
+            error!("Failed to load configuration: {}", e);
             std::process::exit(1);
         }
     };
 
-    info!("YESSSSS");         
+    // Logging has to be up to report a failure here
+    let (tracer_provider, telemetry_error) = match telemetry::tracer_provider(&dotenvy_env.telemetry) {
+        Ok(tracer_provider) => (tracer_provider, None),
+        Err(e) => (None, Some(e)),
+    };
+    logging::init(&dotenvy_env.logging, tracer_provider.as_ref());
+    if let Some(e) = telemetry_error {
+        warn!(error = %e, "Trace export is disabled");
+    }
 
-    let postgres_pool = match postgresql_connection::establish_connection(&dotenvy_env.database.url)
-    {
-        Ok(pool) => pool,
-        Err(e) => {
-            error!("Failed to establish connection to Postgres: {}", e);
-            std::process::exit(1);
-        }
+    let result = match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(dotenvy_env).await,
+        Command::Migrate => migrate(&dotenvy_env),
     };
+    if let Err(e) = &result {
+        error!(error = format!("{:#}", e), "Server failed");
+    }
+
+    // Flushes spans still waiting in the batch, including the shutdown's own
+    if let Some(tracer_provider) = tracer_provider {
+        let _ = tracer_provider.shutdown();
+    }
 
+    if result.is_err() {
+        std::process::exit(1);
+    }
+}
+
+async fn serve(dotenvy_env: DotEnvyConfig) -> Result<()> {
+    let postgres_pool = postgresql_connection::connection_pool(&dotenvy_env.database)
+        .context("Failed to establish connection to Postgres")?;
     info!("Connect to the PostgreSQL database successfully.");
 
-// Use MissionEntity from server::domain::entities::missions; no local definition needed here.
+    if dotenvy_env.database.run_migrations {
+        run_migrations(&postgres_pool)?;
+    } else {
+        // Readiness keeps failing until someone runs them
+        let mut conn = postgres_pool.get().context("Failed to get connection")?;
+        let pending = postgresql_connection::pending_migrations(&mut conn)?;
+        if pending > 0 {
+            warn!(pending, "Schema is behind, not ready until `server migrate` has run");
+        }
+    }
 
+    let config = Arc::new(dotenvy_env);
+    let db_pool = Arc::new(postgres_pool);
+    let repositories = Repositories::new(&db_pool, &config);
+    let image_storage = Arc::new(CloudinaryStorage::new(config.cloudinary.clone()));
+    let app_state = AppState::new(config, db_pool, repositories, image_storage);
 
+    start(Arc::new(app_state)).await
+}
+
+fn migrate(dotenvy_env: &DotEnvyConfig) -> Result<()> {
+    let postgres_pool = postgresql_connection::connection_pool(&dotenvy_env.database)
+        .context("Failed to establish connection to Postgres")?;
+
+    run_migrations(&postgres_pool)
+}
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool)).await.expect("Failed to start the server");
+fn run_migrations(postgres_pool: &PgPoolSquad) -> Result<()> {
+    let mut conn = postgres_pool.get().context("Failed to get connection for migrations")?;
+    let applied = postgresql_connection::run_migrations(&mut conn)?;
 
+    if applied.is_empty() {
+        info!("Schema is up to date");
+    }
+    for migration in applied {
+        info!(migration, "Applied migration");
+    }
 
+    Ok(())
 }
@@ -0,0 +1,32 @@
+mod common;
+
+use reqwest::StatusCode;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn profile_lists_the_badges_a_brawler_earned() {
+    let app = TestApp::spawn().await;
+
+    let (chief_id, chief) = app.register("chief").await;
+    let (member_id, _) = app.register("member").await;
+    // The chief joins their own mission as crew
+    app.create_mission(&chief, "First outing").await;
+
+    let (status, profile) = app.get(&format!("/brawlers/{}", chief_id), None).await;
+    assert_eq!(status, StatusCode::OK, "profile failed: {}", profile);
+    assert_eq!(profile["display_name"], "chief");
+    assert_eq!(profile["role"], "member");
+    assert_eq!(profile["achievements"]["progress"]["missions_joined"], 1);
+    let codes = profile["achievements"]["achievements"]
+        .as_array()
+        .expect("achievements are not a list")
+        .iter()
+        .map(|achievement| achievement["code"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["first_mission_joined"]);
+
+    let (status, profile) = app.get(&format!("/brawlers/{}", member_id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(profile["achievements"]["achievements"], serde_json::json!([]));
+}
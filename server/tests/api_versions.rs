@@ -0,0 +1,56 @@
+mod common;
+
+use reqwest::{StatusCode, header::LINK};
+
+use common::TestApp;
+
+#[tokio::test]
+async fn unversioned_routes_are_deprecated_aliases_of_v1() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let mission_id = app.create_mission(&chief, "Old habits").await;
+
+    let legacy = app
+        .client
+        .get(format!("{}/api/view/{}", app.origin, mission_id))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(legacy.status(), StatusCode::OK);
+    assert!(legacy.headers()["deprecation"].to_str().unwrap().starts_with('@'));
+    assert!(legacy.headers().contains_key("sunset"));
+    assert_eq!(legacy.headers()[LINK], "</api/v1>; rel=\"successor-version\"");
+    let legacy_mission = legacy.text().await.expect("cannot read body");
+
+    let current = app
+        .client
+        .get(format!("{}/missions/{}", app.base_url, mission_id))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(current.status(), StatusCode::OK);
+    assert!(!current.headers().contains_key("deprecation"));
+    assert!(!current.headers().contains_key("sunset"));
+    let mission = current.text().await.expect("cannot read body");
+
+    assert_eq!(legacy_mission, mission);
+}
+
+#[tokio::test]
+async fn routes_added_after_v1_have_no_unversioned_alias() {
+    let app = TestApp::spawn().await;
+
+    for path in ["/categories", "/achievements"] {
+        let (status, _) = app.get(path, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", path);
+
+        let legacy = app
+            .client
+            .get(format!("{}/api{}", app.origin, path))
+            .send()
+            .await
+            .expect("request failed");
+        assert_eq!(legacy.status(), StatusCode::NOT_FOUND, "{}", path);
+    }
+}
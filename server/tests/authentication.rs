@@ -0,0 +1,138 @@
+mod common;
+
+use reqwest::StatusCode;
+use serde_json::json;
+
+use common::{PASSWORD, TestApp, passport};
+
+#[tokio::test]
+async fn registered_brawler_can_log_in() {
+    let app = TestApp::spawn().await;
+
+    let (registered_id, _) = app.register("rookie").await;
+
+    let (status, body) = app
+        .post(
+            "/auth/login",
+            None,
+            json!({ "username": "rookie", "password": PASSWORD }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let (user_id, token) = passport(&body);
+    assert_eq!(user_id, registered_id);
+    assert_eq!(body["role"], "member");
+
+    let (status, _) = app.get("/brawlers/me/missions", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn username_is_unique() {
+    let app = TestApp::spawn().await;
+
+    app.register("rookie").await;
+    let (status, _) = app
+        .post(
+            "/brawlers",
+            None,
+            json!({ "username": "rookie", "password": PASSWORD, "display_name": "Other" }),
+        )
+        .await;
+    assert!(!status.is_success());
+}
+
+#[tokio::test]
+async fn wrong_password_and_unknown_user_fail_alike() {
+    let app = TestApp::spawn().await;
+
+    app.register("rookie").await;
+
+    let (wrong_status, wrong_body) = app
+        .post(
+            "/auth/login",
+            None,
+            json!({ "username": "rookie", "password": "not-the-password" }),
+        )
+        .await;
+    let (unknown_status, unknown_body) = app
+        .post(
+            "/auth/login",
+            None,
+            json!({ "username": "nobody", "password": PASSWORD }),
+        )
+        .await;
+
+    assert_eq!(wrong_status, StatusCode::BAD_REQUEST);
+    assert_eq!(wrong_status, unknown_status);
+    assert_eq!(wrong_body["error"], unknown_body["error"]);
+}
+
+#[tokio::test]
+async fn protected_routes_need_a_valid_token() {
+    let app = TestApp::spawn().await;
+
+    let (status, _) = app.get("/brawlers/me/missions", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = app.get("/brawlers/me/missions", Some("not-a-jwt")).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn banning_revokes_existing_tokens() {
+    let app = TestApp::spawn().await;
+
+    let (admin_id, admin_token) = app.register("warden").await;
+    app.set_role(admin_id, "admin").await;
+    let (rookie_id, rookie_token) = app.register("rookie").await;
+
+    let (status, _) = app.get("/brawlers/me/missions", Some(&rookie_token)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = app
+        .post(
+            &format!("/admin/brawlers/{}/ban", rookie_id),
+            Some(&admin_token),
+            json!({ "reason": "spam" }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "ban failed: {}", body);
+
+    let (status, body) = app.get("/brawlers/me/missions", Some(&rookie_token)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "This brawler has been banned");
+
+    let (status, _) = app
+        .delete(&format!("/admin/brawlers/{}/ban", rookie_id), &admin_token)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = app.get("/brawlers/me/missions", Some(&rookie_token)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn role_changes_apply_without_logging_in_again() {
+    let app = TestApp::spawn().await;
+
+    let (admin_id, admin_token) = app.register("warden").await;
+    app.set_role(admin_id, "admin").await;
+    let (rookie_id, rookie_token) = app.register("rookie").await;
+
+    let (status, _) = app.delete("/admin/chats/1", &rookie_token).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = app
+        .request(
+            reqwest::Method::PATCH,
+            &format!("/admin/brawlers/{}/role", rookie_id),
+            Some(&admin_token),
+            Some(json!({ "role": "moderator" })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "role change failed: {}", body);
+
+    let (status, _) = app.delete("/admin/chats/1", &rookie_token).await;
+    assert_ne!(status, StatusCode::FORBIDDEN);
+}
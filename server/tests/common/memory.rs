@@ -0,0 +1,1125 @@
+//! In-memory stand-ins for the Postgres repositories, so the HTTP tests run
+//! with a plain `cargo test`. One `MemoryStore` backs every repository of a
+//! `TestApp` and mirrors the constraints and joins of the SQL it replaces;
+//! full-text search and keyset cursors are reduced to substring matches and
+//! page offsets.
+
+use std::{
+    cmp::Ordering,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use server::{
+    domain::{
+        entities::{
+            audit_events::{AddAuditEventEntity, AuditEventEntity},
+            brawler_achievements::{AwardAchievementEntity, BrawlerAchievementEntity},
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+            categories::{AddCategoryEntity, CategoryEntity},
+            crew_memberships::CrewMembershipEntity,
+            mission_feedbacks::AddMissionFeedbackEntity,
+            missions::{AddMissionEntity, EditMissionEntity, MissionEntity},
+        },
+        repositories::{
+            achievement::AchievementRepository, audit::AuditRepository,
+            brawlers::BrawlerRepository, category::CategoryRepository,
+            crew_operation::CrewOperationRepository, health::HealthRepository,
+            maintenance::MaintenanceRepository, mission_chat::MissionChatRepository,
+            mission_feedback::MissionFeedbackRepository,
+            mission_management::MissionManagementRepository,
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository, moderation::ModerationRepository,
+            system::SystemRepository,
+        },
+        value_objects::{
+            achievement_model::AchievementProgressModel,
+            audit_model::{AuditEventFilter, AuditEventModel},
+            brawler_model::BrawlerModel,
+            category_model::CategoryModel,
+            mission_chat_model::MissionChatModel,
+            mission_cursor::MissionCursor,
+            mission_feedback_model::{BrawlerRatingModel, MissionFeedbackModel},
+            mission_filter::{MissionFilter, MissionSort, SortDirection},
+            mission_model::MissionModel,
+            mission_statuses::MissionStatuses,
+            roles::Role,
+            system_stats::SystemStatsModel,
+            uploaded_image::UploadedImage,
+        },
+    },
+    infrastructure::{http::app_state::Repositories, rate_limit::RateLimitMemory},
+};
+
+/// The categories the `create_categories` migration seeds.
+const SEEDED_CATEGORIES: [(&str, &str); 8] = [
+    ("general", "General"),
+    ("gaming", "Gaming"),
+    ("work", "Work"),
+    ("fitness", "Fitness"),
+    ("social", "Social"),
+    ("learning", "Learning"),
+    ("creative", "Creative"),
+    ("finance", "Finance"),
+];
+
+#[derive(Default)]
+struct Tables {
+    last_id: i32,
+    brawlers: Vec<BrawlerEntity>,
+    categories: Vec<CategoryEntity>,
+    missions: Vec<MissionEntity>,
+    mission_tags: Vec<(i32, String)>,
+    crew_memberships: Vec<CrewMembershipEntity>,
+    mission_chats: Vec<MissionChatModel>,
+    mission_feedbacks: Vec<MissionFeedbackModel>,
+    brawler_achievements: Vec<BrawlerAchievementEntity>,
+    audit_events: Vec<AuditEventEntity>,
+}
+
+impl Tables {
+    /// Ids come from one sequence shared by every table.
+    fn next_id(&mut self) -> i32 {
+        self.last_id += 1;
+        self.last_id
+    }
+
+    fn brawler(&self, brawler_id: i32) -> Result<&BrawlerEntity> {
+        self.brawlers
+            .iter()
+            .find(|brawler| brawler.id == brawler_id)
+            .ok_or_else(|| anyhow!("Record not found"))
+    }
+
+    fn brawler_mut(&mut self, brawler_id: i32) -> Option<&mut BrawlerEntity> {
+        self.brawlers
+            .iter_mut()
+            .find(|brawler| brawler.id == brawler_id)
+    }
+
+    fn live_mission(&self, mission_id: i32) -> Result<&MissionEntity> {
+        self.missions
+            .iter()
+            .find(|mission| mission.id == mission_id && mission.deleted_at.is_none())
+            .ok_or_else(|| anyhow!("Record not found"))
+    }
+
+    fn live_mission_mut(&mut self, mission_id: i32) -> Result<&mut MissionEntity> {
+        self.missions
+            .iter_mut()
+            .find(|mission| mission.id == mission_id && mission.deleted_at.is_none())
+            .ok_or_else(|| anyhow!("Record not found"))
+    }
+
+    fn crew_of(&self, mission_id: i32) -> Vec<i32> {
+        self.crew_memberships
+            .iter()
+            .filter(|membership| membership.mission_id == mission_id)
+            .map(|membership| membership.brawler_id)
+            .collect()
+    }
+
+    fn tags_of(&self, mission_id: i32) -> Vec<String> {
+        let mut tags = self
+            .mission_tags
+            .iter()
+            .filter(|(id, _)| *id == mission_id)
+            .map(|(_, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags
+    }
+
+    /// The chief always took part, even if the automatic crew join failed.
+    fn participants(&self, mission: &MissionEntity) -> Vec<i32> {
+        let mut participants = self.crew_of(mission.id);
+        if !participants.contains(&mission.chief_id) {
+            participants.push(mission.chief_id);
+        }
+        participants
+    }
+
+    fn display_name(&self, brawler_id: i32) -> String {
+        self.brawler(brawler_id)
+            .map(|brawler| brawler.display_name.clone())
+            .unwrap_or_default()
+    }
+
+    fn to_model(&self, mission: &MissionEntity) -> MissionModel {
+        let category_label = self
+            .categories
+            .iter()
+            .find(|category| category.slug == mission.category)
+            .map(|category| category.label.clone())
+            .unwrap_or_default();
+
+        mission.to_model(
+            self.crew_of(mission.id).len() as i64,
+            self.display_name(mission.chief_id),
+            category_label,
+            self.tags_of(mission.id),
+        )
+    }
+
+    fn matches(&self, mission: &MissionEntity, filter: &MissionFilter, max_crew: i64) -> Result<bool> {
+        if mission.deleted_at.is_some() {
+            return Ok(false);
+        }
+        if filter.status_list()?.is_some_and(|statuses| {
+            !statuses.iter().any(|status| status.to_string() == mission.status)
+        }) {
+            return Ok(false);
+        }
+        if filter
+            .name
+            .as_ref()
+            .is_some_and(|name| !contains_ignore_case(&mission.name, name))
+        {
+            return Ok(false);
+        }
+        if filter.exclude_chief_id == Some(mission.chief_id)
+            || filter.chief_id.is_some_and(|chief_id| chief_id != mission.chief_id)
+        {
+            return Ok(false);
+        }
+        if let Some(category) = &filter.category {
+            let label = self
+                .categories
+                .iter()
+                .find(|c| c.slug == mission.category)
+                .map(|c| c.label.to_lowercase());
+            if mission.category != category.to_lowercase()
+                && label.as_deref() != Some(&category.to_lowercase())
+            {
+                return Ok(false);
+            }
+        }
+        let tags = self.tags_of(mission.id);
+        if filter.search_query().is_some_and(|q| {
+            !contains_ignore_case(&mission.name, &q)
+                && !tags.iter().any(|tag| contains_ignore_case(tag, &q))
+        }) {
+            return Ok(false);
+        }
+        if filter
+            .tag_list()
+            .is_some_and(|wanted| !wanted.iter().all(|tag| tags.contains(tag)))
+        {
+            return Ok(false);
+        }
+        let crew = self.crew_of(mission.id);
+        if filter
+            .member_id
+            .is_some_and(|member_id| !crew.contains(&member_id))
+        {
+            return Ok(false);
+        }
+        if let Some(has_open_slots) = filter.has_open_slots {
+            let open = mission.status == MissionStatuses::Open.to_string()
+                && (crew.len() as i64) < max_crew;
+            if open != has_open_slots {
+                return Ok(false);
+            }
+        }
+        let created = mission.created_at.date();
+        if filter.created_from.is_some_and(|from| created < from)
+            || filter.created_to.is_some_and(|to| created > to)
+        {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+fn now() -> NaiveDateTime {
+    Utc::now().naive_utc()
+}
+
+/// Finished missions trail active ones, as in the SQL listing.
+fn sort_bucket(mission: &MissionModel) -> i32 {
+    match mission.status.as_str() {
+        "Completed" | "Failed" => 1,
+        _ => 0,
+    }
+}
+
+fn compare(left: &MissionModel, right: &MissionModel, sort: &MissionSort) -> Ordering {
+    match sort {
+        MissionSort::Relevance | MissionSort::CreatedAt => left.created_at.cmp(&right.created_at),
+        MissionSort::UpdatedAt => left.updated_at.cmp(&right.updated_at),
+        MissionSort::Name => left.name.to_lowercase().cmp(&right.name.to_lowercase()),
+        MissionSort::CrewCount => left.crew_count.cmp(&right.crew_count),
+        MissionSort::EndsAt => match (left.ends_at, right.ends_at) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+    }
+    .then(left.id.cmp(&right.id))
+}
+
+pub struct MemoryStore {
+    tables: Mutex<Tables>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        let categories = SEEDED_CATEGORIES
+            .iter()
+            .map(|(slug, label)| CategoryEntity {
+                slug: slug.to_string(),
+                label: label.to_string(),
+                description: None,
+                icon: None,
+                created_at: now(),
+                retired_at: None,
+            })
+            .collect();
+
+        Self {
+            tables: Mutex::new(Tables {
+                categories,
+                ..Tables::default()
+            }),
+        }
+    }
+
+    /// Every repository of the app, all reading and writing this store.
+    pub fn repositories(self: &Arc<Self>) -> Repositories {
+        Repositories {
+            achievement: Arc::clone(self) as _,
+            audit: Arc::clone(self) as _,
+            brawler: Arc::clone(self) as _,
+            category: Arc::clone(self) as _,
+            crew_operation: Arc::clone(self) as _,
+            health: Arc::clone(self) as _,
+            maintenance: Arc::clone(self) as _,
+            mission_chat: Arc::clone(self) as _,
+            mission_feedback: Arc::clone(self) as _,
+            mission_management: Arc::clone(self) as _,
+            mission_operation: Arc::clone(self) as _,
+            mission_viewing: Arc::clone(self) as _,
+            moderation: Arc::clone(self) as _,
+            rate_limit: Arc::new(RateLimitMemory::new()),
+            system: Arc::clone(self) as _,
+        }
+    }
+
+    fn tables(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().expect("memory store poisoned")
+    }
+
+    /// Rejects a taken username, as the unique index on `brawlers.username` does.
+    fn insert_brawler(&self, entity: RegisterBrawlerEntity, role: Role) -> Result<i32> {
+        let mut tables = self.tables();
+        if tables
+            .brawlers
+            .iter()
+            .any(|brawler| brawler.username == entity.username)
+        {
+            return Err(anyhow!(
+                "duplicate key value violates unique constraint \"brawlers_username_key\""
+            ));
+        }
+
+        let id = tables.next_id();
+        tables.brawlers.push(BrawlerEntity {
+            id,
+            username: entity.username,
+            password: entity.password,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+            display_name: entity.display_name,
+            avatar_url: None,
+            avatar_public_id: None,
+            role: role.to_string(),
+            banned_at: None,
+            ban_reason: None,
+        });
+
+        Ok(id)
+    }
+
+    fn set_mission_status(&self, mission_id: i32, chief_id: i32, status: MissionStatuses) -> Result<i32> {
+        let mut tables = self.tables();
+        let mission = tables
+            .live_mission_mut(mission_id)
+            .ok()
+            .filter(|mission| mission.chief_id == chief_id)
+            .ok_or_else(|| anyhow!("Failed to execute mission update query"))?;
+        mission.finished_at = matches!(status, MissionStatuses::Completed | MissionStatuses::Failed)
+            .then(now);
+        mission.status = status.to_string();
+
+        Ok(mission.id)
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for MemoryStore {
+    async fn get_progress(&self, brawler_id: i32) -> Result<AchievementProgressModel> {
+        let tables = self.tables();
+        let completed = MissionStatuses::Completed.to_string();
+        let joined = tables
+            .missions
+            .iter()
+            .filter(|mission| mission.deleted_at.is_none())
+            .filter(|mission| tables.crew_of(mission.id).contains(&brawler_id))
+            .collect::<Vec<_>>();
+
+        let mut categories = joined
+            .iter()
+            .map(|mission| mission.category.clone())
+            .collect::<Vec<_>>();
+        categories.sort();
+        categories.dedup();
+
+        Ok(AchievementProgressModel {
+            missions_led: tables
+                .missions
+                .iter()
+                .filter(|mission| mission.deleted_at.is_none())
+                .filter(|mission| mission.chief_id == brawler_id && mission.status == completed)
+                .count() as i64,
+            missions_completed: joined
+                .iter()
+                .filter(|mission| mission.status == completed)
+                .count() as i64,
+            missions_joined: joined.len() as i64,
+            categories_explored: categories.len() as i64,
+        })
+    }
+
+    async fn get_awarded(&self, brawler_id: i32) -> Result<Vec<BrawlerAchievementEntity>> {
+        Ok(self
+            .tables()
+            .brawler_achievements
+            .iter()
+            .filter(|achievement| achievement.brawler_id == brawler_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn award(
+        &self,
+        award_achievement_entities: Vec<AwardAchievementEntity>,
+    ) -> Result<Vec<BrawlerAchievementEntity>> {
+        let mut tables = self.tables();
+        let mut awarded = Vec::new();
+
+        for entity in award_achievement_entities {
+            let exists = tables.brawler_achievements.iter().any(|achievement| {
+                achievement.brawler_id == entity.brawler_id
+                    && achievement.achievement_code == entity.achievement_code
+            });
+            if exists {
+                continue;
+            }
+
+            let achievement = BrawlerAchievementEntity {
+                brawler_id: entity.brawler_id,
+                achievement_code: entity.achievement_code,
+                awarded_at: now(),
+            };
+            tables.brawler_achievements.push(achievement.clone());
+            awarded.push(achievement);
+        }
+
+        Ok(awarded)
+    }
+
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let tables = self.tables();
+        let mission = tables
+            .missions
+            .iter()
+            .find(|mission| mission.id == mission_id)
+            .ok_or_else(|| anyhow!("Record not found"))?;
+
+        Ok(tables.participants(mission))
+    }
+}
+
+#[async_trait]
+impl AuditRepository for MemoryStore {
+    async fn record(&self, add_audit_event_entity: AddAuditEventEntity) -> Result<()> {
+        let mut tables = self.tables();
+        let id = tables.next_id();
+        tables.audit_events.push(AuditEventEntity {
+            id,
+            actor_id: add_audit_event_entity.actor_id,
+            action: add_audit_event_entity.action,
+            target_type: add_audit_event_entity.target_type,
+            target_id: add_audit_event_entity.target_id,
+            details: add_audit_event_entity.details,
+            before: add_audit_event_entity.before,
+            after: add_audit_event_entity.after,
+            request_id: add_audit_event_entity.request_id,
+            created_at: now(),
+        });
+
+        Ok(())
+    }
+
+    async fn query(&self, filter: &AuditEventFilter) -> Result<Vec<AuditEventModel>> {
+        let tables = self.tables();
+        let limit = filter.limit();
+
+        Ok(tables
+            .audit_events
+            .iter()
+            .rev()
+            .filter(|event| filter.actor_id.is_none_or(|actor_id| event.actor_id == actor_id))
+            .filter(|event| filter.action.as_ref().is_none_or(|action| &event.action == action))
+            .filter(|event| {
+                filter
+                    .target_type
+                    .as_ref()
+                    .is_none_or(|target_type| &event.target_type == target_type)
+            })
+            .filter(|event| filter.target_id.is_none_or(|target_id| event.target_id == target_id))
+            .filter(|event| filter.from.is_none_or(|from| event.created_at >= from))
+            .filter(|event| filter.to.is_none_or(|to| event.created_at < to))
+            .skip(((filter.page() - 1) * limit) as usize)
+            .take(limit as usize)
+            .map(|event| event.to_model(tables.display_name(event.actor_id)))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl BrawlerRepository for MemoryStore {
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
+        self.insert_brawler(register_brawler_entity, Role::Member)
+    }
+
+    async fn find_by_username(&self, username: String) -> Result<Option<BrawlerEntity>> {
+        Ok(self
+            .tables()
+            .brawlers
+            .iter()
+            .find(|brawler| brawler.username == username)
+            .cloned())
+    }
+
+    async fn find_by_id(&self, brawler_id: i32) -> Result<Option<BrawlerEntity>> {
+        Ok(self.tables().brawler(brawler_id).ok().cloned())
+    }
+
+    async fn update_avatar(&self, user_id: i32, uploaded_image: UploadedImage) -> Result<()> {
+        if let Some(brawler) = self.tables().brawler_mut(user_id) {
+            brawler.avatar_url = Some(uploaded_image.url);
+            brawler.avatar_public_id = Some(uploaded_image.public_id);
+        }
+
+        Ok(())
+    }
+
+    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+        let tables = self.tables();
+
+        Ok(tables
+            .missions
+            .iter()
+            .rev()
+            .filter(|mission| mission.deleted_at.is_none())
+            .filter(|mission| {
+                mission.chief_id == brawler_id || tables.crew_of(mission.id).contains(&brawler_id)
+            })
+            .map(|mission| tables.to_model(mission))
+            .collect())
+    }
+
+    async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
+        Ok(self.tables().crew_of(mission_id).len() as u32)
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for MemoryStore {
+    async fn get_all(&self) -> Result<Vec<CategoryModel>> {
+        let tables = self.tables();
+        let active = [
+            MissionStatuses::Open.to_string(),
+            MissionStatuses::InProgress.to_string(),
+        ];
+
+        let mut categories = tables
+            .categories
+            .iter()
+            .filter(|category| category.retired_at.is_none())
+            .map(|category| CategoryModel {
+                slug: category.slug.clone(),
+                label: category.label.clone(),
+                description: category.description.clone(),
+                icon: category.icon.clone(),
+                active_mission_count: tables
+                    .missions
+                    .iter()
+                    .filter(|mission| mission.category == category.slug)
+                    .filter(|mission| mission.deleted_at.is_none())
+                    .filter(|mission| active.contains(&mission.status))
+                    .count() as i64,
+            })
+            .collect::<Vec<_>>();
+        categories.sort_by(|left, right| left.label.cmp(&right.label));
+
+        Ok(categories)
+    }
+
+    async fn find_active(&self, slug_or_label: String) -> Result<Option<CategoryEntity>> {
+        let wanted = slug_or_label.to_lowercase();
+
+        Ok(self
+            .tables()
+            .categories
+            .iter()
+            .filter(|category| category.retired_at.is_none())
+            .find(|category| category.slug == wanted || category.label.to_lowercase() == wanted)
+            .cloned())
+    }
+
+    async fn add(&self, add_category_entity: AddCategoryEntity) -> Result<String> {
+        let mut tables = self.tables();
+        if tables
+            .categories
+            .iter()
+            .any(|category| category.slug == add_category_entity.slug)
+        {
+            return Err(anyhow!(
+                "duplicate key value violates unique constraint \"categories_pkey\""
+            ));
+        }
+
+        tables.categories.push(CategoryEntity {
+            slug: add_category_entity.slug.clone(),
+            label: add_category_entity.label,
+            description: add_category_entity.description,
+            icon: add_category_entity.icon,
+            created_at: now(),
+            retired_at: None,
+        });
+
+        Ok(add_category_entity.slug)
+    }
+
+    async fn retire(&self, slug: String) -> Result<()> {
+        let mut tables = self.tables();
+        let category = tables
+            .categories
+            .iter_mut()
+            .find(|category| category.slug == slug && category.retired_at.is_none())
+            .ok_or_else(|| anyhow!("Category not found or already retired"))?;
+        category.retired_at = Some(now());
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CrewOperationRepository for MemoryStore {
+    async fn join(&self, crew_member_ships: CrewMembershipEntity, _allow_bypass: bool) -> Result<()> {
+        let mut tables = self.tables();
+        let exists = tables.crew_memberships.iter().any(|membership| {
+            membership.mission_id == crew_member_ships.mission_id
+                && membership.brawler_id == crew_member_ships.brawler_id
+        });
+        if exists {
+            return Err(anyhow!(
+                "duplicate key value violates unique constraint \"crew_memberships_pkey\""
+            ));
+        }
+
+        tables.crew_memberships.push(crew_member_ships);
+
+        Ok(())
+    }
+
+    async fn leave(&self, crew_member_ships: CrewMembershipEntity) -> Result<()> {
+        self.tables().crew_memberships.retain(|membership| {
+            membership.mission_id != crew_member_ships.mission_id
+                || membership.brawler_id != crew_member_ships.brawler_id
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HealthRepository for MemoryStore {
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn pending_migrations(&self) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+#[async_trait]
+impl MaintenanceRepository for MemoryStore {
+    async fn register_with_role(&self, register_brawler_entity: RegisterBrawlerEntity, role: Role) -> Result<i32> {
+        self.insert_brawler(register_brawler_entity, role)
+    }
+
+    async fn set_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        if let Some(brawler) = self.tables().brawler_mut(brawler_id) {
+            brawler.password = hashed_password;
+        }
+
+        Ok(())
+    }
+
+    async fn brawler_ids(&self) -> Result<Vec<i32>> {
+        Ok(self.tables().brawlers.iter().map(|brawler| brawler.id).collect())
+    }
+
+    async fn purge_deleted_missions(&self, deleted_before: NaiveDateTime) -> Result<Vec<i32>> {
+        let mut tables = self.tables();
+        let purged = tables
+            .missions
+            .iter()
+            .filter(|mission| mission.deleted_at.is_some_and(|deleted_at| deleted_at < deleted_before))
+            .map(|mission| mission.id)
+            .collect::<Vec<_>>();
+
+        tables.missions.retain(|mission| !purged.contains(&mission.id));
+        tables.mission_tags.retain(|(id, _)| !purged.contains(id));
+        tables
+            .crew_memberships
+            .retain(|membership| !purged.contains(&membership.mission_id));
+        tables
+            .mission_chats
+            .retain(|chat| !purged.contains(&chat.mission_id));
+        tables
+            .mission_feedbacks
+            .retain(|feedback| !purged.contains(&feedback.mission_id));
+
+        Ok(purged)
+    }
+}
+
+#[async_trait]
+impl MissionChatRepository for MemoryStore {
+    async fn add_message(&self, mission_id: i32, brawler_id: i32, message: &str) -> Result<MissionChatModel> {
+        let mut tables = self.tables();
+        let brawler_name = tables.brawler(brawler_id)?.display_name.clone();
+        let chat = MissionChatModel {
+            id: tables.next_id(),
+            mission_id,
+            brawler_id,
+            brawler_name,
+            message: message.to_string(),
+            created_at: now(),
+        };
+        tables.mission_chats.push(chat.clone());
+
+        Ok(chat)
+    }
+
+    async fn get_messages(&self, mission_id: i32) -> Result<Vec<MissionChatModel>> {
+        Ok(self
+            .tables()
+            .mission_chats
+            .iter()
+            .filter(|chat| chat.mission_id == mission_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_messages(&self, mission_id: i32) -> Result<()> {
+        self.tables()
+            .mission_chats
+            .retain(|chat| chat.mission_id != mission_id);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MissionFeedbackRepository for MemoryStore {
+    async fn add(&self, add_mission_feedback_entity: AddMissionFeedbackEntity) -> Result<i32> {
+        let mut tables = self.tables();
+        let id = tables.next_id();
+        let feedback = MissionFeedbackModel {
+            id,
+            mission_id: add_mission_feedback_entity.mission_id,
+            rater_id: add_mission_feedback_entity.rater_id,
+            rater_display_name: tables.display_name(add_mission_feedback_entity.rater_id),
+            ratee_id: add_mission_feedback_entity.ratee_id,
+            ratee_display_name: tables.display_name(add_mission_feedback_entity.ratee_id),
+            rating: add_mission_feedback_entity.rating,
+            comment: add_mission_feedback_entity.comment,
+            created_at: now(),
+        };
+        tables.mission_feedbacks.push(feedback);
+
+        Ok(id)
+    }
+
+    async fn has_rated(&self, mission_id: i32, rater_id: i32, ratee_id: i32) -> Result<bool> {
+        Ok(self.tables().mission_feedbacks.iter().any(|feedback| {
+            feedback.mission_id == mission_id
+                && feedback.rater_id == rater_id
+                && feedback.ratee_id == ratee_id
+        }))
+    }
+
+    async fn get_participants(&self, mission_id: i32) -> Result<Vec<i32>> {
+        let tables = self.tables();
+        let mission = tables.live_mission(mission_id)?;
+
+        Ok(tables.participants(mission))
+    }
+
+    async fn get_by_mission(&self, mission_id: i32) -> Result<Vec<MissionFeedbackModel>> {
+        Ok(self
+            .tables()
+            .mission_feedbacks
+            .iter()
+            .filter(|feedback| feedback.mission_id == mission_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_by_ratee(&self, brawler_id: i32) -> Result<Vec<MissionFeedbackModel>> {
+        Ok(self
+            .tables()
+            .mission_feedbacks
+            .iter()
+            .rev()
+            .filter(|feedback| feedback.ratee_id == brawler_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_rating(&self, brawler_id: i32) -> Result<BrawlerRatingModel> {
+        let tables = self.tables();
+        let ratings = tables
+            .mission_feedbacks
+            .iter()
+            .filter(|feedback| feedback.ratee_id == brawler_id)
+            .map(|feedback| feedback.rating as f64)
+            .collect::<Vec<_>>();
+        let rating_average = match ratings.len() {
+            0 => 0.0,
+            count => ratings.iter().sum::<f64>() / count as f64,
+        };
+
+        Ok(BrawlerRatingModel {
+            brawler_id,
+            rating_average,
+            rating_count: ratings.len() as i64,
+        })
+    }
+}
+
+#[async_trait]
+impl MissionManagementRepository for MemoryStore {
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
+        let mut tables = self.tables();
+        let id = tables.next_id();
+        let created_at = now();
+        tables.missions.push(MissionEntity {
+            id,
+            chief_id: add_mission_entity.chief_id,
+            name: add_mission_entity.name,
+            status: add_mission_entity.status,
+            description: add_mission_entity.description,
+            created_at,
+            updated_at: created_at,
+            deleted_at: None,
+            category: add_mission_entity.category,
+            ends_at: add_mission_entity.ends_at,
+            finished_at: None,
+        });
+
+        Ok(id)
+    }
+
+    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+        let mut tables = self.tables();
+        let mission = tables
+            .live_mission_mut(mission_id)
+            .ok()
+            .filter(|mission| mission.status == MissionStatuses::Open.to_string())
+            .ok_or_else(|| anyhow!("Record not found"))?;
+
+        mission.chief_id = edit_mission_entity.chief_id;
+        if let Some(name) = edit_mission_entity.name {
+            mission.name = name;
+        }
+        if let Some(description) = edit_mission_entity.description {
+            mission.description = Some(description);
+        }
+        if let Some(category) = edit_mission_entity.category {
+            mission.category = category;
+        }
+        if let Some(ends_at) = edit_mission_entity.ends_at {
+            mission.ends_at = Some(ends_at);
+        }
+
+        Ok(mission.id)
+    }
+
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+        let mut tables = self.tables();
+        if let Ok(mission) = tables.live_mission_mut(mission_id) {
+            if mission.status == MissionStatuses::Open.to_string() {
+                mission.deleted_at = Some(now());
+                mission.chief_id = chief_id;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_tags(&self, mission_id: i32, tags: Vec<String>) -> Result<()> {
+        let mut tables = self.tables();
+        tables.mission_tags.retain(|(id, _)| *id != mission_id);
+        tables
+            .mission_tags
+            .extend(tags.into_iter().map(|tag| (mission_id, tag)));
+
+        Ok(())
+    }
+
+    async fn find(&self, mission_id: i32) -> Result<MissionEntity> {
+        self.tables().live_mission(mission_id).cloned()
+    }
+
+    async fn get_tags(&self, mission_id: i32) -> Result<Vec<String>> {
+        Ok(self.tables().tags_of(mission_id))
+    }
+}
+
+#[async_trait]
+impl MissionOperationRepository for MemoryStore {
+    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        self.set_mission_status(mission_id, chief_id, MissionStatuses::InProgress)
+    }
+
+    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        self.set_mission_status(mission_id, chief_id, MissionStatuses::Completed)
+    }
+
+    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+        self.set_mission_status(mission_id, chief_id, MissionStatuses::Failed)
+    }
+}
+
+#[async_trait]
+impl MissionViewingRepository for MemoryStore {
+    async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
+        Ok(self.tables().crew_of(mission_id).len() as i64)
+    }
+
+    async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
+        let tables = self.tables();
+        let mission = tables.live_mission(mission_id)?;
+
+        Ok(tables.to_model(mission))
+    }
+
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        _cursor: Option<MissionCursor>,
+        max_crew_per_mission: i64,
+    ) -> Result<(Vec<MissionModel>, Option<MissionCursor>)> {
+        let tables = self.tables();
+        let (sort, direction) = mission_filter.resolved_sort();
+
+        let mut missions = Vec::new();
+        for mission in &tables.missions {
+            if tables.matches(mission, mission_filter, max_crew_per_mission)? {
+                missions.push(tables.to_model(mission));
+            }
+        }
+        missions.sort_by(|left, right| {
+            let order = compare(left, right, &sort);
+            sort_bucket(left).cmp(&sort_bucket(right)).then(match direction {
+                SortDirection::Asc => order,
+                SortDirection::Desc => order.reverse(),
+            })
+        });
+
+        let limit = mission_filter.limit();
+        let page = missions
+            .into_iter()
+            .skip(((mission_filter.page() - 1) * limit) as usize)
+            .take(limit as usize)
+            .collect();
+
+        Ok((page, None))
+    }
+
+    async fn count_all(&self, mission_filter: &MissionFilter, max_crew_per_mission: i64) -> Result<i64> {
+        let tables = self.tables();
+
+        let mut total = 0;
+        for mission in &tables.missions {
+            if tables.matches(mission, mission_filter, max_crew_per_mission)? {
+                total += 1;
+            }
+        }
+
+        Ok(total)
+    }
+
+    async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
+        let tables = self.tables();
+        let completed = MissionStatuses::Completed.to_string();
+
+        let mut crew = Vec::new();
+        for brawler_id in tables.crew_of(mission_id) {
+            let brawler = tables.brawler(brawler_id)?;
+            let joined = tables
+                .crew_memberships
+                .iter()
+                .filter(|membership| membership.brawler_id == brawler_id)
+                .map(|membership| membership.mission_id)
+                .collect::<Vec<_>>();
+            let ratings = tables
+                .mission_feedbacks
+                .iter()
+                .filter(|feedback| feedback.ratee_id == brawler_id)
+                .map(|feedback| feedback.rating as f64)
+                .collect::<Vec<_>>();
+
+            crew.push(BrawlerModel {
+                brawler_id,
+                display_name: brawler.display_name.clone(),
+                avatar_url: brawler.avatar_url.clone().unwrap_or_default(),
+                mission_success_count: tables
+                    .missions
+                    .iter()
+                    .filter(|mission| joined.contains(&mission.id) && mission.status == completed)
+                    .count() as i64,
+                mission_joined_count: joined.len() as i64,
+                rating_average: match ratings.len() {
+                    0 => 0.0,
+                    count => ratings.iter().sum::<f64>() / count as f64,
+                },
+                rating_count: ratings.len() as i64,
+            });
+        }
+
+        Ok(crew)
+    }
+}
+
+#[async_trait]
+impl ModerationRepository for MemoryStore {
+    async fn find_brawler(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        self.tables().brawler(brawler_id).cloned()
+    }
+
+    async fn ban(&self, brawler_id: i32, reason: Option<String>) -> Result<()> {
+        if let Some(brawler) = self.tables().brawler_mut(brawler_id) {
+            brawler.banned_at = Some(now());
+            brawler.ban_reason = reason;
+            brawler.updated_at = now();
+        }
+
+        Ok(())
+    }
+
+    async fn unban(&self, brawler_id: i32) -> Result<()> {
+        if let Some(brawler) = self.tables().brawler_mut(brawler_id) {
+            brawler.banned_at = None;
+            brawler.ban_reason = None;
+            brawler.updated_at = now();
+        }
+
+        Ok(())
+    }
+
+    async fn set_role(&self, brawler_id: i32, role: Role) -> Result<()> {
+        if let Some(brawler) = self.tables().brawler_mut(brawler_id) {
+            brawler.role = role.to_string();
+            brawler.updated_at = now();
+        }
+
+        Ok(())
+    }
+
+    async fn force_fail_mission(&self, mission_id: i32) -> Result<String> {
+        let mut tables = self.tables();
+        let mission = tables.live_mission_mut(mission_id)?;
+        let previous_status = mission.status.clone();
+
+        if previous_status != MissionStatuses::Open.to_string()
+            && previous_status != MissionStatuses::InProgress.to_string()
+        {
+            return Err(anyhow!("Mission is not open or in progress"));
+        }
+
+        mission.status = MissionStatuses::Failed.to_string();
+        mission.updated_at = now();
+        mission.finished_at = Some(now());
+
+        Ok(previous_status)
+    }
+
+    async fn delete_mission(&self, mission_id: i32) -> Result<()> {
+        let mut tables = self.tables();
+        let mission = tables
+            .live_mission_mut(mission_id)
+            .map_err(|_| anyhow!("Mission not found"))?;
+        mission.deleted_at = Some(now());
+        tables
+            .mission_chats
+            .retain(|chat| chat.mission_id != mission_id);
+
+        Ok(())
+    }
+
+    async fn delete_chat_message(&self, message_id: i32) -> Result<i32> {
+        let mut tables = self.tables();
+        let index = tables
+            .mission_chats
+            .iter()
+            .position(|chat| chat.id == message_id)
+            .ok_or_else(|| anyhow!("Record not found"))?;
+
+        Ok(tables.mission_chats.remove(index).mission_id)
+    }
+}
+
+#[async_trait]
+impl SystemRepository for MemoryStore {
+    async fn get_stats(&self) -> Result<SystemStatsModel> {
+        let tables = self.tables();
+        let count = |status: MissionStatuses| {
+            tables
+                .missions
+                .iter()
+                .filter(|mission| mission.status == status.to_string())
+                .count() as i64
+        };
+        let missions_completed = count(MissionStatuses::Completed);
+        let missions_failed = count(MissionStatuses::Failed);
+
+        let success_rate = if missions_completed + missions_failed > 0 {
+            (missions_completed as f64 / (missions_completed + missions_failed) as f64) * 100.0
+        } else {
+            100.0
+        };
+
+        Ok(SystemStatsModel {
+            active_members: tables.brawlers.len() as i64,
+            missions_completed,
+            missions_failed,
+            success_rate,
+        })
+    }
+}
@@ -0,0 +1,331 @@
+//! Harness for the integration tests: every `TestApp` serves the full router
+//! from `http_serv::app` on a random port, backed by its own `MemoryStore`, so
+//! the HTTP tests need no database.
+//!
+//! Tests that exercise Postgres itself use a `TestDatabase` instead. Its server
+//! comes from `TEST_DATABASE_URL` (`.env` is read) and each one gets its own
+//! `gangbro_test_*` database. Those tests are `#[ignore]`d, so run them with
+//! `cargo test -- --ignored`; they panic when `TEST_DATABASE_URL` is unset.
+
+#![allow(dead_code)]
+
+pub mod memory;
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum::http::{HeaderValue, Method};
+use diesel::{
+    Connection, PgConnection, RunQueryDsl,
+    r2d2::{ConnectionManager, Pool},
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+use server::{
+    config::{
+        config_model::{
+            Cors, Database, DotEnvyConfig, JwtEnv, LogFormat, Logging, Metrics, Missions,
+            RateLimit, RateLimitStore, SecurityHeaders, Server, Telemetry,
+        },
+        stage::Stage,
+    },
+    domain::repositories::moderation::ModerationRepository,
+    infrastructure::{
+        cloudinary::CloudinaryStorage,
+        http::{app_state::AppState, http_serv},
+        shutdown::Shutdown,
+    },
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use memory::MemoryStore;
+
+pub const MAX_CREW_PER_MISSION: u32 = 2;
+pub const PASSWORD: &str = "password1";
+/// Never connected to; `TestApp`s keep their data in a `MemoryStore`.
+const UNUSED_DATABASE_URL: &str = "postgres://localhost/gangbro_unused";
+
+static DATABASE_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+pub struct TestApp {
+    /// `http://127.0.0.1:<port>`, for routes outside the API.
+    pub origin: String,
+    /// The `/api/v1` root every `request` path is relative to.
+    pub base_url: String,
+    pub client: reqwest::Client,
+    /// Lets a test begin draining as a stop signal would.
+    pub shutdown: Arc<Shutdown>,
+    store: Arc<MemoryStore>,
+    server: JoinHandle<()>,
+}
+
+impl TestApp {
+    pub async fn spawn() -> Self {
+        let config = Arc::new(test_config());
+        // Only the metrics router reads the pool, for its gauges, so it never connects
+        let db_pool = Arc::new(
+            Pool::builder()
+                .max_size(config.database.pool_size)
+                .build_unchecked(ConnectionManager::<PgConnection>::new(UNUSED_DATABASE_URL)),
+        );
+        let store = Arc::new(MemoryStore::new());
+
+        let state = AppState::new(
+            config,
+            db_pool,
+            store.repositories(),
+            Arc::new(CloudinaryStorage::new(None)),
+        );
+        let shutdown = Arc::clone(&state.shutdown);
+        let app = http_serv::app(Arc::new(state)).expect("cannot build the app");
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("cannot bind");
+        let addr = listener.local_addr().expect("no local address");
+        let server = tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .await
+                .expect("server stopped");
+        });
+
+        let origin = format!("http://{}", addr);
+        Self {
+            base_url: format!("{}/api/v1", origin),
+            origin,
+            client: reqwest::Client::new(),
+            shutdown,
+            store,
+            server,
+        }
+    }
+
+    pub async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path));
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request
+                .header("content-type", "application/json")
+                .body(body.to_string());
+        }
+
+        let response = request.send().await.expect("request failed");
+        let status = response.status();
+        let text = response.text().await.expect("cannot read body");
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+
+        (status, body)
+    }
+
+    pub async fn get(&self, path: &str, token: Option<&str>) -> (StatusCode, Value) {
+        self.request(Method::GET, path, token, None).await
+    }
+
+    pub async fn post(&self, path: &str, token: Option<&str>, body: Value) -> (StatusCode, Value) {
+        self.request(Method::POST, path, token, Some(body)).await
+    }
+
+    pub async fn patch(&self, path: &str, token: &str) -> (StatusCode, Value) {
+        self.request(Method::PATCH, path, Some(token), None).await
+    }
+
+    pub async fn delete(&self, path: &str, token: &str) -> (StatusCode, Value) {
+        self.request(Method::DELETE, path, Some(token), None).await
+    }
+
+    /// Registers `username` and returns its id and token.
+    pub async fn register(&self, username: &str) -> (i32, String) {
+        let (status, body) = self
+            .post(
+                "/brawlers",
+                None,
+                serde_json::json!({
+                    "username": username,
+                    "password": PASSWORD,
+                    "display_name": username,
+                }),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "register failed: {}", body);
+
+        passport(&body)
+    }
+
+    /// Gives a brawler a role directly in the store, as `gangbro-admin` would.
+    pub async fn set_role(&self, brawler_id: i32, role: &str) {
+        let role = role.parse().expect("invalid role");
+        ModerationRepository::set_role(self.store.as_ref(), brawler_id, role)
+            .await
+            .expect("cannot set the role");
+    }
+
+    /// Creates a mission chiefed by `token` and returns its id.
+    pub async fn create_mission(&self, token: &str, name: &str) -> i32 {
+        let (status, body) = self
+            .post(
+                "/missions",
+                Some(token),
+                serde_json::json!({ "name": name }),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "create mission failed: {}", body);
+
+        body["mission_id"].as_i64().expect("no mission_id") as i32
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+pub fn passport(body: &Value) -> (i32, String) {
+    let user_id = body["user_id"].as_i64().expect("no user_id") as i32;
+    let token = body["token"].as_str().expect("no token").to_string();
+
+    (user_id, token)
+}
+
+fn test_config() -> DotEnvyConfig {
+    let policy = "1000/60".parse().expect("invalid policy");
+
+    DotEnvyConfig {
+        stage: Stage::Local,
+        server: Server {
+            port: 0,
+            body_limit: 10,
+            timeout: 30,
+            trust_proxy_headers: false,
+            shutdown_drain: 0,
+        },
+        database: Database {
+            url: UNUSED_DATABASE_URL.to_string(),
+            pool_size: 4,
+            run_migrations: false,
+        },
+        jwt: JwtEnv {
+            secret: "integration-test-secret".to_string(),
+            lift_time_days: 1,
+        },
+        cloudinary: None,
+        missions: Missions {
+            max_crew_per_mission: MAX_CREW_PER_MISSION,
+        },
+        rate_limit: RateLimit {
+            enabled: false,
+            store: RateLimitStore::Memory,
+            login: policy,
+            register: policy,
+            upload: policy,
+            chat: policy,
+            write: policy,
+            read: policy,
+        },
+        cors: Cors {
+            allowed_origins: None,
+            allowed_methods: vec![Method::GET, Method::POST, Method::PATCH, Method::DELETE],
+            allow_credentials: false,
+        },
+        security_headers: SecurityHeaders {
+            hsts: false,
+            content_security_policy: HeaderValue::from_static("default-src 'self'"),
+        },
+        metrics: Metrics {
+            enabled: true,
+            token: None,
+            port: None,
+        },
+        logging: Logging {
+            level: "info".to_string(),
+            format: LogFormat::Pretty,
+        },
+        telemetry: Telemetry {
+            otlp_endpoint: None,
+            service_name: "gangbro-test".to_string(),
+        },
+    }
+}
+
+/// A database created for one test and dropped, connections and all, after it.
+pub struct TestDatabase {
+    server_url: String,
+    name: String,
+    pub url: String,
+}
+
+impl TestDatabase {
+    /// Never falls back to `DATABASE_URL`, which may well point at real data.
+    pub fn create() -> Self {
+        let _ = dotenvy::dotenv();
+        let server_url = std::env::var("TEST_DATABASE_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .expect("TEST_DATABASE_URL must point at a Postgres server to run the database tests");
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.subsec_nanos())
+            .unwrap_or_default();
+        let name = format!(
+            "gangbro_test_{}_{}_{}",
+            std::process::id(),
+            DATABASE_COUNTER.fetch_add(1, Ordering::Relaxed),
+            nanos
+        );
+
+        let mut connection =
+            PgConnection::establish(&server_url).expect("cannot connect to the database server");
+        diesel::sql_query(format!("CREATE DATABASE {}", name))
+            .execute(&mut connection)
+            .expect("cannot create the test database");
+
+        let url = with_database(&server_url, &name);
+        Self {
+            server_url,
+            name,
+            url,
+        }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        if let Ok(mut connection) = PgConnection::establish(&self.server_url) {
+            let _ = diesel::sql_query(format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", self.name))
+                .execute(&mut connection);
+        }
+    }
+}
+
+/// Swaps the database name in a `postgres://` URL, keeping any query string.
+fn with_database(url: &str, name: &str) -> String {
+    let (base, query) = match url.split_once('?') {
+        Some((base, query)) => (base, Some(query)),
+        None => (url, None),
+    };
+    let authority_start = base.find("://").map(|index| index + 3).unwrap_or(0);
+    let base = match base[authority_start..].find('/') {
+        Some(slash) => &base[..authority_start + slash],
+        None => base,
+    };
+
+    match query {
+        Some(query) => format!("{}/{}?{}", base, name, query),
+        None => format!("{}/{}", base, name),
+    }
+}
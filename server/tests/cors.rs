@@ -0,0 +1,37 @@
+mod common;
+
+use reqwest::{Method, StatusCode};
+
+use common::TestApp;
+
+#[tokio::test]
+async fn preflight_allows_the_trace_context_headers() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .client
+        .request(Method::OPTIONS, format!("{}/missions", app.base_url))
+        .header("origin", "http://localhost:4200")
+        .header("access-control-request-method", "POST")
+        .header(
+            "access-control-request-headers",
+            "authorization,content-type,x-request-id,traceparent,tracestate",
+        )
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let allowed = response.headers()["access-control-allow-headers"]
+        .to_str()
+        .unwrap()
+        .to_ascii_lowercase();
+    for header in ["authorization", "content-type", "x-request-id", "traceparent", "tracestate"] {
+        assert!(
+            allowed.split(',').any(|h| h.trim() == header),
+            "{} not in {}",
+            header,
+            allowed
+        );
+    }
+}
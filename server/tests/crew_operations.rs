@@ -0,0 +1,86 @@
+mod common;
+
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use common::{MAX_CREW_PER_MISSION, TestApp};
+
+async fn join(app: &TestApp, mission_id: i32, token: &str) -> (StatusCode, Value) {
+    app.request(
+        Method::POST,
+        &format!("/missions/{}/crew", mission_id),
+        Some(token),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn crew_is_capped_at_the_configured_size() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let mission_id = app.create_mission(&chief, "Small crew").await;
+
+    // The chief takes one of the seats
+    for index in 1..MAX_CREW_PER_MISSION {
+        let (_, member) = app.register(&format!("member{}", index)).await;
+        let (status, body) = join(&app, mission_id, &member).await;
+        assert_eq!(status, StatusCode::OK, "join failed: {}", body);
+    }
+
+    let (_, latecomer) = app.register("latecomer").await;
+    let (status, body) = join(&app, mission_id, &latecomer).await;
+    assert!(!status.is_success());
+    assert_eq!(body["error"], "Mission is full");
+
+    let (_, mission) = app.get(&format!("/missions/{}", mission_id), None).await;
+    assert_eq!(mission["crew_count"], MAX_CREW_PER_MISSION);
+}
+
+#[tokio::test]
+async fn leaving_frees_a_seat() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let (_, member) = app.register("member").await;
+    let (_, latecomer) = app.register("latecomer").await;
+    let mission_id = app.create_mission(&chief, "Revolving door").await;
+
+    join(&app, mission_id, &member).await;
+    let (status, _) = join(&app, mission_id, &latecomer).await;
+    assert!(!status.is_success());
+
+    let (status, _) = app
+        .delete(&format!("/missions/{}/crew", mission_id), &member)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = join(&app, mission_id, &latecomer).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, roster) = app.get(&format!("/missions/{}/crew", mission_id), None).await;
+    assert_eq!(roster.as_array().map(Vec::len), Some(MAX_CREW_PER_MISSION as usize));
+}
+
+#[tokio::test]
+async fn chief_cannot_join_and_started_missions_are_closed() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let (_, member) = app.register("member").await;
+    let mission_id = app.create_mission(&chief, "Locked vault").await;
+
+    let (status, _) = join(&app, mission_id, &chief).await;
+    assert!(!status.is_success());
+
+    join(&app, mission_id, &member).await;
+    app.patch(&format!("/missions/{}/start", mission_id), &chief)
+        .await;
+
+    let (status, body) = app
+        .delete(&format!("/missions/{}/crew", mission_id), &member)
+        .await;
+    assert!(!status.is_success());
+    assert_eq!(body["error"], "Mission is not leavable");
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use reqwest::StatusCode;
+use serde_json::Value;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn probes_report_liveness_and_each_readiness_check() {
+    let app = TestApp::spawn().await;
+
+    let live = app
+        .client
+        .get(format!("{}/health/live", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(live.status(), StatusCode::OK);
+
+    let ready = app
+        .client
+        .get(format!("{}/health/ready", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(ready.status(), StatusCode::OK);
+    let body: Value = ready.json().await.expect("invalid JSON");
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["checks"]["database"]["status"], "ok");
+    assert_eq!(body["checks"]["migrations"]["status"], "ok");
+    assert!(body["checks"]["database"]["latency_ms"].is_u64());
+    // No Cloudinary credentials in tests
+    assert_eq!(body["checks"]["storage"]["status"], "disabled");
+}
+
+#[tokio::test]
+async fn make_error_rejects_invalid_status_codes() {
+    let app = TestApp::spawn().await;
+
+    let teapot = app
+        .client
+        .get(format!("{}/api/util/make-error/418", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(teapot.status(), StatusCode::IM_A_TEAPOT);
+
+    let invalid = app
+        .client
+        .get(format!("{}/api/util/make-error/1000", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn readiness_fails_while_draining() {
+    let app = TestApp::spawn().await;
+
+    app.shutdown.begin_draining();
+
+    let ready = app
+        .client
+        .get(format!("{}/health/ready", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(ready.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = ready.json().await.expect("invalid JSON");
+    assert_eq!(body["checks"]["shutdown"]["status"], "fail");
+
+    // Still serving until the drain period is over
+    let live = app
+        .client
+        .get(format!("{}/health/live", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(live.status(), StatusCode::OK);
+}
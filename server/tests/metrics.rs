@@ -0,0 +1,41 @@
+mod common;
+
+use reqwest::{StatusCode, header::CONTENT_TYPE};
+
+use common::TestApp;
+
+#[tokio::test]
+async fn metrics_count_requests_by_route_and_domain_events() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let (_, member) = app.register("member").await;
+    let mission_id = app.create_mission(&chief, "Counted heist").await;
+    app.request(
+        reqwest::Method::POST,
+        &format!("/missions/{}/crew", mission_id),
+        Some(&member),
+        None,
+    )
+    .await;
+    app.get(&format!("/missions/{}", mission_id), None).await;
+
+    let response = app
+        .client
+        .get(format!("{}/metrics", app.origin))
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()[CONTENT_TYPE].to_str().unwrap().starts_with("text/plain"));
+    let metrics = response.text().await.expect("cannot read body");
+
+    // Labelled by route template, not by mission id
+    assert!(metrics.contains(
+        "gangbro_http_requests_total{method=\"GET\",route=\"/api/v1/missions/{mission_id}\",status=\"200\"}"
+    ));
+    assert!(metrics.contains("gangbro_http_request_duration_seconds_bucket"));
+    assert!(metrics.contains("gangbro_missions_created_total 1"));
+    assert!(metrics.contains("gangbro_crew_joins_total 1"));
+    assert!(metrics.contains("gangbro_db_pool_max_connections"));
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use std::thread;
+
+use diesel::{Connection, PgConnection};
+use server::infrastructure::database::postgresql_connection::{migration_states, run_migrations};
+
+use common::TestDatabase;
+
+#[test]
+#[ignore = "needs TEST_DATABASE_URL"]
+fn concurrent_runs_apply_each_migration_once() {
+    let database = TestDatabase::create();
+
+    let runs = (0..3)
+        .map(|_| {
+            let url = database.url.clone();
+            thread::spawn(move || {
+                let mut connection = PgConnection::establish(&url).expect("cannot connect");
+                run_migrations(&mut connection)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut applied = Vec::new();
+    for run in runs {
+        applied.extend(run.join().expect("migration thread panicked").expect("migrations failed"));
+    }
+
+    let mut connection = PgConnection::establish(&database.url).expect("cannot connect");
+    let states = migration_states(&mut connection).expect("cannot list migrations");
+    assert!(states.iter().all(|migration| migration.applied));
+    // Whoever waited for the lock found nothing left to run
+    assert_eq!(applied.len(), states.len());
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use reqwest::StatusCode;
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn messages_are_listed_in_order_with_their_author() {
+    let app = TestApp::spawn().await;
+
+    let (chief_id, chief) = app.register("chief").await;
+    let (member_id, member) = app.register("member").await;
+    let mission_id = app.create_mission(&chief, "Chatty crew").await;
+    let path = format!("/missions/{}/messages", mission_id);
+
+    let (status, _) = app.post(&path, Some(&chief), json!({ "message": "Briefing at 9" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = app.post(&path, Some(&member), json!({ "message": "Copy that" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, messages) = app.get(&path, Some(&member)).await;
+    assert_eq!(status, StatusCode::OK);
+    let messages = messages.as_array().expect("messages are not a list");
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0]["brawler_id"], chief_id);
+    assert_eq!(messages[0]["brawler_name"], "chief");
+    assert_eq!(messages[0]["message"], "Briefing at 9");
+    assert_eq!(messages[1]["brawler_id"], member_id);
+}
+
+#[tokio::test]
+async fn chat_needs_a_token() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let mission_id = app.create_mission(&chief, "Private line").await;
+    let path = format!("/missions/{}/messages", mission_id);
+
+    let (status, _) = app.post(&path, None, json!({ "message": "Anyone there?" })).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = app.get(&path, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
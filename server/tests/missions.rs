@@ -0,0 +1,120 @@
+mod common;
+
+use reqwest::StatusCode;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn mission_runs_from_open_to_completed() {
+    let app = TestApp::spawn().await;
+
+    let (chief_id, chief) = app.register("chief").await;
+    let (_, member) = app.register("member").await;
+    let mission_id = app.create_mission(&chief, "Night heist").await;
+
+    let (status, mission) = app.get(&format!("/missions/{}", mission_id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(mission["status"], "Open");
+    assert_eq!(mission["chief_id"], chief_id);
+    assert_eq!(mission["crew_count"], 1);
+
+    // The chief alone is not a crew
+    let (status, _) = app
+        .patch(&format!("/missions/{}/start", mission_id), &chief)
+        .await;
+    assert!(!status.is_success());
+
+    let (status, _) = app
+        .request(
+            reqwest::Method::POST,
+            &format!("/missions/{}/crew", mission_id),
+            Some(&member),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Only the chief moves the mission along
+    let (status, _) = app
+        .patch(&format!("/missions/{}/start", mission_id), &member)
+        .await;
+    assert!(!status.is_success());
+
+    let (status, _) = app
+        .patch(&format!("/missions/{}/start", mission_id), &chief)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = app
+        .patch(&format!("/missions/{}/complete", mission_id), &chief)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, mission) = app.get(&format!("/missions/{}", mission_id), None).await;
+    assert_eq!(mission["status"], "Completed");
+
+    let (status, missions) = app.get("/brawlers/me/missions", Some(&member)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(
+        missions
+            .as_array()
+            .expect("missions are not a list")
+            .iter()
+            .any(|mission| mission["id"] == mission_id)
+    );
+}
+
+#[tokio::test]
+async fn deleted_missions_leave_the_listing() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let kept = app.create_mission(&chief, "Quiet job").await;
+    let deleted = app.create_mission(&chief, "Called off").await;
+
+    let (status, _) = app
+        .delete(&format!("/missions/{}", deleted), &chief)
+        .await;
+    assert!(status.is_success());
+
+    let (status, page) = app.get("/missions", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(page["total"], 1);
+    assert_eq!(page["items"][0]["id"], kept);
+}
+
+#[tokio::test]
+async fn listing_filters_by_status_and_name() {
+    let app = TestApp::spawn().await;
+
+    let (_, chief) = app.register("chief").await;
+    let (_, member) = app.register("member").await;
+    let started = app.create_mission(&chief, "Bank job").await;
+    let open = app.create_mission(&chief, "Museum job").await;
+
+    app.request(
+        reqwest::Method::POST,
+        &format!("/missions/{}/crew", started),
+        Some(&member),
+        None,
+    )
+    .await;
+    let (status, _) = app
+        .patch(&format!("/missions/{}/start", started), &chief)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, page) = app.get("/missions?status=Open", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let ids = page["items"]
+        .as_array()
+        .expect("items are not a list")
+        .iter()
+        .map(|mission| mission["id"].as_i64().unwrap_or_default() as i32)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![open]);
+
+    let (_, page) = app.get("/missions?name=bank", None).await;
+    assert_eq!(page["total"], 1);
+    assert_eq!(page["items"][0]["id"], started);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use reqwest::StatusCode;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn error_bodies_carry_the_request_id() {
+    let app = TestApp::spawn().await;
+
+    let response = app
+        .client
+        .get(format!("{}/brawlers/me/missions", app.base_url))
+        .header("x-request-id", "support-ticket-42")
+        .send()
+        .await
+        .expect("request failed");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()["x-request-id"], "support-ticket-42");
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.expect("cannot read body")).expect("json body");
+    assert_eq!(body["request_id"], "support-ticket-42");
+
+    // Generated when the client sends none
+    let (_, chief) = app.register("chief").await;
+    let response = app
+        .client
+        .patch(format!("{}/missions/999999/start", app.base_url))
+        .bearer_auth(&chief)
+        .send()
+        .await
+        .expect("request failed");
+    assert!(response.status().is_client_error() || response.status().is_server_error());
+    let request_id = response.headers()["x-request-id"].to_str().unwrap().to_string();
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.expect("cannot read body")).expect("json body");
+    assert_eq!(body["request_id"], request_id.as_str());
+    assert!(body["error"].is_string());
+}
+
+#[tokio::test]
+async fn successful_bodies_are_left_alone() {
+    let app = TestApp::spawn().await;
+
+    let (status, body) = app.get("/missions", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("request_id").is_none());
+}
@@ -0,0 +1,117 @@
+//! The Angular client imports its DTOs from `GangBro-client/src/app/_models/generated`,
+//! which is generated from the Rust types below. This test fails once those files
+//! drift from the Rust side; regenerate them with
+//! `UPDATE_TS_BINDINGS=1 cargo test --test typescript_bindings`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use server::{
+    domain::value_objects::{
+        brawler_model::{BrawlerModel, BrawlerProfileModel, RegisterBrawlerModel},
+        mission_chat_model::{AddMissionChatModel, MissionChatModel},
+        mission_filter::MissionFilter,
+        mission_model::{AddMissionModel, EditMissionModel, MissionModel},
+        mission_page_model::MissionPageModel,
+        notification_model::NotificationModel,
+        system_stats::SystemStatsModel,
+        uploaded_image::UploadedImage,
+    },
+    infrastructure::jwt::{authentication_model::LoginModel, jwt_model::Passport},
+};
+use ts_rs::TS;
+
+const INDEX: &str = "index.ts";
+
+fn bindings_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../GangBro-client/src/app/_models/generated")
+}
+
+/// Writes every type the client exchanges with the API, their dependencies and
+/// an index re-exporting all of them into `dir`. Chat sockets send
+/// `MissionChatModel[]`, notification sockets `NotificationModel`.
+fn export_bindings(dir: &Path) {
+    fs::create_dir_all(dir).expect("cannot create the bindings directory");
+
+    MissionModel::export_all_to(dir).expect("MissionModel");
+    AddMissionModel::export_all_to(dir).expect("AddMissionModel");
+    EditMissionModel::export_all_to(dir).expect("EditMissionModel");
+    MissionFilter::export_all_to(dir).expect("MissionFilter");
+    MissionPageModel::export_all_to(dir).expect("MissionPageModel");
+    MissionChatModel::export_all_to(dir).expect("MissionChatModel");
+    AddMissionChatModel::export_all_to(dir).expect("AddMissionChatModel");
+    BrawlerModel::export_all_to(dir).expect("BrawlerModel");
+    BrawlerProfileModel::export_all_to(dir).expect("BrawlerProfileModel");
+    RegisterBrawlerModel::export_all_to(dir).expect("RegisterBrawlerModel");
+    LoginModel::export_all_to(dir).expect("LoginModel");
+    Passport::export_all_to(dir).expect("Passport");
+    UploadedImage::export_all_to(dir).expect("UploadedImage");
+    SystemStatsModel::export_all_to(dir).expect("SystemStatsModel");
+    NotificationModel::export_all_to(dir).expect("NotificationModel");
+
+    let exports = read_bindings(dir)
+        .keys()
+        .filter(|name| name.as_str() != INDEX)
+        .map(|name| format!("export * from './{}';\n", name.trim_end_matches(".ts")))
+        .collect::<String>();
+    let index = format!(
+        "// Generated by server/tests/typescript_bindings.rs. Do not edit this file manually.\n{}",
+        exports
+    );
+    fs::write(dir.join(INDEX), index).expect("cannot write the index");
+}
+
+/// `.ts` files in `dir` by file name.
+fn read_bindings(dir: &Path) -> BTreeMap<String, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+
+    entries
+        .map(|entry| entry.expect("cannot read the bindings directory").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ts"))
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let content = fs::read_to_string(&path).expect("cannot read a binding");
+            (name, content)
+        })
+        .collect()
+}
+
+#[test]
+fn typescript_bindings_are_up_to_date() {
+    let committed_dir = bindings_dir();
+
+    if std::env::var_os("UPDATE_TS_BINDINGS").is_some() {
+        // Start over so bindings of removed types don't linger
+        for name in read_bindings(&committed_dir).keys() {
+            fs::remove_file(committed_dir.join(name)).expect("cannot remove a stale binding");
+        }
+        export_bindings(&committed_dir);
+        return;
+    }
+
+    let fresh_dir = std::env::temp_dir().join(format!("gangbro_ts_bindings_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&fresh_dir);
+    export_bindings(&fresh_dir);
+    let fresh = read_bindings(&fresh_dir);
+    let _ = fs::remove_dir_all(&fresh_dir);
+
+    let committed = read_bindings(&committed_dir);
+    let stale = fresh
+        .keys()
+        .chain(committed.keys())
+        .filter(|name| fresh.get(*name) != committed.get(*name))
+        .collect::<BTreeSet<_>>();
+
+    assert!(
+        stale.is_empty(),
+        "TypeScript bindings in {} are stale: {:?}. \
+         Run `UPDATE_TS_BINDINGS=1 cargo test --test typescript_bindings` and commit the result.",
+        committed_dir.display(),
+        stale
+    );
+}